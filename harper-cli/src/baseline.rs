@@ -0,0 +1,259 @@
+//! Records existing lints so that `harper-cli lint --baseline` only fails on
+//! new ones.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::format_err;
+use harper_core::linting::{Lint, LintKind};
+use serde::{Deserialize, Serialize};
+
+use crate::output::FileReport;
+
+/// The number of characters on either side of a lint that are included in
+/// its fingerprint.
+const CONTEXT_CHARS: usize = 24;
+
+/// A set of known lints, read from and written to a JSON file.
+///
+/// Lints are identified by their rule, the file they were found in and the
+/// text surrounding them, rather than by their position, so that unrelated
+/// edits elsewhere in a file don't cause them to reappear.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Baseline {
+    lints: Vec<BaselineEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct BaselineEntry {
+    /// The path of the file, relative to the baseline.
+    file: String,
+    rule: Option<String>,
+    /// The text of the lint and its surroundings, with whitespace collapsed.
+    context: String,
+    /// Purely informational, to make the baseline easier to review.
+    kind: LintKind,
+    /// Purely informational, to make the baseline easier to review.
+    message: String,
+}
+
+impl BaselineEntry {
+    fn new(file: String, source: &[char], lint: &Lint) -> Self {
+        Self {
+            file,
+            rule: lint.rule.clone(),
+            context: lint_context(source, lint),
+            kind: lint.lint_kind,
+            message: lint.message.clone(),
+        }
+    }
+
+    fn into_fingerprint(self) -> (String, Option<String>, String) {
+        (self.file, self.rule, self.context)
+    }
+}
+
+impl Baseline {
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let source = std::fs::read_to_string(path).map_err(|err| {
+            format_err!(
+                "Unable to read baseline {}: {}. Create one with `--update-baseline`.",
+                path.display(),
+                err
+            )
+        })?;
+
+        Ok(serde_json::from_str(&source)?)
+    }
+
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        let mut json = serde_json::to_string_pretty(self)?;
+        json.push('\n');
+
+        std::fs::write(path, json)
+            .map_err(|err| format_err!("Unable to write baseline {}: {}", path.display(), err))
+    }
+
+    /// Record every lint in the reports.
+    /// Paths are stored relative to the directory containing `path`.
+    pub fn from_reports(path: &Path, reports: &[FileReport]) -> Self {
+        let root = baseline_root(path);
+        let mut lints = Vec::new();
+
+        for report in reports {
+            let file = relative_path(&root, &report.path);
+            let source: Vec<char> = report.source.chars().collect();
+
+            lints.extend(
+                report
+                    .lints
+                    .iter()
+                    .map(|lint| BaselineEntry::new(file.clone(), &source, lint)),
+            );
+        }
+
+        // Keep the file stable, so it produces small diffs when updated.
+        lints.sort_by(|a, b| (&a.file, &a.rule, &a.context).cmp(&(&b.file, &b.rule, &b.context)));
+
+        Self { lints }
+    }
+
+    /// Remove any lints that are present in the baseline from the reports.
+    ///
+    /// Each entry in the baseline can only suppress a single lint, so
+    /// introducing another copy of an existing problem is still reported.
+    ///
+    /// Returns the number of lints that were removed.
+    pub fn suppress(&self, path: &Path, reports: &mut [FileReport]) -> usize {
+        let root = baseline_root(path);
+
+        let mut remaining: HashMap<_, usize> = HashMap::new();
+        for entry in &self.lints {
+            *remaining
+                .entry(entry.clone().into_fingerprint())
+                .or_default() += 1;
+        }
+
+        let mut suppressed = 0;
+
+        for report in reports {
+            let file = relative_path(&root, &report.path);
+            let source: Vec<char> = report.source.chars().collect();
+
+            report.lints.retain(|lint| {
+                let entry = BaselineEntry::new(file.clone(), &source, lint);
+
+                match remaining.get_mut(&entry.into_fingerprint()) {
+                    Some(count) if *count > 0 => {
+                        *count -= 1;
+                        suppressed += 1;
+                        false
+                    }
+                    _ => true,
+                }
+            });
+        }
+
+        suppressed
+    }
+}
+
+fn baseline_root(path: &Path) -> PathBuf {
+    let path = std::path::absolute(path).unwrap_or(path.to_path_buf());
+    path.parent().unwrap_or(Path::new("")).to_path_buf()
+}
+
+/// Get a platform-independent representation of a path, relative to `root`
+/// when possible.
+fn relative_path(root: &Path, path: &Path) -> String {
+    let path = std::path::absolute(path).unwrap_or(path.to_path_buf());
+    let relative = path.strip_prefix(root).unwrap_or(&path);
+
+    relative
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Get the text of a lint, along with up to [`CONTEXT_CHARS`] characters on
+/// either side that belong to the same line.
+fn lint_context(source: &[char], lint: &Lint) -> String {
+    let span_start = lint.span.start.min(source.len());
+    let span_end = lint.span.end.min(source.len());
+
+    let mut start = span_start;
+    while start > 0 && span_start - start < CONTEXT_CHARS && source[start - 1] != '\n' {
+        start -= 1;
+    }
+
+    let mut end = span_end;
+    while end < source.len() && end - span_end < CONTEXT_CHARS && source[end] != '\n' {
+        end += 1;
+    }
+
+    let mut context = String::new();
+
+    for c in &source[start..end] {
+        if c.is_whitespace() {
+            if !context.is_empty() && !context.ends_with(' ') {
+                context.push(' ');
+            }
+        } else {
+            context.push(*c);
+        }
+    }
+
+    context.trim_end().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use harper_core::linting::Lint;
+    use harper_core::{SeverityOverrides, Span};
+
+    use super::{lint_context, Baseline};
+    use crate::output::FileReport;
+
+    fn report(source: &str, word: &str) -> FileReport {
+        let start = source.find(word).unwrap();
+        let start = source[..start].chars().count();
+
+        FileReport {
+            path: "/project/docs/README.md".into(),
+            source: source.to_string(),
+            lints: vec![Lint {
+                span: Span::new(start, start + word.chars().count()),
+                rule: Some("SpellCheck".to_string()),
+                ..Default::default()
+            }],
+            severity: SeverityOverrides::new(),
+        }
+    }
+
+    #[test]
+    fn context_stays_on_one_line() {
+        let source: Vec<char> = "First line.\nThis  is a tesst.\nLast line."
+            .chars()
+            .collect();
+        let lint = Lint {
+            span: Span::new(23, 28),
+            ..Default::default()
+        };
+
+        assert_eq!(lint_context(&source, &lint), "This is a tesst.");
+    }
+
+    #[test]
+    fn survives_unrelated_edits() {
+        let path = std::path::Path::new("/project/harper-baseline.json");
+        let baseline = Baseline::from_reports(path, &[report("This is a tesst.", "tesst")]);
+
+        let mut reports = [report(
+            "A brand new paragraph.\n\nThis is a tesst.",
+            "tesst",
+        )];
+
+        assert_eq!(baseline.suppress(path, &mut reports), 1);
+        assert!(reports[0].lints.is_empty());
+    }
+
+    #[test]
+    fn reports_changed_lints() {
+        let path = std::path::Path::new("/project/harper-baseline.json");
+        let baseline = Baseline::from_reports(path, &[report("This is a tesst.", "tesst")]);
+
+        let mut reports = [report("This is another tesst.", "tesst")];
+
+        assert_eq!(baseline.suppress(path, &mut reports), 0);
+        assert_eq!(reports[0].lints.len(), 1);
+    }
+
+    #[test]
+    fn stores_relative_paths() {
+        let path = std::path::Path::new("/project/harper-baseline.json");
+        let baseline = Baseline::from_reports(path, &[report("This is a tesst.", "tesst")]);
+
+        assert_eq!(baseline.lints[0].file, "docs/README.md");
+    }
+}
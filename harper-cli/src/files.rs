@@ -0,0 +1,106 @@
+//! Finds the files `harper-cli` should lint and picks a parser for each.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::format_err;
+use harper_comments::CommentParser;
+use harper_core::parsers::{Markdown, Parser};
+use harper_html::HtmlParser;
+use ignore::overrides::OverrideBuilder;
+use ignore::WalkBuilder;
+
+/// Get the Language Server Protocol language ID of a file, based on its
+/// extension, so it can be matched against `language_linters` in a project
+/// configuration.
+pub fn language_id_for(path: &Path) -> Option<&'static str> {
+    match path.extension()?.to_str()? {
+        "md" => Some("markdown"),
+        "html" | "htm" => Some("html"),
+        _ => CommentParser::filename_to_filetype(path),
+    }
+}
+
+/// Get the parser appropriate for a file, based on its extension.
+pub fn parser_for(path: &Path) -> Option<Box<dyn Parser>> {
+    match path.extension()?.to_str()? {
+        "md" => Some(Box::new(Markdown)),
+        "html" | "htm" => Some(Box::new(HtmlParser::default())),
+        _ => CommentParser::new_from_filename(path).map(|p| Box::new(p) as Box<dyn Parser>),
+    }
+}
+
+/// Expand the provided paths into the list of files to lint.
+///
+/// Files named explicitly are always included.
+/// Directories are walked recursively, honoring `.gitignore` and similar
+/// files, and only yield files Harper knows how to parse.
+///
+/// `include` and `exclude` are globs, relative to each directory being
+/// walked, that further narrow the files found in directories.
+pub fn find_files(
+    paths: &[PathBuf],
+    include: &[String],
+    exclude: &[String],
+) -> anyhow::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+
+    for path in paths {
+        if path.is_file() {
+            if parser_for(path).is_none() {
+                return Err(format_err!(
+                    "Could not detect language ID for {}.",
+                    path.display()
+                ));
+            }
+
+            files.push(path.clone());
+            continue;
+        }
+
+        if !path.is_dir() {
+            return Err(format_err!("{} does not exist.", path.display()));
+        }
+
+        let mut overrides = OverrideBuilder::new(path);
+
+        for glob in include {
+            overrides.add(glob)?;
+        }
+
+        for glob in exclude {
+            overrides.add(&format!("!{}", glob))?;
+        }
+
+        let walker = WalkBuilder::new(path).overrides(overrides.build()?).build();
+
+        for entry in walker {
+            let entry = entry?;
+
+            if entry.file_type().is_some_and(|t| t.is_file()) && parser_for(entry.path()).is_some()
+            {
+                files.push(entry.into_path());
+            }
+        }
+    }
+
+    files.sort();
+    files.dedup();
+
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::parser_for;
+
+    #[test]
+    fn picks_parsers_by_extension() {
+        assert!(parser_for(Path::new("README.md")).is_some());
+        assert!(parser_for(Path::new("index.html")).is_some());
+        assert!(parser_for(Path::new("main.rs")).is_some());
+        assert!(parser_for(Path::new("image.png")).is_none());
+        assert!(parser_for(Path::new("Makefile")).is_none());
+    }
+}
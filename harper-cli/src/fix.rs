@@ -0,0 +1,121 @@
+//! Applies the suggestions attached to lints back to the source text.
+
+use std::path::Path;
+
+use harper_core::linting::Lint;
+use similar::TextDiff;
+
+/// Apply the first suggestion of each lint to the source.
+///
+/// The lints are expected to be free of overlaps (see
+/// [`harper_core::remove_overlaps`]), but any that still collide with a
+/// previously applied fix are skipped.
+///
+/// Returns the fixed source, along with the number of fixes that were applied.
+pub fn apply_fixes(source: &[char], lints: &[Lint]) -> (Vec<char>, usize) {
+    let mut fixable: Vec<&Lint> = lints
+        .iter()
+        .filter(|lint| !lint.suggestions.is_empty())
+        .collect();
+
+    // Applying fixes back-to-front keeps the spans of the remaining lints valid.
+    fixable.sort_by_key(|lint| std::cmp::Reverse(lint.span.start));
+
+    let mut fixed = source.to_vec();
+    let mut applied = 0;
+    let mut last_start = usize::MAX;
+
+    for lint in fixable {
+        if lint.span.end > last_start {
+            continue;
+        }
+
+        lint.suggestions[0].apply(lint.span, &mut fixed);
+        last_start = lint.span.start;
+        applied += 1;
+    }
+
+    (fixed, applied)
+}
+
+/// Render the changes made to a file as a unified diff.
+pub fn unified_diff(path: &Path, original: &str, fixed: &str) -> String {
+    let path = path.to_string_lossy();
+
+    TextDiff::from_lines(original, fixed)
+        .unified_diff()
+        .header(&format!("a/{}", path), &format!("b/{}", path))
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use harper_core::linting::{Lint, Suggestion};
+    use harper_core::Span;
+
+    use super::{apply_fixes, unified_diff};
+
+    fn replace(start: usize, end: usize, with: &str) -> Lint {
+        Lint {
+            span: Span::new(start, end),
+            suggestions: vec![Suggestion::ReplaceWith(with.chars().collect())],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn applies_fixes_in_any_order() {
+        let source: Vec<char> = "This is a tesst of teh linter.".chars().collect();
+        let lints = vec![replace(10, 15, "test"), replace(19, 22, "the")];
+
+        let (fixed, applied) = apply_fixes(&source, &lints);
+
+        assert_eq!(applied, 2);
+        assert_eq!(
+            fixed.iter().collect::<String>(),
+            "This is a test of the linter."
+        );
+    }
+
+    #[test]
+    fn removes_text() {
+        let source: Vec<char> = "the the cat".chars().collect();
+        let lints = vec![Lint {
+            span: Span::new(3, 7),
+            suggestions: vec![Suggestion::Remove],
+            ..Default::default()
+        }];
+
+        let (fixed, _) = apply_fixes(&source, &lints);
+
+        assert_eq!(fixed.iter().collect::<String>(), "the cat");
+    }
+
+    #[test]
+    fn skips_overlapping_and_unfixable_lints() {
+        let source: Vec<char> = "abcdef".chars().collect();
+        let lints = vec![
+            replace(0, 3, "x"),
+            replace(2, 4, "y"),
+            Lint {
+                span: Span::new(5, 6),
+                ..Default::default()
+            },
+        ];
+
+        let (fixed, applied) = apply_fixes(&source, &lints);
+
+        assert_eq!(applied, 1);
+        assert_eq!(fixed.iter().collect::<String>(), "abyef");
+    }
+
+    #[test]
+    fn renders_diff() {
+        let diff = unified_diff(Path::new("README.md"), "a tesst\n", "a test\n");
+
+        assert!(diff.starts_with("--- a/README.md\n+++ b/README.md\n"));
+        assert!(diff.contains("-a tesst\n+a test\n"));
+    }
+}
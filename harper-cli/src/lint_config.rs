@@ -0,0 +1,135 @@
+//! Parses the flags that configure linting from the command line.
+
+use anyhow::format_err;
+use harper_core::linting::LintGroupConfig;
+use harper_core::{Dialect, Severity, SeverityOverrides};
+use serde_json::{Map, Value};
+
+/// Build a [`LintGroupConfig`] from `KEY=VALUE` pairs.
+///
+/// Keys are either the name of a linter, to turn it on or off
+/// (`long_sentences=false`), or the name of a linter and one of its options,
+/// separated by a dot (`long_sentences.max_words=30`).
+/// Values are read as JSON, falling back to a plain string.
+pub fn parse_lint_overrides(pairs: &[String]) -> anyhow::Result<LintGroupConfig> {
+    let mut config = Map::new();
+
+    for pair in pairs {
+        let (key, value) = pair
+            .split_once('=')
+            .ok_or_else(|| format_err!("Expected KEY=VALUE, found `{}`.", pair))?;
+
+        let value = serde_json::from_str(value).unwrap_or_else(|_| Value::String(value.into()));
+
+        match key.split_once('.') {
+            Some((linter, option)) => {
+                let options = config
+                    .entry("options")
+                    .or_insert_with(|| Value::Object(Map::new()));
+                let linter_options = options
+                    .as_object_mut()
+                    .unwrap()
+                    .entry(linter)
+                    .or_insert_with(|| Value::Object(Map::new()));
+
+                linter_options
+                    .as_object_mut()
+                    .ok_or_else(|| format_err!("Unable to set `{}`.", key))?
+                    .insert(option.to_string(), value);
+            }
+            None => {
+                config.insert(key.to_string(), value);
+            }
+        }
+    }
+
+    serde_json::from_value(Value::Object(config))
+        .map_err(|err| format_err!("Invalid linter configuration: {}", err))
+}
+
+/// Parse one of `error`, `warning`, `information` or `hint`.
+pub fn parse_severity(value: &str) -> anyhow::Result<Severity> {
+    serde_json::from_value(Value::String(value.to_string())).map_err(|_| {
+        format_err!(
+            "Unknown severity `{}`. Expected error, warning, information or hint.",
+            value
+        )
+    })
+}
+
+/// Parse a dialect, either by name (`british`) or language tag (`en-GB`).
+pub fn parse_dialect(value: &str) -> anyhow::Result<Dialect> {
+    serde_json::from_value(Value::String(value.to_string())).map_err(|_| {
+        format_err!(
+            "Unknown dialect `{}`. Expected american, british, australian or canadian.",
+            value
+        )
+    })
+}
+
+/// Build [`SeverityOverrides`] from `KEY=SEVERITY` pairs, where each key is
+/// either a rule (`SpellCheck`) or a kind of lint (`Readability`).
+pub fn parse_severity_overrides(pairs: &[String]) -> anyhow::Result<SeverityOverrides> {
+    let mut overrides = SeverityOverrides::new();
+
+    for pair in pairs {
+        let (key, value) = pair
+            .split_once('=')
+            .ok_or_else(|| format_err!("Expected KEY=SEVERITY, found `{}`.", pair))?;
+
+        overrides.insert(key, parse_severity(value)?);
+    }
+
+    Ok(overrides)
+}
+
+#[cfg(test)]
+mod tests {
+    use harper_core::linting::{Lint, LintKind, QuoteStyle};
+    use harper_core::Severity;
+
+    use super::{parse_lint_overrides, parse_severity_overrides};
+
+    #[test]
+    fn sets_linters_and_options() {
+        let config = parse_lint_overrides(&[
+            "long_sentences.max_words=30".to_string(),
+            "wrong_quotes=true".to_string(),
+            "wrong_quotes.style=straight".to_string(),
+            r#"boring_words.words=["very", "really"]"#.to_string(),
+        ])
+        .unwrap();
+
+        assert_eq!(config.wrong_quotes, Some(true));
+        assert_eq!(config.options.long_sentences.unwrap().max_words, 30);
+        assert_eq!(
+            config.options.wrong_quotes.unwrap().style,
+            QuoteStyle::Straight
+        );
+        assert_eq!(
+            config.options.boring_words.unwrap().words,
+            vec!["very", "really"]
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_options() {
+        assert!(parse_lint_overrides(&["long_sentences.max_word=30".to_string()]).is_err());
+        assert!(parse_lint_overrides(&["long_sentences".to_string()]).is_err());
+    }
+
+    #[test]
+    fn parses_severity_overrides() {
+        let overrides =
+            parse_severity_overrides(&["Readability=hint".to_string(), "AnA=error".to_string()])
+                .unwrap();
+
+        let lint = Lint {
+            lint_kind: LintKind::Readability,
+            ..Default::default()
+        };
+
+        assert_eq!(overrides.get(&lint), Some(Severity::Hint));
+        assert!(parse_severity_overrides(&["AnA=fatal".to_string()]).is_err());
+    }
+}
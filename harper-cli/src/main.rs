@@ -1,25 +1,89 @@
 #![doc = include_str!("../README.md")]
 
+mod baseline;
+mod files;
+mod fix;
+mod lint_config;
+mod output;
+mod stats;
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufWriter, Write};
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 use anyhow::format_err;
-use ariadne::{Color, Label, Report, ReportKind, Source};
+use baseline::Baseline;
 use clap::Parser;
-use harper_comments::CommentParser;
-use harper_core::linting::{LintGroup, LintGroupConfig, Linter};
-use harper_core::parsers::Markdown;
-use harper_core::{remove_overlaps, Dictionary, Document, FstDictionary};
+use files::{find_files, language_id_for, parser_for};
+use fix::{apply_fixes, unified_diff};
+use harper_core::linting::{LintGroup, LintGroupConfig, Linter, ReplacementRules};
+use harper_core::readability::ReadabilityReport;
+use harper_core::{
+    remove_overlaps, Dialect, Dictionary, Document, FstDictionary, FullDictionary,
+    MergedDictionary, ProjectConfig, Severity, SeverityOverrides,
+};
+use lint_config::{parse_dialect, parse_lint_overrides, parse_severity, parse_severity_overrides};
+use output::{pluralize_lints, print_reports, print_summary, FileReport, OutputFormat};
+use rayon::prelude::*;
+use stats::{print_stats, FileStats, StatsFormat};
 
 #[derive(Debug, Parser)]
 enum Args {
-    /// Lint a provided document.
+    /// Lint the provided documents.
     Lint {
-        /// The file you wish to grammar check.
-        file: PathBuf,
+        #[command(flatten)]
+        files: FileArgs,
         /// Whether to merely print out the number of errors encountered,
         /// without further details.
         #[arg(short, long)]
         count: bool,
+        /// The format to print lints in.
+        #[arg(short, long, value_enum, default_value_t)]
+        format: OutputFormat,
+        /// Apply the suggested fixes, except for spelling, before reporting
+        /// the lints that remain.
+        #[arg(long)]
+        fix: bool,
+        /// Only report lints that are not recorded in this baseline file.
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+        /// Record every lint found in the baseline file, instead of reporting
+        /// them.
+        #[arg(long, requires = "baseline")]
+        update_baseline: bool,
+        /// Override the severity of a rule (e.g. `SpellCheck`) or kind of lint
+        /// (e.g. `Readability`), taking precedence over the project's
+        /// `harper.toml`. May be provided multiple times.
+        #[arg(long, value_name = "KEY=SEVERITY")]
+        severity: Vec<String>,
+        /// Exit with a non-zero status code if any lint is at least this severe.
+        /// Lints without an overridden severity are warnings.
+        #[arg(long, value_name = "SEVERITY", value_parser = parse_severity, default_value = "warning")]
+        fail_on: Severity,
+    },
+    /// Apply the first suggestion of each lint to the provided documents.
+    Fix {
+        #[command(flatten)]
+        files: FileArgs,
+        /// Print the changes as a unified diff instead of writing them.
+        #[arg(long)]
+        dry_run: bool,
+        /// Only apply fixes for lints from this rule (e.g. `RepeatedWords`).
+        /// May be provided multiple times.
+        /// Spelling is only fixed when `SpellCheck` is named here.
+        #[arg(short, long)]
+        rule: Vec<String>,
+    },
+    /// Print readability scores, like the Flesch–Kincaid grade level, for
+    /// each of the provided documents and their paragraphs.
+    Stats {
+        #[command(flatten)]
+        files: FileArgs,
+        /// The format to print the scores in.
+        #[arg(short, long, value_enum, default_value_t)]
+        format: StatsFormat,
     },
     /// Parse a provided document and print the detected symbols.
     Parse {
@@ -28,54 +92,179 @@ enum Args {
     },
     /// Emit decompressed, line-separated list of words in Harper's dictionary.
     Words,
+    /// Compile a dictionary into an FST and a metadata file, so it can be
+    /// loaded without expanding it again.
+    CompileDict {
+        /// Where to write the FST.
+        /// The metadata is written next to it, with the `.meta` extension.
+        output: PathBuf,
+        /// Compile a Hunspell dictionary (`.dic` file) instead of Harper's
+        /// curated dictionary.
+        /// The `.aff` file of the same name is read alongside it.
+        #[arg(long, value_name = "DIC")]
+        hunspell: Option<PathBuf>,
+        /// The dialect of the curated dictionary to compile.
+        #[arg(long, value_parser = parse_dialect, default_value = "american", conflicts_with = "hunspell")]
+        dialect: Dialect,
+    },
+}
+
+#[derive(Debug, clap::Args)]
+struct FileArgs {
+    /// The files or directories you wish to grammar check.
+    /// Directories are searched recursively, respecting `.gitignore`.
+    #[arg(required = true)]
+    paths: Vec<PathBuf>,
+    /// Only lint files in directories that match this glob.
+    /// May be provided multiple times.
+    #[arg(short, long)]
+    include: Vec<String>,
+    /// Skip files in directories that match this glob.
+    /// May be provided multiple times.
+    #[arg(short, long)]
+    exclude: Vec<String>,
+    /// A TOML or JSON file of custom replacement rules.
+    /// Used alongside any configured by the project.
+    #[arg(long)]
+    replacement_rules: Option<PathBuf>,
+    /// Configure a linter, taking precedence over the project's `harper.toml`.
+    /// Either `LINTER=true|false` or `LINTER.OPTION=VALUE`
+    /// (e.g. `long_sentences.max_words=30`).
+    /// May be provided multiple times.
+    #[arg(short, long, value_name = "KEY=VALUE")]
+    set: Vec<String>,
+}
+
+/// A file to lint, along with the project it belongs to.
+struct LintJob {
+    file: PathBuf,
+    lint_config: LintGroupConfig,
+    severity: SeverityOverrides,
+    dictionary: Arc<MergedDictionary>,
+    replacement_rules: Arc<ReplacementRules>,
 }
 
 fn main() -> anyhow::Result<()> {
     let args = Args::parse();
 
     match args {
-        Args::Lint { file, count } => {
-            let (doc, source) = load_file(&file)?;
+        Args::Lint {
+            files,
+            count,
+            format,
+            fix,
+            baseline,
+            update_baseline,
+            severity,
+            fail_on,
+        } => {
+            let severity = parse_severity_overrides(&severity)?;
+            let jobs = plan_jobs(&files)?;
+
+            if fix {
+                fix_files(&jobs, &[], false)?;
+            }
+
+            let mut reports = jobs
+                .par_iter()
+                .map(lint_file)
+                .collect::<anyhow::Result<Vec<_>>>()?;
 
-            let mut linter = LintGroup::new(LintGroupConfig::default(), FstDictionary::curated());
-            let mut lints = linter.lint(&doc);
+            for report in &mut reports {
+                report.severity.override_with(&severity);
+            }
+
+            if let Some(baseline_path) = &baseline {
+                if update_baseline {
+                    let baseline = Baseline::from_reports(baseline_path, &reports);
+                    baseline.save(baseline_path)?;
+
+                    let recorded: usize = reports.iter().map(|r| r.lints.len()).sum();
+                    println!(
+                        "Recorded {} in {}",
+                        pluralize_lints(recorded),
+                        baseline_path.display()
+                    );
+
+                    return Ok(());
+                }
+
+                let suppressed =
+                    Baseline::load(baseline_path)?.suppress(baseline_path, &mut reports);
+
+                if suppressed > 0 && !count {
+                    eprintln!(
+                        "{} known lints were suppressed by the baseline.",
+                        suppressed
+                    );
+                }
+            }
+
+            // Lints are counted before overlaps are removed, as they always
+            // have been.
+            let lint_count: usize = reports.iter().map(|r| r.lints.len()).sum();
 
             if count {
-                println!("{}", lints.len());
+                println!("{}", lint_count);
                 return Ok(());
             }
 
-            if lints.is_empty() {
-                println!("No lints found");
-                return Ok(());
+            for report in &mut reports {
+                remove_overlaps(&mut report.lints);
             }
 
-            remove_overlaps(&mut lints);
+            print_reports(&reports, format)?;
+            print_summary(&reports, format);
+
+            let failed = reports.iter().any(|report| {
+                report
+                    .lints
+                    .iter()
+                    .any(|lint| report.severity_for(lint) <= fail_on)
+            });
 
-            let primary_color = Color::Magenta;
+            if !failed {
+                return Ok(());
+            }
 
-            let filename = file
-                .file_name()
-                .map(|s| s.to_string_lossy().into())
-                .unwrap_or("<file>".to_string());
+            std::process::exit(1);
+        }
+        Args::Fix {
+            files,
+            dry_run,
+            rule,
+        } => {
+            let jobs = plan_jobs(&files)?;
+            let fixed = fix_files(&jobs, &rule, dry_run)?;
 
-            let mut report_builder = Report::build(ReportKind::Advice, &filename, 0);
+            let noun = if fixed == 1 { "fix" } else { "fixes" };
 
-            for lint in lints {
-                report_builder = report_builder.with_label(
-                    Label::new((&filename, lint.span.into()))
-                        .with_message(lint.message)
-                        .with_color(primary_color),
-                );
+            if dry_run {
+                eprintln!("Would apply {} {}.", fixed, noun);
+            } else {
+                println!("Applied {} {}.", fixed, noun);
             }
 
-            let report = report_builder.finish();
-            report.print((&filename, Source::from(source)))?;
+            Ok(())
+        }
+        Args::Stats { files, format } => {
+            let stats = plan_jobs(&files)?
+                .par_iter()
+                .map(|job| {
+                    let (doc, source) = load_file(&job.file, &job.dictionary)?;
 
-            std::process::exit(1);
+                    Ok(FileStats {
+                        path: job.file.clone(),
+                        source,
+                        report: ReadabilityReport::new(&doc),
+                    })
+                })
+                .collect::<anyhow::Result<Vec<_>>>()?;
+
+            print_stats(&stats, format)
         }
         Args::Parse { file } => {
-            let (doc, _) = load_file(&file)?;
+            let (doc, _) = load_file(&file, &FstDictionary::curated())?;
 
             for token in doc.tokens() {
                 let json = serde_json::to_string(&token)?;
@@ -98,22 +287,276 @@ fn main() -> anyhow::Result<()> {
 
             Ok(())
         }
+        Args::CompileDict {
+            output,
+            hunspell,
+            dialect,
+        } => {
+            let dict = match &hunspell {
+                Some(dic_path) => {
+                    let dict = FstDictionary::from_hunspell_files(
+                        dic_path.with_extension("aff"),
+                        dic_path,
+                    )
+                    .map_err(|err| format_err!("Unable to load {}: {}", dic_path.display(), err))?;
+                    Arc::new(dict)
+                }
+                None => Arc::new(FstDictionary::compile_curated(dialect)),
+            };
+
+            let metadata_path = output.with_extension("meta");
+
+            // Both files are written next to their destinations and then
+            // renamed over them, so processes that have the old ones mapped
+            // into memory keep reading the old ones.
+            let fst_temp_path = output.with_extension("fst.tmp");
+            let metadata_temp_path = output.with_extension("meta.tmp");
+
+            let mut fst_file = BufWriter::new(File::create(&fst_temp_path)?);
+            let mut metadata_file = BufWriter::new(File::create(&metadata_temp_path)?);
+
+            dict.write_compiled(&mut fst_file, &mut metadata_file)?;
+
+            fst_file.flush()?;
+            metadata_file.flush()?;
+
+            std::fs::rename(&fst_temp_path, &output)?;
+            std::fs::rename(&metadata_temp_path, &metadata_path)?;
+
+            println!(
+                "Compiled {} words into {} and {}",
+                dict.words_iter().count(),
+                output.display(),
+                metadata_path.display()
+            );
+
+            Ok(())
+        }
+    }
+}
+
+/// Load the curated dictionary for a dialect, along with the project
+/// dictionary, Hunspell dictionaries and compiled dictionaries if any are
+/// configured.
+fn load_dictionary(
+    project_config: Option<&ProjectConfig>,
+    dialect: Dialect,
+) -> anyhow::Result<MergedDictionary> {
+    let mut dictionary = MergedDictionary::new();
+    dictionary.add_dictionary(FstDictionary::curated_dialect(dialect));
+
+    if let Some(path) = project_config.and_then(|config| config.dictionary_path()) {
+        let word_list = std::fs::read_to_string(&path)
+            .map_err(|err| format_err!("Unable to read {}: {}", path.display(), err))?;
+
+        let (project_dictionary, warnings) = FullDictionary::from_word_list(&word_list);
+
+        for warning in warnings {
+            eprintln!("{}: {}", path.display(), warning);
+        }
+
+        dictionary.add_dictionary(Arc::new(project_dictionary));
+    }
+
+    for (aff_path, dic_path) in project_config
+        .map(|config| config.hunspell_dictionary_paths())
+        .unwrap_or_default()
+    {
+        let hunspell_dictionary = FstDictionary::from_hunspell_files(&aff_path, &dic_path)
+            .map_err(|err| format_err!("Unable to load {}: {}", dic_path.display(), err))?;
+
+        dictionary.add_dictionary(Arc::new(hunspell_dictionary));
     }
+
+    for (fst_path, metadata_path) in project_config
+        .map(|config| config.compiled_dictionary_paths())
+        .unwrap_or_default()
+    {
+        let compiled_dictionary = FstDictionary::load_compiled(&fst_path, &metadata_path)
+            .map_err(|err| format_err!("Unable to load {}: {}", fst_path.display(), err))?;
+
+        dictionary.add_dictionary(Arc::new(compiled_dictionary));
+    }
+
+    Ok(dictionary)
 }
 
-fn load_file(file: &Path) -> anyhow::Result<(Document, String)> {
-    let source = std::fs::read_to_string(file)?;
-
-    let mut parser: Box<dyn harper_core::parsers::Parser> =
-        if let Some("md") = file.extension().map(|v| v.to_str().unwrap()) {
-            Box::new(Markdown)
-        } else {
-            Box::new(
-                CommentParser::new_from_filename(file)
-                    .map(Box::new)
-                    .ok_or(format_err!("Could not detect language ID."))?,
-            )
+/// Find the files to lint, along with the configuration and dictionary each
+/// one should be linted with.
+fn plan_jobs(args: &FileArgs) -> anyhow::Result<Vec<LintJob>> {
+    let files = find_files(&args.paths, &args.include, &args.exclude)?;
+
+    let mut project_configs = HashMap::new();
+    let mut dictionaries = HashMap::new();
+    let mut replacement_rules = HashMap::new();
+    let mut jobs = Vec::new();
+
+    let user_rules = match &args.replacement_rules {
+        Some(path) => load_replacement_rules(path)?,
+        None => ReplacementRules::new(),
+    };
+
+    let lint_overrides = parse_lint_overrides(&args.set)?;
+
+    for file in files {
+        let dir = file.parent().unwrap_or(Path::new("")).to_path_buf();
+
+        let project_config = match project_configs.get(&dir) {
+            Some(config) => Option::clone(config),
+            None => {
+                let config = ProjectConfig::discover(&file)?.map(Arc::new);
+                project_configs.insert(dir, config.clone());
+                config
+            }
+        };
+
+        if project_config
+            .as_ref()
+            .is_some_and(|config| config.is_ignored(&file))
+        {
+            continue;
+        }
+
+        let root = project_config.as_ref().map(|c| c.root().to_path_buf());
+
+        let mut lint_config = LintGroupConfig::default();
+        if let Some(project_config) = &project_config {
+            lint_config.override_with(&project_config.linters_for(language_id_for(&file)));
+        }
+        lint_config.override_with(&lint_overrides);
+
+        // The dialect can be set for each language, so it is part of the key.
+        let dialect = lint_config.dialect.unwrap_or_default();
+        let dictionary = match dictionaries.get(&(root.clone(), dialect)) {
+            Some(dictionary) => Arc::clone(dictionary),
+            None => {
+                let dictionary = Arc::new(load_dictionary(project_config.as_deref(), dialect)?);
+                dictionaries.insert((root.clone(), dialect), dictionary.clone());
+                dictionary
+            }
         };
 
-    Ok((Document::new_curated(&source, &mut parser), source))
+        let rules = match replacement_rules.get(&root) {
+            Some(rules) => Arc::clone(rules),
+            None => {
+                let mut rules = user_rules.clone();
+
+                if let Some(path) = project_config
+                    .as_ref()
+                    .and_then(|config| config.replacement_rules_path())
+                {
+                    rules.extend(load_replacement_rules(&path)?);
+                }
+
+                let rules = Arc::new(rules);
+                replacement_rules.insert(root, rules.clone());
+                rules
+            }
+        };
+
+        let severity = project_config
+            .as_ref()
+            .map(|config| config.severity.clone())
+            .unwrap_or_default();
+
+        jobs.push(LintJob {
+            file,
+            lint_config,
+            severity,
+            dictionary,
+            replacement_rules: rules,
+        });
+    }
+
+    Ok(jobs)
+}
+
+fn load_replacement_rules(path: &Path) -> anyhow::Result<ReplacementRules> {
+    ReplacementRules::load(path).map_err(|err| format_err!("{} ({})", err, path.display()))
+}
+
+/// Lint a single file.
+///
+/// Overlapping lints are kept, so callers should use [`remove_overlaps`]
+/// before displaying or fixing them.
+fn lint_file(job: &LintJob) -> anyhow::Result<FileReport> {
+    let (doc, source) = load_file(&job.file, &job.dictionary)?;
+
+    let mut linter = LintGroup::new(job.lint_config.clone(), job.dictionary.clone());
+    linter.replacement_rules = ReplacementRules::clone(&job.replacement_rules);
+    let lints = linter.lint(&doc);
+
+    Ok(FileReport {
+        path: job.file.clone(),
+        source,
+        lints,
+        severity: job.severity.clone(),
+    })
+}
+
+/// Rules whose first suggestion is only a guess, so they are only fixed when
+/// asked for by name.
+const GUESSED_FIX_RULES: [&str; 1] = ["SpellCheck"];
+
+/// Apply fixes to each file, either writing them back to disk or printing
+/// them as a diff.
+///
+/// If `rules` is not empty, only lints from those rules are fixed.
+/// Otherwise, everything but [`GUESSED_FIX_RULES`] is fixed.
+/// Returns the total number of fixes.
+fn fix_files(jobs: &[LintJob], rules: &[String], dry_run: bool) -> anyhow::Result<usize> {
+    let fixes = jobs
+        .par_iter()
+        .map(|job| {
+            let mut report = lint_file(job)?;
+
+            if rules.is_empty() {
+                report
+                    .lints
+                    .retain(|lint| !GUESSED_FIX_RULES.iter().any(|rule| lint.is_from_rule(rule)));
+            } else {
+                report
+                    .lints
+                    .retain(|lint| rules.iter().any(|rule| lint.is_from_rule(rule)));
+            }
+
+            remove_overlaps(&mut report.lints);
+
+            let source: Vec<char> = report.source.chars().collect();
+            let (fixed, applied) = apply_fixes(&source, &report.lints);
+            let fixed: String = fixed.into_iter().collect();
+
+            if applied > 0 && !dry_run {
+                std::fs::write(&job.file, &fixed).map_err(|err| {
+                    format_err!("Unable to write {}: {}", job.file.display(), err)
+                })?;
+            }
+
+            Ok((job, report.source, fixed, applied))
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let mut total = 0;
+
+    for (job, original, fixed, applied) in fixes {
+        if dry_run && applied > 0 {
+            print!("{}", unified_diff(&job.file, &original, &fixed));
+        }
+
+        total += applied;
+    }
+
+    Ok(total)
+}
+
+fn load_file(file: &Path, dictionary: &impl Dictionary) -> anyhow::Result<(Document, String)> {
+    let source = std::fs::read_to_string(file)
+        .map_err(|err| format_err!("Unable to read {}: {}", file.display(), err))?;
+
+    let mut parser = parser_for(file).ok_or(format_err!(
+        "Could not detect language ID for {}.",
+        file.display()
+    ))?;
+
+    Ok((Document::new(&source, &mut parser, dictionary), source))
 }
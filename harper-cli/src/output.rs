@@ -0,0 +1,494 @@
+//! Renders lints in the formats supported by `harper-cli lint --format`.
+
+use std::path::PathBuf;
+
+use ariadne::{Color, Label, Report, ReportKind, Source};
+use clap::ValueEnum;
+use harper_core::linting::{Lint, LintKind, Suggestion};
+use harper_core::{Severity, SeverityOverrides, Span};
+use serde::Serialize;
+use serde_json::json;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Annotated source code, meant for humans.
+    #[default]
+    Pretty,
+    /// A JSON array of lints.
+    Json,
+    /// A SARIF 2.1.0 log, for code scanning dashboards.
+    Sarif,
+    /// Checkstyle XML.
+    Checkstyle,
+    /// GitHub Actions workflow commands, which annotate pull requests.
+    Github,
+}
+
+/// The lints found in a single file.
+pub struct FileReport {
+    pub path: PathBuf,
+    pub source: String,
+    pub lints: Vec<Lint>,
+    pub severity: SeverityOverrides,
+}
+
+impl FileReport {
+    /// Lints without an overridden severity are treated as warnings.
+    pub fn severity_for(&self, lint: &Lint) -> Severity {
+        self.severity.get(lint).unwrap_or(Severity::Warning)
+    }
+
+    fn filename(&self) -> String {
+        self.path.to_string_lossy().into()
+    }
+}
+
+/// A one-indexed position in a file.
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+}
+
+/// Converts character indices into [`Position`]s.
+pub struct LineIndex {
+    /// The index of the first character of each line.
+    line_starts: Vec<usize>,
+}
+
+impl LineIndex {
+    pub fn new(source: &[char]) -> Self {
+        let mut line_starts = vec![0];
+
+        line_starts.extend(
+            source
+                .iter()
+                .enumerate()
+                .filter(|(_, c)| **c == '\n')
+                .map(|(idx, _)| idx + 1),
+        );
+
+        Self { line_starts }
+    }
+
+    pub fn position(&self, index: usize) -> Position {
+        let line = self.line_starts.partition_point(|s| *s <= index) - 1;
+
+        Position {
+            line: line + 1,
+            column: index - self.line_starts[line] + 1,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct LintRecord {
+    file: String,
+    start: Position,
+    end: Position,
+    span: Span,
+    rule: Option<String>,
+    kind: LintKind,
+    severity: Severity,
+    message: String,
+    suggestions: Vec<String>,
+}
+
+fn suggestion_text(suggestion: &Suggestion) -> String {
+    match suggestion {
+        Suggestion::ReplaceWith(with) => with.iter().collect(),
+        Suggestion::Remove => String::new(),
+    }
+}
+
+fn to_records(reports: &[FileReport]) -> Vec<LintRecord> {
+    let mut records = Vec::new();
+
+    for report in reports {
+        let source: Vec<char> = report.source.chars().collect();
+        let index = LineIndex::new(&source);
+
+        for lint in &report.lints {
+            records.push(LintRecord {
+                file: report.filename(),
+                start: index.position(lint.span.start),
+                end: index.position(lint.span.end),
+                span: lint.span,
+                rule: lint.rule.clone(),
+                kind: lint.lint_kind,
+                severity: report.severity_for(lint),
+                message: lint.message.clone(),
+                suggestions: lint.suggestions.iter().map(suggestion_text).collect(),
+            })
+        }
+    }
+
+    records
+}
+
+/// Print the lints from each report to standard output.
+pub fn print_reports(reports: &[FileReport], format: OutputFormat) -> anyhow::Result<()> {
+    match format {
+        OutputFormat::Pretty => print_pretty(reports)?,
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&to_records(reports))?),
+        OutputFormat::Sarif => println!("{}", serde_json::to_string_pretty(&to_sarif(reports))?),
+        OutputFormat::Checkstyle => print!("{}", to_checkstyle(reports)),
+        OutputFormat::Github => print!("{}", to_github(reports)),
+    }
+
+    Ok(())
+}
+
+/// Print the number of lints found in each file, along with the total.
+///
+/// To keep machine-readable output parsable, the summary is printed to
+/// standard error unless the format is [`OutputFormat::Pretty`].
+pub fn print_summary(reports: &[FileReport], format: OutputFormat) {
+    let summary = to_summary(reports);
+
+    if format == OutputFormat::Pretty {
+        print!("{}", summary);
+    } else {
+        eprint!("{}", summary);
+    }
+}
+
+fn to_summary(reports: &[FileReport]) -> String {
+    let mut output = String::new();
+
+    for report in reports {
+        output.push_str(&format!(
+            "{}: {}\n",
+            report.filename(),
+            pluralize_lints(report.lints.len())
+        ));
+    }
+
+    let total: usize = reports.iter().map(|r| r.lints.len()).sum();
+    let files_with_lints = reports.iter().filter(|r| !r.lints.is_empty()).count();
+
+    output.push_str(&format!(
+        "Found {} in {} of {} {}.\n",
+        pluralize_lints(total),
+        files_with_lints,
+        reports.len(),
+        if reports.len() == 1 { "file" } else { "files" }
+    ));
+
+    output
+}
+
+pub fn pluralize_lints(count: usize) -> String {
+    if count == 1 {
+        "1 lint".to_string()
+    } else {
+        format!("{} lints", count)
+    }
+}
+
+fn print_pretty(reports: &[FileReport]) -> anyhow::Result<()> {
+    for report in reports {
+        if report.lints.is_empty() {
+            continue;
+        }
+
+        let filename = report.filename();
+
+        let kind = match report
+            .lints
+            .iter()
+            .map(|lint| report.severity_for(lint))
+            .min()
+        {
+            Some(Severity::Error) => ReportKind::Error,
+            Some(Severity::Warning) => ReportKind::Warning,
+            _ => ReportKind::Advice,
+        };
+
+        let mut report_builder = Report::build(kind, &filename, 0);
+
+        for lint in &report.lints {
+            let message = match &lint.rule {
+                Some(rule) => format!("{} ({})", lint.message, rule),
+                None => lint.message.clone(),
+            };
+
+            report_builder = report_builder.with_label(
+                Label::new((&filename, lint.span.into()))
+                    .with_message(message)
+                    .with_color(severity_color(report.severity_for(lint))),
+            );
+        }
+
+        report_builder
+            .finish()
+            .print((&filename, Source::from(&report.source)))?;
+    }
+
+    Ok(())
+}
+
+fn severity_color(severity: Severity) -> Color {
+    match severity {
+        Severity::Error => Color::Red,
+        Severity::Warning => Color::Yellow,
+        Severity::Information => Color::Blue,
+        Severity::Hint => Color::Magenta,
+    }
+}
+
+fn to_sarif(reports: &[FileReport]) -> serde_json::Value {
+    let records = to_records(reports);
+
+    let mut rules: Vec<&str> = records.iter().filter_map(|r| r.rule.as_deref()).collect();
+    rules.sort();
+    rules.dedup();
+
+    let results: Vec<_> = records
+        .iter()
+        .map(|record| {
+            let region = json!({
+                "startLine": record.start.line,
+                "startColumn": record.start.column,
+                "endLine": record.end.line,
+                "endColumn": record.end.column,
+            });
+
+            let level = match record.severity {
+                Severity::Error => "error",
+                Severity::Warning => "warning",
+                Severity::Information | Severity::Hint => "note",
+            };
+
+            let fixes: Vec<_> = record
+                .suggestions
+                .iter()
+                .map(|suggestion| {
+                    json!({
+                        "description": { "text": format!("Replace with “{}”", suggestion) },
+                        "artifactChanges": [{
+                            "artifactLocation": { "uri": record.file },
+                            "replacements": [{
+                                "deletedRegion": region,
+                                "insertedContent": { "text": suggestion },
+                            }],
+                        }],
+                    })
+                })
+                .collect();
+
+            json!({
+                "ruleId": record.rule,
+                "level": level,
+                "message": { "text": record.message },
+                "locations": [{
+                    "physicalLocation": {
+                        "artifactLocation": { "uri": record.file },
+                        "region": region,
+                    },
+                }],
+                "fixes": fixes,
+            })
+        })
+        .collect();
+
+    json!({
+        "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "Harper",
+                    "informationUri": "https://writewithharper.com",
+                    "rules": rules.iter().map(|id| json!({ "id": id })).collect::<Vec<_>>(),
+                },
+            },
+            "columnKind": "unicodeCodePoints",
+            "results": results,
+        }],
+    })
+}
+
+fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+fn to_checkstyle(reports: &[FileReport]) -> String {
+    let mut output = String::new();
+
+    output.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    output.push_str("<checkstyle version=\"4.3\">\n");
+
+    for report in reports {
+        let source: Vec<char> = report.source.chars().collect();
+        let index = LineIndex::new(&source);
+
+        output.push_str(&format!(
+            "  <file name=\"{}\">\n",
+            escape_xml(&report.filename())
+        ));
+
+        for lint in &report.lints {
+            let start = index.position(lint.span.start);
+
+            let severity = match report.severity_for(lint) {
+                Severity::Error => "error",
+                Severity::Warning => "warning",
+                Severity::Information | Severity::Hint => "info",
+            };
+
+            output.push_str(&format!(
+                "    <error line=\"{}\" column=\"{}\" severity=\"{}\" message=\"{}\" source=\"harper.{}\"/>\n",
+                start.line,
+                start.column,
+                severity,
+                escape_xml(&lint.message),
+                escape_xml(lint.rule.as_deref().unwrap_or("Unknown"))
+            ));
+        }
+
+        output.push_str("  </file>\n");
+    }
+
+    output.push_str("</checkstyle>\n");
+
+    output
+}
+
+/// Escapes text according to the rules for GitHub workflow commands.
+fn escape_github(text: &str, is_property: bool) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '%' => escaped.push_str("%25"),
+            '\r' => escaped.push_str("%0D"),
+            '\n' => escaped.push_str("%0A"),
+            ':' if is_property => escaped.push_str("%3A"),
+            ',' if is_property => escaped.push_str("%2C"),
+            _ => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+fn to_github(reports: &[FileReport]) -> String {
+    let mut output = String::new();
+
+    for record in to_records(reports) {
+        let command = match record.severity {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+            Severity::Information | Severity::Hint => "notice",
+        };
+
+        let title = match &record.rule {
+            Some(rule) => format!("Harper ({})", rule),
+            None => "Harper".to_string(),
+        };
+
+        output.push_str(&format!(
+            "::{} file={},line={},col={},endLine={},endColumn={},title={}::{}\n",
+            command,
+            escape_github(&record.file, true),
+            record.start.line,
+            record.start.column,
+            record.end.line,
+            record.end.column,
+            escape_github(&title, true),
+            escape_github(&record.message, false)
+        ));
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use harper_core::linting::{Lint, Suggestion};
+    use harper_core::{SeverityOverrides, Span};
+
+    use super::{
+        escape_github, escape_xml, to_checkstyle, to_records, to_summary, FileReport, LineIndex,
+    };
+
+    fn example_report() -> FileReport {
+        FileReport {
+            path: "docs/README.md".into(),
+            source: "First line.\nThis is a tesst.".to_string(),
+            lints: vec![Lint {
+                span: Span::new(22, 27),
+                message: "Did you mean to spell “tesst” this way?".to_string(),
+                suggestions: vec![Suggestion::ReplaceWith("test".chars().collect())],
+                rule: Some("SpellCheck".to_string()),
+                ..Default::default()
+            }],
+            severity: SeverityOverrides::new(),
+        }
+    }
+
+    #[test]
+    fn maps_indices_to_positions() {
+        let source: Vec<char> = "ab\ncd\n\nef".chars().collect();
+        let index = LineIndex::new(&source);
+
+        assert_eq!(index.position(0).line, 1);
+        assert_eq!(index.position(0).column, 1);
+        assert_eq!(index.position(4).line, 2);
+        assert_eq!(index.position(4).column, 2);
+        assert_eq!(index.position(7).line, 4);
+        assert_eq!(index.position(7).column, 1);
+    }
+
+    #[test]
+    fn records_include_positions_and_suggestions() {
+        let records = to_records(&[example_report()]);
+
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].start.line, 2);
+        assert_eq!(records[0].start.column, 11);
+        assert_eq!(records[0].suggestions, vec!["test".to_string()]);
+    }
+
+    #[test]
+    fn summarizes_each_file() {
+        let clean = FileReport {
+            path: "docs/clean.md".into(),
+            source: "This is fine.".to_string(),
+            lints: Vec::new(),
+            severity: SeverityOverrides::new(),
+        };
+
+        assert_eq!(
+            to_summary(&[example_report(), clean]),
+            "docs/README.md: 1 lint\ndocs/clean.md: 0 lints\nFound 1 lint in 1 of 2 files.\n"
+        );
+    }
+
+    #[test]
+    fn checkstyle_is_escaped() {
+        assert_eq!(escape_xml("<a & \"b\">"), "&lt;a &amp; &quot;b&quot;&gt;");
+        assert!(to_checkstyle(&[example_report()]).contains("source=\"harper.SpellCheck\""));
+    }
+
+    #[test]
+    fn github_properties_are_escaped() {
+        assert_eq!(escape_github("a:b,c", true), "a%3Ab%2Cc");
+        assert_eq!(escape_github("a:b,c", false), "a:b,c");
+        assert_eq!(escape_github("100%\n", false), "100%25%0A");
+    }
+}
@@ -0,0 +1,193 @@
+//! Renders the readability statistics printed by `harper-cli stats`.
+
+use std::fmt::Write;
+use std::path::PathBuf;
+
+use clap::ValueEnum;
+use harper_core::readability::{ParagraphReadability, ReadabilityReport, ReadabilityScores};
+use serde::Serialize;
+
+use crate::output::LineIndex;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum StatsFormat {
+    /// A table of scores for each file and paragraph, meant for humans.
+    #[default]
+    Pretty,
+    /// A JSON array with the scores of each file, paragraph and sentence.
+    Json,
+}
+
+/// The readability of a single file.
+pub struct FileStats {
+    pub path: PathBuf,
+    pub source: String,
+    pub report: ReadabilityReport,
+}
+
+#[derive(Debug, Serialize)]
+struct ParagraphRecord<'a> {
+    line: usize,
+    #[serde(flatten)]
+    paragraph: &'a ParagraphReadability,
+}
+
+#[derive(Debug, Serialize)]
+struct StatsRecord<'a> {
+    file: String,
+    scores: &'a ReadabilityScores,
+    paragraphs: Vec<ParagraphRecord<'a>>,
+}
+
+fn to_records(stats: &[FileStats]) -> Vec<StatsRecord<'_>> {
+    stats
+        .iter()
+        .map(|file| {
+            let source: Vec<char> = file.source.chars().collect();
+            let index = LineIndex::new(&source);
+
+            StatsRecord {
+                file: file.path.to_string_lossy().into(),
+                scores: &file.report.scores,
+                paragraphs: file
+                    .report
+                    .paragraphs
+                    .iter()
+                    .map(|paragraph| ParagraphRecord {
+                        line: index.position(paragraph.span.start).line,
+                        paragraph,
+                    })
+                    .collect(),
+            }
+        })
+        .collect()
+}
+
+pub fn print_stats(stats: &[FileStats], format: StatsFormat) -> anyhow::Result<()> {
+    match format {
+        StatsFormat::Pretty => print!("{}", to_pretty(stats)),
+        StatsFormat::Json => println!("{}", serde_json::to_string_pretty(&to_records(stats))?),
+    }
+
+    Ok(())
+}
+
+/// Format a score to one decimal place, or a dash if there isn't one.
+fn format_score(score: Option<f32>) -> String {
+    match score {
+        Some(score) => format!("{:.1}", score),
+        None => "-".to_string(),
+    }
+}
+
+fn format_percentage(ratio: Option<f32>) -> String {
+    match ratio {
+        Some(ratio) => format!("{:.0}%", ratio * 100.0),
+        None => "-".to_string(),
+    }
+}
+
+fn to_pretty(stats: &[FileStats]) -> String {
+    let mut output = String::new();
+
+    for record in to_records(stats) {
+        let scores = record.scores;
+
+        writeln!(output, "{}", record.file).unwrap();
+        writeln!(
+            output,
+            "  {} words, {} sentences, {} syllables",
+            scores.counts.words, scores.counts.sentences, scores.counts.syllables
+        )
+        .unwrap();
+        writeln!(
+            output,
+            "  Flesch reading ease:     {}",
+            format_score(scores.flesch_reading_ease)
+        )
+        .unwrap();
+        writeln!(
+            output,
+            "  Flesch–Kincaid grade:    {}",
+            format_score(scores.flesch_kincaid_grade)
+        )
+        .unwrap();
+        writeln!(
+            output,
+            "  Gunning Fog index:       {}",
+            format_score(scores.gunning_fog)
+        )
+        .unwrap();
+        writeln!(
+            output,
+            "  Average sentence length: {}",
+            format_score(scores.average_sentence_length)
+        )
+        .unwrap();
+        writeln!(
+            output,
+            "  Passive sentences:       {}",
+            format_percentage(scores.passive_ratio)
+        )
+        .unwrap();
+
+        if !record.paragraphs.is_empty() {
+            writeln!(output, "  Paragraphs:").unwrap();
+        }
+
+        for paragraph in &record.paragraphs {
+            let scores = &paragraph.paragraph.scores;
+
+            writeln!(
+                output,
+                "    line {}: grade {}, fog {}, {} words per sentence, {} passive",
+                paragraph.line,
+                format_score(scores.flesch_kincaid_grade),
+                format_score(scores.gunning_fog),
+                format_score(scores.average_sentence_length),
+                format_percentage(scores.passive_ratio)
+            )
+            .unwrap();
+        }
+
+        writeln!(output).unwrap();
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use harper_core::readability::ReadabilityReport;
+    use harper_core::Document;
+
+    use super::{to_pretty, to_records, FileStats};
+
+    fn example_stats() -> FileStats {
+        let source = "# Title\n\nThe cat sat on the mat.\n\nThe bug was fixed. We shipped it.";
+
+        FileStats {
+            path: "docs/README.md".into(),
+            source: source.to_string(),
+            report: ReadabilityReport::new(&Document::new_markdown_curated(source)),
+        }
+    }
+
+    #[test]
+    fn records_paragraph_lines() {
+        let stats = [example_stats()];
+        let records = to_records(&stats);
+
+        let lines: Vec<usize> = records[0].paragraphs.iter().map(|p| p.line).collect();
+        assert_eq!(lines, vec![1, 3, 5]);
+    }
+
+    #[test]
+    fn pretty_output_lists_paragraphs() {
+        let output = to_pretty(&[example_stats()]);
+
+        assert!(output.starts_with("docs/README.md\n"));
+        assert!(output.contains("Passive sentences:       25%"));
+        assert!(output.contains("    line 5: "));
+    }
+}
@@ -10,6 +10,21 @@ use crate::comment_parsers;
 
 pub struct CommentParser {
     inner: parsers::Mask<TreeSitterMasker, Box<dyn Parser>>,
+    language_id: String,
+}
+
+/// Clones share the syntax tree of the original, so that a document can be
+/// parsed incrementally by whichever copy sees it next.
+impl Clone for CommentParser {
+    fn clone(&self) -> Self {
+        Self {
+            inner: parsers::Mask::new(
+                self.inner.masker.clone(),
+                Self::doc_comment_parser(&self.language_id),
+            ),
+            language_id: self.language_id.clone(),
+        }
+    }
 }
 
 impl CommentParser {
@@ -39,21 +54,25 @@ impl CommentParser {
             _ => return None,
         };
 
-        let comment_parser: Box<dyn Parser> = match language_id {
-            "javascriptreact" | "typescript" | "typescriptreact" | "javascript" => Box::new(JsDoc),
-            "java" => Box::new(JavaDoc::default()),
-            "go" => Box::new(Go),
-            _ => Box::new(Unit),
-        };
-
         Some(Self {
             inner: parsers::Mask::new(
                 TreeSitterMasker::new(language, Self::node_condition),
-                comment_parser,
+                Self::doc_comment_parser(language_id),
             ),
+            language_id: language_id.to_string(),
         })
     }
 
+    /// Get the parser for the contents of comments in a given language.
+    fn doc_comment_parser(language_id: &str) -> Box<dyn Parser> {
+        match language_id {
+            "javascriptreact" | "typescript" | "typescriptreact" | "javascript" => Box::new(JsDoc),
+            "java" => Box::new(JavaDoc::default()),
+            "go" => Box::new(Go),
+            _ => Box::new(Unit),
+        }
+    }
+
     /// Infer the programming language from a provided filename.
     pub fn new_from_filename(filename: &Path) -> Option<Self> {
         Self::new_from_language_id(Self::filename_to_filetype(filename)?)
@@ -64,7 +83,7 @@ impl CommentParser {
     ///
     /// Note to contributors: try to keep this in sync with
     /// [`Self::new_from_language_id`]
-    fn filename_to_filetype(path: &Path) -> Option<&'static str> {
+    pub fn filename_to_filetype(path: &Path) -> Option<&'static str> {
         Some(match path.extension()?.to_str()? {
             "py" => "python",
             "nix" => "nix",
@@ -0,0 +1,23 @@
+use harper_comments::CommentParser;
+use harper_core::parsers::StrParser;
+
+/// Reparsing an edited file with the same parser should produce exactly the
+/// same tokens as starting from scratch.
+#[test]
+fn incremental_parse_matches_fresh_parse() {
+    let before = "/// Adds two numbers.\nfn add(a: u32, b: u32) -> u32 {\n    a + b\n}\n";
+    let after = "/// Adds two numbers together.\nfn add(a: u32, b: u32) -> u32 {\n    // Just the sum.\n    a + b\n}\n";
+
+    let mut parser = CommentParser::new_from_language_id("rust").unwrap();
+    parser.parse_str(before);
+
+    let mut clone = parser.clone();
+    let incremental = clone.parse_str(after);
+
+    let mut fresh = CommentParser::new_from_language_id("rust").unwrap();
+    assert_eq!(incremental, fresh.parse_str(after));
+
+    // The original shares its tree with the clone, so this undoes the edit.
+    let mut fresh = CommentParser::new_from_language_id("rust").unwrap();
+    assert_eq!(parser.parse_str(before), fresh.parse_str(before));
+}
@@ -0,0 +1,14 @@
+// harper:ignore-next-line
+/// This comment has a tesst in it.
+fn first() {}
+
+/// This one has one too, but it is on purpose: tesst. // harper:ignore-line
+fn second() {}
+
+// harper:disable SpellCheck
+/// Ths whole block is ignred.
+fn third() {}
+// harper:enable SpellCheck
+
+/// This one is not ignored: tesst.
+fn fourth() {}
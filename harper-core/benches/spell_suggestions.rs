@@ -0,0 +1,33 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use harper_core::{suggest_correct_spelling_str, FstDictionary};
+
+static TYPOS: &str = include_str!("../tests/typos.txt");
+
+fn suggest_for_typos(c: &mut Criterion) {
+    let dictionary = FstDictionary::curated();
+    let typos: Vec<&str> = TYPOS
+        .lines()
+        .filter(|line| !line.starts_with('#'))
+        .filter_map(|line| line.split_once(' ').map(|(typo, _)| typo))
+        .collect();
+
+    c.bench_function("suggest_for_typos", |b| {
+        b.iter(|| {
+            for typo in &typos {
+                black_box(suggest_correct_spelling_str(
+                    black_box(*typo),
+                    100,
+                    2,
+                    &dictionary,
+                ));
+            }
+        })
+    });
+}
+
+pub fn criterion_benchmark(c: &mut Criterion) {
+    suggest_for_typos(c);
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
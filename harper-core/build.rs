@@ -0,0 +1,110 @@
+//! Compiles the curated dictionary for each dialect, so Harper can load it
+//! at startup without expanding and sorting its words.
+//!
+//! The modules that build the curated word list are included from `src`
+//! under a crate root of their own, so the dictionary is always compiled
+//! from the same code and sources Harper uses at runtime.
+
+#![allow(dead_code)]
+
+#[path = "src/char_string.rs"]
+mod char_string;
+#[path = "src/dialect.rs"]
+mod dialect;
+#[path = "src/span.rs"]
+mod span;
+#[path = "src/word_metadata.rs"]
+mod word_metadata;
+
+#[path = "src/spell"]
+mod spell {
+    pub mod compiled;
+    mod dialect_spellings;
+    #[path = "hunspell/mod.rs"]
+    mod hunspell;
+
+    mod inflection {
+        mod forms;
+
+        pub(crate) use forms::annotate_inflections;
+    }
+}
+
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+pub use char_string::{CharString, CharStringExt};
+pub use dialect::Dialect;
+pub use span::Span;
+use spell::compiled::{curated_words, CompiledMetadata, CompiledWords};
+pub use word_metadata::{NounData, VerbData, VerbForm, WordMetadata};
+
+/// The files the curated dictionary is compiled from.
+const SOURCES: [&str; 20] = [
+    "build.rs",
+    "dictionary.dict",
+    "affixes.json",
+    "irregular_forms.json",
+    "dialects.json",
+    "src/char_string.rs",
+    "src/dialect.rs",
+    "src/span.rs",
+    "src/word_metadata.rs",
+    "src/spell/compiled.rs",
+    "src/spell/dialect_spellings.rs",
+    "src/spell/inflection/forms.rs",
+    "src/spell/hunspell/mod.rs",
+    "src/spell/hunspell/aff_file.rs",
+    "src/spell/hunspell/affix_replacement.rs",
+    "src/spell/hunspell/attribute_list.rs",
+    "src/spell/hunspell/error.rs",
+    "src/spell/hunspell/expansion.rs",
+    "src/spell/hunspell/matcher.rs",
+    "src/spell/hunspell/word_list.rs",
+];
+
+fn main() {
+    for source in SOURCES {
+        println!("cargo:rerun-if-changed={source}");
+    }
+
+    let out_dir = std::env::var("OUT_DIR").expect("Cargo always sets OUT_DIR for build scripts.");
+
+    for dialect in Dialect::ALL {
+        let name = dialect.to_string().to_lowercase();
+        let out_dir = Path::new(&out_dir);
+
+        write_dictionary(
+            dialect,
+            &out_dir.join(format!("{name}.fst")),
+            &out_dir.join(format!("{name}.meta")),
+        )
+        .unwrap_or_else(|err| panic!("Unable to write the {name} dictionary: {err}"));
+    }
+}
+
+/// Compile the curated dictionary for `dialect` the way
+/// `FstDictionary::write_compiled` would.
+fn write_dictionary(
+    dialect: Dialect,
+    fst_path: &Path,
+    metadata_path: &Path,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let CompiledWords {
+        fst,
+        words,
+        metadata,
+    } = CompiledWords::new(curated_words(dialect));
+
+    std::fs::write(fst_path, &fst)?;
+
+    let mut metadata_file = BufWriter::new(File::create(metadata_path)?);
+    serde_json::to_writer(
+        &mut metadata_file,
+        &CompiledMetadata::new(&fst, words.len(), metadata),
+    )?;
+    metadata_file.flush()?;
+
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+/// A regional variety of English, which decides the spellings Harper
+/// accepts.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Dialect {
+    #[default]
+    #[serde(alias = "en-US")]
+    American,
+    #[serde(alias = "en-GB")]
+    British,
+    #[serde(alias = "en-AU")]
+    Australian,
+    #[serde(alias = "en-CA")]
+    Canadian,
+}
+
+impl Dialect {
+    pub const ALL: [Dialect; 4] = [
+        Dialect::American,
+        Dialect::British,
+        Dialect::Australian,
+        Dialect::Canadian,
+    ];
+}
+
+impl Display for Dialect {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Dialect::American => "American",
+            Dialect::British => "British",
+            Dialect::Australian => "Australian",
+            Dialect::Canadian => "Canadian",
+        };
+
+        write!(f, "{}", s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Dialect;
+
+    #[test]
+    fn accepts_language_tags() {
+        assert_eq!(
+            serde_json::from_str::<Dialect>(r#""en-GB""#).unwrap(),
+            Dialect::British
+        );
+        assert_eq!(
+            serde_json::from_str::<Dialect>(r#""canadian""#).unwrap(),
+            Dialect::Canadian
+        );
+    }
+}
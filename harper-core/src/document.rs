@@ -6,6 +6,7 @@ use paste::paste;
 
 use crate::parsers::{Markdown, Parser, PlainEnglish};
 use crate::patterns::{PatternExt, RepeatingPattern, SequencePattern};
+use crate::pos_tagger::tag_parts_of_speech;
 use crate::punctuation::Punctuation;
 use crate::token::NumberSuffix;
 use crate::vec_ext::VecExt;
@@ -81,6 +82,137 @@ impl Document {
         Self::new(text, &mut Markdown, dictionary)
     }
 
+    /// Replace the text within `span` with `replacement`, re-lexing and
+    /// re-parsing only the paragraphs that the edit touches.
+    ///
+    /// If those paragraphs can't be parsed in isolation, because they belong to
+    /// a larger construct like a code block containing blank lines, the whole
+    /// document is parsed again instead.
+    ///
+    /// A span that reaches past the end of the document is clamped to it.
+    pub fn apply_edit(
+        &mut self,
+        span: Span,
+        replacement: &[char],
+        parser: &mut impl Parser,
+        dictionary: &impl Dictionary,
+    ) {
+        let end = span.end.min(self.source.len());
+        let span = Span::new(span.start.min(end), end);
+
+        let mut source = self.source.to_vec();
+        source.splice(span.start..span.end, replacement.iter().copied());
+        let source = Lrc::new(source);
+
+        // The paragraph breaks closest to the edit, without being touched by it,
+        // bound the tokens that have to be parsed again.
+        let first_tok = self
+            .tokens
+            .iter()
+            .rposition(|t| t.kind.is_paragraph_break() && t.span.end < span.start)
+            .map_or(0, |i| i + 1);
+        let last_tok = self.tokens[first_tok..]
+            .iter()
+            .position(|t| t.kind.is_paragraph_break() && t.span.start > span.end)
+            .map_or(self.tokens.len(), |i| first_tok + i);
+
+        // Between paragraphs, the region only extends as far as the tokens (or the
+        // edit) do, since any markup surrounding them may belong to a neighbour.
+        let region_tokens = &self.tokens[first_tok..last_tok];
+
+        let region_start = if first_tok == 0 {
+            0
+        } else {
+            region_tokens
+                .first()
+                .map_or(span.start, |t| t.span.start.min(span.start))
+        };
+        let old_region_end = if last_tok == self.tokens.len() {
+            self.source.len()
+        } else {
+            region_tokens
+                .last()
+                .map_or(span.end, |t| t.span.end.max(span.end))
+        };
+        let new_region_end = old_region_end - span.len() + replacement.len();
+
+        // Make sure the region parses the same way in isolation as it did as part
+        // of the whole document.
+        let old_region = Self::new_from_vec(
+            Lrc::new(self.source[region_start..old_region_end].to_vec()),
+            parser,
+            dictionary,
+        );
+        let isolated = old_region.tokens.len() == region_tokens.len()
+            && old_region.tokens.iter().zip(region_tokens).all(|(a, b)| {
+                let mut b = without_quote_twin(*b);
+                b.span.pull_by(region_start);
+                without_quote_twin(*a) == b
+            });
+
+        let new_region = Self::new_from_vec(
+            Lrc::new(source[region_start..new_region_end].to_vec()),
+            parser,
+            dictionary,
+        );
+
+        // An unterminated construct (like a code fence) may extend into the
+        // paragraphs that follow.
+        let spills_over = last_tok < self.tokens.len()
+            && new_region
+                .tokens
+                .last()
+                .is_some_and(|t| t.kind.is_unlintable());
+
+        if !isolated || spills_over {
+            *self = Self::new_from_vec(source, parser, dictionary);
+            return;
+        }
+
+        for token in &mut self.tokens[last_tok..] {
+            token.span.pull_by(span.len());
+            token.span.push_by(replacement.len());
+        }
+
+        self.tokens.splice(
+            first_tok..last_tok,
+            new_region.tokens.into_iter().map(|mut t| {
+                t.span.push_by(region_start);
+                t
+            }),
+        );
+        self.source = source;
+
+        // Quotes refer to each other by index, which the edit may have changed.
+        for token in &mut self.tokens {
+            *token = without_quote_twin(*token);
+        }
+        self.match_quotes();
+    }
+
+    /// Create a document containing only the text covered by some of the
+    /// tokens of this one, with their spans moved to start from zero.
+    ///
+    /// Returns [`None`] if `tokens` is empty.
+    pub(crate) fn excerpt(&self, tokens: &[Token]) -> Option<Self> {
+        let span = tokens.span()?;
+
+        let mut document = Self {
+            source: Lrc::new(self.get_span_content(span).to_vec()),
+            tokens: tokens
+                .iter()
+                .copied()
+                .map(|mut token| {
+                    token.span.pull_by(span.start);
+                    without_quote_twin(token)
+                })
+                .collect(),
+        };
+        document.match_quotes();
+
+        Some(document)
+    }
+
     /// Re-parse important language constructs.
     ///
     /// Should be run after every change to the underlying [`Self::source`].
@@ -101,6 +233,8 @@ impl Document {
                 *meta = meta.or(&found_meta);
             }
         }
+
+        tag_parts_of_speech(&mut self.tokens, &self.source);
     }
 
     /// Convert all sets of newlines greater than 2 to paragraph breaks.
@@ -538,6 +672,16 @@ impl TokenStringExt for Document {
     }
 }
 
+/// Forget which quote a token is paired with, since that depends on the tokens
+/// around it.
+fn without_quote_twin(mut token: Token) -> Token {
+    if let Some(quote) = token.kind.as_mut_quote() {
+        quote.twin_loc = None;
+    }
+
+    token
+}
+
 impl Display for Document {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         for token in &self.tokens {
@@ -553,7 +697,8 @@ mod tests {
     use itertools::Itertools;
 
     use super::Document;
-    use crate::Span;
+    use crate::parsers::{Markdown, Parser, PlainEnglish};
+    use crate::{FstDictionary, Span};
 
     fn assert_condensed_contractions(text: &str, final_tok_count: usize) {
         let document = Document::new_plain_english_curated(text);
@@ -651,4 +796,88 @@ mod tests {
     fn parses_short_ellipsis() {
         assert_token_count("..", 1);
     }
+
+    /// Apply an edit incrementally and make sure the result is identical to
+    /// parsing the edited text from scratch.
+    fn assert_edit_matches(text: &str, find: &str, replace: &str, parser: &mut impl Parser) {
+        let dict = FstDictionary::curated();
+
+        let byte_start = text.find(find).unwrap();
+        let start = text[..byte_start].chars().count();
+        let span = Span::new(start, start + find.chars().count());
+        let replacement: Vec<char> = replace.chars().collect();
+
+        let mut document = Document::new(text, parser, &dict);
+        document.apply_edit(span, &replacement, parser, &dict);
+
+        let edited = text.replacen(find, replace, 1);
+        let expected = Document::new(&edited, parser, &dict);
+
+        assert_eq!(document.get_full_string(), edited);
+        assert_eq!(document.tokens, expected.tokens);
+    }
+
+    const PARAGRAPHS: &str =
+        "This is the first paragraph.\n\nThis is \"the\" second one.\n\nAnd \"this\" is the third.";
+
+    #[test]
+    fn edits_middle_paragraph() {
+        assert_edit_matches(PARAGRAPHS, "second", "2nd", &mut PlainEnglish);
+    }
+
+    #[test]
+    fn edits_first_and_last_paragraphs() {
+        assert_edit_matches(PARAGRAPHS, "first", "very first", &mut PlainEnglish);
+        assert_edit_matches(PARAGRAPHS, "third.", "third...", &mut PlainEnglish);
+    }
+
+    #[test]
+    fn splits_and_joins_paragraphs() {
+        assert_edit_matches(PARAGRAPHS, "is \"the\"", "is\n\n\"the\"", &mut PlainEnglish);
+        assert_edit_matches(PARAGRAPHS, "one.\n\nAnd", "one and", &mut PlainEnglish);
+    }
+
+    #[test]
+    fn edits_quotes() {
+        assert_edit_matches(PARAGRAPHS, "\"the\"", "the\"", &mut PlainEnglish);
+    }
+
+    #[test]
+    fn edits_markdown() {
+        let text = "# A Heading\n\nSome *emphasized* text.\n\n- A list\n- of items\n\nThe end.";
+
+        assert_edit_matches(text, "emphasized", "strong", &mut Markdown);
+        assert_edit_matches(text, "Some", "## Some", &mut Markdown);
+        assert_edit_matches(text, "of items", "of many items", &mut Markdown);
+        assert_edit_matches(text, "The end.", "The end!\n\nNot quite.", &mut Markdown);
+    }
+
+    #[test]
+    fn clamps_edits_past_the_end() {
+        let dict = FstDictionary::curated();
+        let mut document = Document::new_plain_english("Hello there.", &dict);
+
+        let replacement: Vec<char> = "e!".chars().collect();
+        document.apply_edit(Span::new(10, 40), &replacement, &mut PlainEnglish, &dict);
+        assert_eq!(document.get_full_string(), "Hello there!");
+
+        document.apply_edit(Span::new(50, 60), &['?'], &mut PlainEnglish, &dict);
+        assert_eq!(document.get_full_string(), "Hello there!?");
+    }
+
+    #[test]
+    fn reparses_unterminated_code_blocks() {
+        assert_edit_matches(
+            "First.\n\nSecond.\n\nThird.",
+            "Second.",
+            "```\nSecond.",
+            &mut Markdown,
+        );
+        assert_edit_matches(
+            "```\nlet a = 1;\n\nlet b = 2;\n```\n\nSome text.",
+            "let b",
+            "let c",
+            &mut Markdown,
+        );
+    }
 }
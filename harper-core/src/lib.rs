@@ -3,6 +3,7 @@
 
 mod char_ext;
 mod char_string;
+mod dialect;
 mod document;
 pub mod language_detection;
 mod lexing;
@@ -10,7 +11,12 @@ pub mod linting;
 mod mask;
 pub mod parsers;
 pub mod patterns;
+mod pos_tagger;
+#[cfg(feature = "project-config")]
+mod project_config;
 mod punctuation;
+pub mod readability;
+mod severity;
 mod span;
 mod spell;
 mod sync;
@@ -21,16 +27,26 @@ mod word_metadata;
 use std::collections::VecDeque;
 
 pub use char_string::{CharString, CharStringExt};
+pub use dialect::Dialect;
 pub use document::Document;
 use linting::Lint;
 pub use mask::{Mask, Masker};
+#[cfg(feature = "project-config")]
+pub use project_config::{ProjectConfig, ProjectConfigError, PROJECT_CONFIG_FILENAMES};
 pub use punctuation::{Punctuation, Quote};
+pub use severity::{Severity, SeverityOverrides};
 pub use span::Span;
-pub use spell::{Dictionary, FstDictionary, FullDictionary, MergedDictionary};
+pub use spell::{
+    suggest_correct_spelling, suggest_correct_spelling_in_context, suggest_correct_spelling_str,
+    CompiledDictionaryError, Dictionary, FstDictionary, FullDictionary, HunspellError,
+    MergedDictionary, SuggestionContext, WordListWarning,
+};
 pub use sync::Lrc;
 pub use token::{FatToken, Token, TokenKind, TokenStringExt};
 pub use vec_ext::VecExt;
-pub use word_metadata::{AdverbData, ConjunctionData, NounData, Tense, VerbData, WordMetadata};
+pub use word_metadata::{
+    AdverbData, ConjunctionData, NounData, PartOfSpeech, Tense, VerbData, VerbForm, WordMetadata,
+};
 
 /// A utility function that removes overlapping lints in a vector,
 /// keeping the more important ones.
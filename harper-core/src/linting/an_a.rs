@@ -55,6 +55,7 @@ impl Linter for AnA {
                         suggestions: vec![Suggestion::ReplaceWith(replacement)],
                         message: "Incorrect indefinite article.".to_string(),
                         priority: 31,
+                        ..Default::default()
                     })
                 }
             }
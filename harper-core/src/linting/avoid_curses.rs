@@ -1,20 +1,59 @@
+use serde::{Deserialize, Serialize};
+
 use super::{Lint, LintKind, Linter};
-use crate::{Document, TokenStringExt};
+use crate::{CharStringExt, Document, TokenStringExt};
+
+/// Options for the [`AvoidCurses`] linter.
+///
+/// Words are compared case-insensitively.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AvoidCursesOptions {
+    /// Swear words from the dictionary that should not be flagged.
+    pub allowed_words: Vec<String>,
+    /// Additional words that should be flagged.
+    pub extra_words: Vec<String>,
+}
 
 #[derive(Debug, Default)]
-pub struct AvoidCurses;
+pub struct AvoidCurses {
+    allowed_words: Vec<Vec<char>>,
+    extra_words: Vec<Vec<char>>,
+}
+
+impl AvoidCurses {
+    pub fn new(options: AvoidCursesOptions) -> Self {
+        let to_chars = |words: Vec<String>| {
+            words
+                .into_iter()
+                .map(|word| word.to_lowercase().chars().collect())
+                .collect()
+        };
+
+        Self {
+            allowed_words: to_chars(options.allowed_words),
+            extra_words: to_chars(options.extra_words),
+        }
+    }
+}
 
 impl Linter for AvoidCurses {
     fn lint(&mut self, document: &Document) -> Vec<Lint> {
         document
             .iter_words()
-            .filter(|t| t.kind.is_swear())
+            .filter(|t| {
+                let word = document.get_span_content(t.span).to_lower();
+                let listed = |words: &[Vec<char>]| words.iter().any(|w| w[..] == word[..]);
+
+                !listed(&self.allowed_words) && (t.kind.is_swear() || listed(&self.extra_words))
+            })
             .map(|t| Lint {
                 span: t.span,
                 lint_kind: LintKind::Miscellaneous,
                 suggestions: vec![],
                 message: "Try to avoid offensive language.".to_string(),
                 priority: 63,
+                ..Default::default()
             })
             .collect()
     }
@@ -22,11 +61,39 @@ impl Linter for AvoidCurses {
 
 #[cfg(test)]
 mod tests {
-    use super::AvoidCurses;
+    use super::{AvoidCurses, AvoidCursesOptions};
     use crate::linting::tests::assert_lint_count;
 
     #[test]
     fn detects_shit() {
-        assert_lint_count("He ate shit when he fell off the bike.", AvoidCurses, 1);
+        assert_lint_count(
+            "He ate shit when he fell off the bike.",
+            AvoidCurses::default(),
+            1,
+        );
+    }
+
+    #[test]
+    fn respects_allowed_words() {
+        assert_lint_count(
+            "He ate Shit when he fell off the bike.",
+            AvoidCurses::new(AvoidCursesOptions {
+                allowed_words: vec!["shit".to_string()],
+                ..Default::default()
+            }),
+            0,
+        );
+    }
+
+    #[test]
+    fn flags_extra_words() {
+        assert_lint_count(
+            "Well, darn it.",
+            AvoidCurses::new(AvoidCursesOptions {
+                extra_words: vec!["Darn".to_string()],
+                ..Default::default()
+            }),
+            1,
+        );
     }
 }
@@ -1,3 +1,5 @@
+use serde::{Deserialize, Serialize};
+
 use crate::{
     patterns::{Pattern, WordPatternGroup},
     Token, TokenStringExt,
@@ -5,16 +7,33 @@ use crate::{
 
 use super::{Lint, LintKind, PatternLinter};
 
+/// Options for the [`BoringWords`] linter.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct BoringWordsOptions {
+    /// The words to flag.
+    pub words: Vec<String>,
+}
+
+impl Default for BoringWordsOptions {
+    fn default() -> Self {
+        Self {
+            words: vec!["very".to_string(), "interesting".to_string()],
+        }
+    }
+}
+
 pub struct BoringWords {
     pattern: Box<dyn Pattern>,
 }
 
-impl Default for BoringWords {
-    fn default() -> Self {
+impl BoringWords {
+    pub fn new(options: BoringWordsOptions) -> Self {
         let mut pattern = WordPatternGroup::default();
 
-        pattern.add_word("very");
-        pattern.add_word("interesting");
+        for word in &options.words {
+            pattern.add_word(word);
+        }
 
         Self {
             pattern: Box::new(pattern),
@@ -22,6 +41,12 @@ impl Default for BoringWords {
     }
 }
 
+impl Default for BoringWords {
+    fn default() -> Self {
+        Self::new(BoringWordsOptions::default())
+    }
+}
+
 impl PatternLinter for BoringWords {
     fn pattern(&self) -> &dyn Pattern {
         self.pattern.as_ref()
@@ -39,6 +64,36 @@ impl PatternLinter for BoringWords {
                 matched_word
             ),
             priority: 127,
+            ..Default::default()
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{BoringWords, BoringWordsOptions};
+    use crate::linting::tests::assert_lint_count;
+
+    #[test]
+    fn flags_default_words() {
+        assert_lint_count("That was very nice.", BoringWords::default(), 1);
+    }
+
+    #[test]
+    fn flags_configured_words() {
+        assert_lint_count(
+            "That was very nice.",
+            BoringWords::new(BoringWordsOptions {
+                words: vec!["nice".to_string()],
+            }),
+            1,
+        );
+        assert_lint_count(
+            "That was very good.",
+            BoringWords::new(BoringWordsOptions {
+                words: vec!["nice".to_string()],
+            }),
+            0,
+        );
+    }
+}
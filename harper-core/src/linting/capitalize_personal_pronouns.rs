@@ -27,6 +27,7 @@ impl Linter for CapitalizePersonalPronouns {
                         suggestions: vec![Suggestion::ReplaceWith(vec!['I'])],
                         message: "First-person singular pronouns must be capitalized.".to_string(),
                         priority: 31,
+                        ..Default::default()
                     });
                 }
             }
@@ -0,0 +1,120 @@
+use super::{Lint, LintKind, Linter, Suggestion};
+use crate::spell::dialect_corrections;
+use crate::{CharString, CharStringExt, Dialect, Document, TokenStringExt};
+
+/// Flags words that are spelled the way another dialect of English spells
+/// them, like “color” in British English.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DialectSpelling {
+    dialect: Dialect,
+}
+
+impl DialectSpelling {
+    pub fn new(dialect: Dialect) -> Self {
+        Self { dialect }
+    }
+}
+
+impl Linter for DialectSpelling {
+    fn lint(&mut self, document: &Document) -> Vec<Lint> {
+        let corrections = dialect_corrections(self.dialect);
+        let mut lints = Vec::new();
+
+        for word in document.iter_words() {
+            let chars = document.get_span_content(word.span);
+
+            let Some(correction) = corrections.get(&chars.to_lower()) else {
+                continue;
+            };
+
+            let correction = match_case(chars, correction);
+
+            lints.push(Lint {
+                span: word.span,
+                lint_kind: LintKind::Spelling,
+                suggestions: vec![Suggestion::ReplaceWith(correction.to_vec())],
+                message: format!(
+                    "{} English spells this “{}”.",
+                    self.dialect,
+                    correction.iter().collect::<String>()
+                ),
+                priority: 63,
+                ..Default::default()
+            });
+        }
+
+        lints
+    }
+}
+
+/// Copy the capitalization of `template` onto `word`.
+fn match_case(template: &[char], word: &[char]) -> CharString {
+    let is_upper = |c: &char| c.is_uppercase();
+
+    if template.len() > 1 && template.iter().all(is_upper) {
+        return word.iter().flat_map(|c| c.to_uppercase()).collect();
+    }
+
+    let mut word: CharString = word.into();
+
+    if template.first().is_some_and(is_upper) {
+        if let Some(first) = word.first_mut() {
+            *first = first.to_uppercase().next().unwrap();
+        }
+    }
+
+    word
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DialectSpelling;
+    use crate::linting::tests::{assert_lint_count, assert_suggestion_result};
+    use crate::Dialect;
+
+    #[test]
+    fn flags_american_spellings_in_british_english() {
+        assert_suggestion_result(
+            "Which color is the room?",
+            DialectSpelling::new(Dialect::British),
+            "Which colour is the room?",
+        );
+        assert_suggestion_result(
+            "They organized the room.",
+            DialectSpelling::new(Dialect::British),
+            "They organised the room.",
+        );
+    }
+
+    #[test]
+    fn flags_british_spellings_in_american_english() {
+        assert_suggestion_result(
+            "The Centre was lovely.",
+            DialectSpelling::new(Dialect::American),
+            "The Center was lovely.",
+        );
+    }
+
+    #[test]
+    fn allows_canadian_spellings() {
+        assert_lint_count(
+            "They organized a colourful parade.",
+            DialectSpelling::new(Dialect::Canadian),
+            0,
+        );
+        assert_lint_count(
+            "They organized a colorful parade.",
+            DialectSpelling::new(Dialect::Canadian),
+            1,
+        );
+    }
+
+    #[test]
+    fn preserves_capitalization() {
+        assert_suggestion_result(
+            "ANALYZE this.",
+            DialectSpelling::new(Dialect::Australian),
+            "ANALYSE this.",
+        );
+    }
+}
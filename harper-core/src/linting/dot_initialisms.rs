@@ -49,6 +49,7 @@ impl PatternLinter for DotInitialisms {
             suggestions: vec![Suggestion::ReplaceWith(correction.chars().collect())],
             message: "Initialisms should have dot-separated letters.".to_owned(),
             priority: 63,
+            ..Default::default()
         }
     }
 }
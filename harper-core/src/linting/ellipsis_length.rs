@@ -29,6 +29,7 @@ impl Linter for EllipsisLength {
                     suggestions: vec![Suggestion::ReplaceWith(vec!['.', '.', '.'])],
                     message: "Horizontal ellipsis must have 3 dots.".to_string(),
                     priority: 31,
+                    ..Default::default()
                 })
             }
         }
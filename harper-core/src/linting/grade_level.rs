@@ -0,0 +1,98 @@
+use serde::{Deserialize, Serialize};
+
+use super::{Lint, LintKind, Linter};
+use crate::readability::TextCounts;
+use crate::token::TokenStringExt;
+use crate::Document;
+
+/// Options for the [`GradeLevel`] linter.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct GradeLevelOptions {
+    /// The highest Flesch–Kincaid grade level a paragraph may have before it
+    /// is flagged.
+    pub max_grade: usize,
+}
+
+impl Default for GradeLevelOptions {
+    fn default() -> Self {
+        Self { max_grade: 12 }
+    }
+}
+
+/// Warn about paragraphs that are harder to read than a target grade level.
+#[derive(Debug, Clone, Default)]
+pub struct GradeLevel {
+    options: GradeLevelOptions,
+}
+
+impl GradeLevel {
+    pub fn new(options: GradeLevelOptions) -> Self {
+        Self { options }
+    }
+}
+
+impl Linter for GradeLevel {
+    fn lint(&mut self, document: &Document) -> Vec<Lint> {
+        let mut output = Vec::new();
+
+        for paragraph in document.iter_paragraphs() {
+            let Some(grade) =
+                TextCounts::from_tokens(paragraph, document.get_source()).flesch_kincaid_grade()
+            else {
+                continue;
+            };
+
+            let Some(span) = paragraph.span() else {
+                continue;
+            };
+
+            if grade > self.options.max_grade as f32 {
+                output.push(Lint {
+                    span,
+                    lint_kind: LintKind::Readability,
+                    message: format!(
+                        "This paragraph reads at grade level {:.0}, above the target of {}. Try shorter sentences and simpler words.",
+                        grade, self.options.max_grade
+                    ),
+                    ..Default::default()
+                })
+            }
+        }
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GradeLevel, GradeLevelOptions};
+    use crate::linting::tests::assert_lint_count;
+
+    #[test]
+    fn allows_simple_paragraphs() {
+        assert_lint_count(
+            "The cat sat on the mat. It was warm.",
+            GradeLevel::default(),
+            0,
+        );
+    }
+
+    #[test]
+    fn flags_complex_paragraphs() {
+        assert_lint_count(
+            "Institutional considerations necessitate comprehensive organizational evaluation of the administrative infrastructure.\n\nThe cat sat on the mat.",
+            GradeLevel::default(),
+            1,
+        );
+    }
+
+    #[test]
+    fn respects_max_grade() {
+        assert_lint_count(
+            "Our quarterly planning meeting covered several complicated budget decisions.",
+            GradeLevel::new(GradeLevelOptions { max_grade: 4 }),
+            1,
+        );
+    }
+}
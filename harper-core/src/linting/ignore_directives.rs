@@ -0,0 +1,363 @@
+use super::lint::normalize_rule_name;
+use super::Lint;
+use crate::{Document, Span};
+
+/// The text that must precede every directive.
+const DIRECTIVE_PREFIX: [char; 7] = ['h', 'a', 'r', 'p', 'e', 'r', ':'];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DirectiveKind {
+    IgnoreLine,
+    IgnoreNextLine,
+    Disable,
+    Enable,
+}
+
+impl DirectiveKind {
+    fn from_chars(chars: &[char]) -> Option<Self> {
+        let s: String = chars.iter().collect();
+
+        match s.as_str() {
+            "ignore-line" => Some(Self::IgnoreLine),
+            "ignore-next-line" => Some(Self::IgnoreNextLine),
+            "disable" => Some(Self::Disable),
+            "enable" => Some(Self::Enable),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Directive {
+    kind: DirectiveKind,
+    /// The location of the directive itself, including the prefix, any
+    /// rule names and any reason.
+    span: Span,
+    /// The (zero-indexed) line the directive was found on.
+    line: usize,
+    /// The normalized names of the rules this directive applies to, and
+    /// where each was written.
+    /// If empty, it applies to all rules.
+    rules: Vec<(String, Span)>,
+}
+
+impl Directive {
+    fn applies_to(&self, rule: Option<&str>) -> bool {
+        if self.rules.is_empty() {
+            return true;
+        }
+
+        let Some(rule) = rule else {
+            return false;
+        };
+
+        let rule = normalize_rule_name(rule);
+        self.rules.iter().any(|(name, _)| *name == rule)
+    }
+}
+
+/// Inline comments that silence lints, read from the source text of a
+/// [`Document`].
+///
+/// Directives are recognized anywhere in the source, so they can be placed
+/// inside whatever comment syntax the host language uses (`//`, `#`,
+/// `<!-- -->`, etc.).
+///
+/// - `harper:ignore-line` ignores lints on the same line.
+/// - `harper:ignore-next-line` ignores lints on the following line.
+/// - `harper:disable` ignores lints until the next `harper:enable`, or the end
+///   of the document.
+/// - `harper:enable` undoes a previous `harper:disable`.
+///
+/// Any directive may be followed by the names of specific linters (e.g.
+/// `harper:disable SpellCheck AnA`), in which case it only applies to those.
+/// A reason for the directive can be given after `--` (e.g.
+/// `harper:disable SpellCheck -- product names`).
+#[derive(Debug, Clone, Default)]
+pub struct IgnoreDirectives {
+    directives: Vec<Directive>,
+    /// The index of the first character of each line.
+    line_starts: Vec<usize>,
+}
+
+impl IgnoreDirectives {
+    pub fn new_from_document(document: &Document) -> Self {
+        Self::new_from_source(document.get_source())
+    }
+
+    pub fn new_from_source(source: &[char]) -> Self {
+        let mut line_starts = vec![0];
+        let mut directives = Vec::new();
+
+        let mut cursor = 0;
+
+        while cursor < source.len() {
+            if source[cursor] == '\n' {
+                line_starts.push(cursor + 1);
+                cursor += 1;
+                continue;
+            }
+
+            if source[cursor..].starts_with(&DIRECTIVE_PREFIX) {
+                if let Some(directive) = parse_directive(source, cursor, line_starts.len() - 1) {
+                    cursor = directive.span.end;
+                    directives.push(directive);
+                    continue;
+                }
+            }
+
+            cursor += 1;
+        }
+
+        Self {
+            directives,
+            line_starts,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.directives.is_empty()
+    }
+
+    /// The locations of rule names that `is_known` doesn't recognize, which
+    /// are probably reasons written without a `--` before them.
+    pub fn unknown_rules(&self, is_known: impl Fn(&str) -> bool) -> Vec<Span> {
+        self.directives
+            .iter()
+            .flat_map(|directive| &directive.rules)
+            .filter(|(name, _)| !is_known(name))
+            .map(|(_, span)| *span)
+            .collect()
+    }
+
+    /// Check whether a lint should be silenced.
+    ///
+    /// Directives that name specific rules only apply to lints that have a
+    /// [`Lint::rule`].
+    pub fn is_ignored(&self, lint: &Lint) -> bool {
+        let span = lint.span;
+        let rule = lint.rule.as_deref();
+        let line = self.line_of(span.start);
+        let mut disabled = false;
+
+        for directive in &self.directives {
+            // Never complain about the directives themselves.
+            if directive.span.overlaps_with(span) {
+                return true;
+            }
+
+            if !directive.applies_to(rule) {
+                continue;
+            }
+
+            match directive.kind {
+                DirectiveKind::IgnoreLine if directive.line == line => return true,
+                DirectiveKind::IgnoreNextLine if directive.line + 1 == line => return true,
+                DirectiveKind::Disable if directive.span.start < span.start => disabled = true,
+                DirectiveKind::Enable if directive.span.start < span.start => disabled = false,
+                _ => (),
+            }
+        }
+
+        disabled
+    }
+
+    fn line_of(&self, char_index: usize) -> usize {
+        self.line_starts.partition_point(|s| *s <= char_index) - 1
+    }
+}
+
+/// Parse a directive that begins (including the prefix) at `start`.
+fn parse_directive(source: &[char], start: usize, line: usize) -> Option<Directive> {
+    let kind_start = start + DIRECTIVE_PREFIX.len();
+    let mut cursor = kind_start;
+
+    while cursor < source.len() && (source[cursor].is_ascii_lowercase() || source[cursor] == '-') {
+        cursor += 1;
+    }
+
+    // Allows for things like `<!--harper:disable-->`
+    while cursor > kind_start && source[cursor - 1] == '-' {
+        cursor -= 1;
+    }
+
+    let kind = DirectiveKind::from_chars(&source[kind_start..cursor])?;
+    let mut end = cursor;
+    let mut rules = Vec::new();
+
+    loop {
+        while cursor < source.len() && matches!(source[cursor], ' ' | '\t' | ',') {
+            cursor += 1;
+        }
+
+        // Everything after `--` is a reason, up to the end of the line or
+        // comment.
+        if source[cursor..].starts_with(&['-', '-'])
+            && !source[cursor..].starts_with(&['-', '-', '>'])
+        {
+            while cursor < source.len()
+                && source[cursor] != '\n'
+                && !source[cursor..].starts_with(&['-', '-', '>'])
+                && !source[cursor..].starts_with(&['*', '/'])
+            {
+                cursor += 1;
+            }
+
+            while source[cursor - 1].is_whitespace() {
+                cursor -= 1;
+            }
+
+            end = cursor;
+            break;
+        }
+
+        let rule_start = cursor;
+
+        while cursor < source.len() && (source[cursor].is_alphanumeric() || source[cursor] == '_') {
+            cursor += 1;
+        }
+
+        if rule_start == cursor {
+            break;
+        }
+
+        let rule: String = source[rule_start..cursor].iter().collect();
+        rules.push((normalize_rule_name(&rule), Span::new(rule_start, cursor)));
+        end = cursor;
+    }
+
+    Some(Directive {
+        kind,
+        span: Span::new(start, end),
+        line,
+        rules,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IgnoreDirectives;
+    use crate::linting::{Lint, LintGroup, LintGroupConfig, Linter};
+    use crate::{Document, FstDictionary, Span};
+
+    fn lint_at(start: usize, rule: &str) -> Lint {
+        Lint {
+            span: Span::new_with_len(start, 1),
+            rule: Some(rule.to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn assert_lint_group_count(text: &str, count: usize) {
+        let document = Document::new_markdown_curated(text);
+        let mut linter = LintGroup::new(LintGroupConfig::default(), FstDictionary::curated());
+        let lints = linter.lint(&document);
+        assert_eq!(lints.len(), count);
+    }
+
+    #[test]
+    fn parses_rules() {
+        let source: Vec<_> = "<!-- harper:disable SpellCheck, an_a -->".chars().collect();
+        let directives = IgnoreDirectives::new_from_source(&source);
+
+        assert!(directives.is_ignored(&lint_at(40, "SpellCheck")));
+        assert!(directives.is_ignored(&lint_at(40, "AnA")));
+        assert!(!directives.is_ignored(&lint_at(40, "RepeatedWords")));
+    }
+
+    #[test]
+    fn ignores_directive_without_spaces() {
+        let source: Vec<_> = "<!--harper:disable-->".chars().collect();
+        let directives = IgnoreDirectives::new_from_source(&source);
+
+        assert!(!directives.is_empty());
+        assert!(directives.is_ignored(&lint_at(21, "SpellCheck")));
+    }
+
+    #[test]
+    fn ignores_unknown_directives() {
+        let source: Vec<_> = "harper:nonsense".chars().collect();
+        assert!(IgnoreDirectives::new_from_source(&source).is_empty());
+    }
+
+    #[test]
+    fn ignores_next_line() {
+        assert_lint_group_count("<!-- harper:ignore-next-line -->\nThis is a tesst.", 0);
+    }
+
+    #[test]
+    fn only_ignores_next_line() {
+        assert_lint_group_count(
+            "<!-- harper:ignore-next-line -->\nThis is a tesst.\n\nThis is a tesst.",
+            1,
+        );
+    }
+
+    #[test]
+    fn ignores_same_line() {
+        assert_lint_group_count("This is a tesst. <!-- harper:ignore-line -->", 0);
+    }
+
+    #[test]
+    fn disables_until_enabled() {
+        assert_lint_group_count(
+            "<!-- harper:disable -->\n\nThis is a tesst.\n\n<!-- harper:enable -->\n\nThis is a tesst.",
+            1,
+        );
+    }
+
+    #[test]
+    fn disables_specific_rule() {
+        assert_lint_group_count(
+            "<!-- harper:disable SpellCheck -->\n\nThis is a tesst of the the linter.",
+            1,
+        );
+    }
+
+    #[test]
+    fn reads_reasons() {
+        let source: Vec<_> = "<!-- harper:disable SpellCheck -- product namez -->"
+            .chars()
+            .collect();
+        let directives = IgnoreDirectives::new_from_source(&source);
+
+        assert!(directives.is_ignored(&lint_at(50, "SpellCheck")));
+        assert!(!directives.is_ignored(&lint_at(50, "RepeatedWords")));
+        assert_eq!(directives.unknown_rules(|_| false).len(), 1);
+    }
+
+    #[test]
+    fn reasons_are_not_linted() {
+        assert_lint_group_count(
+            "<!-- harper:disable -- the the tesst is intentional -->\n\nThis is a tesst.",
+            0,
+        );
+        assert_lint_group_count("This is a tesst. // harper:ignore-line -- naame", 0);
+    }
+
+    #[test]
+    fn reports_unknown_rules() {
+        let document = Document::new_markdown_curated(
+            "<!-- harper:disable this is intentional -->\n\nThis is a tesst.",
+        );
+        let mut linter = LintGroup::new(LintGroupConfig::default(), FstDictionary::curated());
+        let lints = linter.lint(&document);
+
+        let unknown: Vec<_> = lints
+            .iter()
+            .filter(|lint| lint.is_from_rule("IgnoreDirectives"))
+            .map(|lint| document.get_span_content_str(lint.span))
+            .collect();
+
+        assert_eq!(unknown, vec!["this", "is", "intentional"]);
+        assert!(lints.iter().any(|lint| lint.is_from_rule("SpellCheck")));
+    }
+
+    #[test]
+    fn enables_specific_rule() {
+        assert_lint_group_count(
+            "<!-- harper:disable -->\n\n<!-- harper:enable spell_check -->\n\nThis is a tesst of the the linter.",
+            1,
+        );
+    }
+}
@@ -55,4 +55,9 @@ mod tests {
     fn working_right() {
         assert_lint_count("\"working\" is a noun.", LinkingVerbs, 0);
     }
+
+    #[test]
+    fn homograph_as_noun() {
+        assert_lint_count("The lead is heavy.", LinkingVerbs, 0);
+    }
 }
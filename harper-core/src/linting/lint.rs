@@ -14,6 +14,13 @@ pub struct Lint {
     /// A numerical value for the importance of a lint.
     /// Lower = more important.
     pub priority: u8,
+    /// A stable identifier for the linter that produced this lint (e.g.
+    /// `SpellCheck`).
+    ///
+    /// Filled in by [`LintGroup`](super::LintGroup), so it will be [`None`]
+    /// for lints produced by an individual linter.
+    #[serde(default)]
+    pub rule: Option<String>,
 }
 
 impl Default for Lint {
@@ -24,11 +31,33 @@ impl Default for Lint {
             suggestions: Default::default(),
             message: Default::default(),
             priority: 127,
+            rule: None,
         }
     }
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, Is, Default)]
+impl Lint {
+    /// Check whether this lint was produced by the named rule.
+    ///
+    /// Rules may be referred to by either their type name (`SpellCheck`) or
+    /// their configuration key (`spell_check`).
+    pub fn is_from_rule(&self, rule: &str) -> bool {
+        self.rule
+            .as_deref()
+            .is_some_and(|own| normalize_rule_name(own) == normalize_rule_name(rule))
+    }
+}
+
+/// Allows rules to be referred to by either their type name (`SpellCheck`) or
+/// their configuration key (`spell_check`).
+pub(crate) fn normalize_rule_name(rule: &str) -> String {
+    rule.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(|c| c.to_lowercase())
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Is, Default, PartialEq, Eq, Hash)]
 pub enum LintKind {
     Spelling,
     Capitalization,
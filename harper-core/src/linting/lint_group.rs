@@ -2,41 +2,102 @@ use paste::paste;
 use serde::{Deserialize, Serialize};
 
 use super::an_a::AnA;
-use super::avoid_curses::AvoidCurses;
-use super::boring_words::BoringWords;
+use super::avoid_curses::{AvoidCurses, AvoidCursesOptions};
+use super::boring_words::{BoringWords, BoringWordsOptions};
 use super::capitalize_personal_pronouns::CapitalizePersonalPronouns;
 use super::correct_number_suffix::CorrectNumberSuffix;
+use super::dialect_spelling::DialectSpelling;
 use super::dot_initialisms::DotInitialisms;
 use super::ellipsis_length::EllipsisLength;
+use super::grade_level::{GradeLevel, GradeLevelOptions};
+use super::ignore_directives::IgnoreDirectives;
 use super::linking_verbs::LinkingVerbs;
-use super::long_sentences::LongSentences;
+use super::lint::normalize_rule_name;
+use super::long_sentences::{LongSentences, LongSentencesOptions};
 use super::matcher::Matcher;
 use super::multiple_sequential_pronouns::MultipleSequentialPronouns;
 use super::number_suffix_capitalization::NumberSuffixCapitalization;
+use super::paragraph_cache::ParagraphLintCache;
+use super::passive_voice::PassiveVoice;
 use super::repeated_words::RepeatedWords;
+use super::replacement_rules::ReplacementRules;
 use super::sentence_capitalization::SentenceCapitalization;
 use super::spaces::Spaces;
 use super::spell_check::SpellCheck;
 use super::spelled_numbers::SpelledNumbers;
+use super::subject_verb_agreement::SubjectVerbAgreement;
 use super::terminating_conjunctions::TerminatingConjunctions;
 use super::that_which::ThatWhich;
 use super::unclosed_quotes::UnclosedQuotes;
 use super::use_genitive::UseGenitive;
-use super::wrong_quotes::WrongQuotes;
-use super::{Lint, Linter};
-use crate::{Dictionary, Document};
+use super::wrong_quotes::{WrongQuotes, WrongQuotesOptions};
+use super::{Lint, LintKind, Linter};
+use crate::{Dialect, Dictionary, Document};
+
+/// Options for the linters that can be tuned beyond being turned on or off,
+/// keyed by the same names as [`LintGroupConfig`].
+///
+/// Each linter's options are set as a whole: any option left out of a set
+/// entry takes its default value, rather than one from a config it overrides.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+pub struct LinterOptions {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub long_sentences: Option<LongSentencesOptions>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub boring_words: Option<BoringWordsOptions>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub avoid_curses: Option<AvoidCursesOptions>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wrong_quotes: Option<WrongQuotesOptions>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grade_level: Option<GradeLevelOptions>,
+}
+
+impl LinterOptions {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Replaces the options in `self` with any that are set (not [`None`]) in
+    /// `other`.
+    pub fn override_with(&mut self, other: &Self) {
+        if other.long_sentences.is_some() {
+            self.long_sentences.clone_from(&other.long_sentences);
+        }
+        if other.boring_words.is_some() {
+            self.boring_words.clone_from(&other.boring_words);
+        }
+        if other.avoid_curses.is_some() {
+            self.avoid_curses.clone_from(&other.avoid_curses);
+        }
+        if other.wrong_quotes.is_some() {
+            self.wrong_quotes.clone_from(&other.wrong_quotes);
+        }
+        if other.grade_level.is_some() {
+            self.grade_level.clone_from(&other.grade_level);
+        }
+    }
+}
 
 macro_rules! create_lint_group_config {
     ($($linter:ident => $default:expr),*) => {
         paste! {
-            #[derive(Debug, Clone, Copy, Serialize, Deserialize, Default)]
+            #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
             pub struct LintGroupConfig {
                 $(
                     #[doc = "Configures the use of the [`" $linter "`] linter.
                     If set to [`None`], the default configuration will be used."]
                     pub [<$linter:snake>]: Option<bool>,
                 )*
-                pub spell_check: Option<bool>
+                pub spell_check: Option<bool>,
+                /// The dialect of English that text is written in.
+                /// If set to [`None`], American English is assumed.
+                #[serde(default, skip_serializing_if = "Option::is_none")]
+                pub dialect: Option<Dialect>,
+                /// Options for the linters that accept them.
+                #[serde(default, skip_serializing_if = "LinterOptions::is_empty")]
+                pub options: LinterOptions,
             }
 
             impl LintGroupConfig {
@@ -46,10 +107,32 @@ macro_rules! create_lint_group_config {
                         $(
                             [<$linter:snake>]: Some(false),
                         )*
-                        spell_check: Some(false)
+                        spell_check: Some(false),
+                        dialect: None,
+                        options: LinterOptions::default(),
                     }
                 }
 
+                /// Replaces the values in `self` with any values that are set (not
+                /// [`None`]) in `other`.
+                pub fn override_with(&mut self, other: &Self) {
+                    $(
+                        if other.[<$linter:snake>].is_some() {
+                            self.[<$linter:snake>] = other.[<$linter:snake>];
+                        }
+                    )*
+
+                    if other.spell_check.is_some() {
+                        self.spell_check = other.spell_check;
+                    }
+
+                    if other.dialect.is_some() {
+                        self.dialect = other.dialect;
+                    }
+
+                    self.options.override_with(&other.options);
+                }
+
                 /// Fills the [`None`] values in the configuration with the default values.
                 pub fn fill_default_values(&mut self){
                     $(
@@ -61,6 +144,10 @@ macro_rules! create_lint_group_config {
                     if self.spell_check.is_none() {
                         self.spell_check = Some(true);
                     }
+
+                    if self.dialect.is_none() {
+                        self.dialect = Some(Dialect::default());
+                    }
                 }
             }
 
@@ -71,43 +158,119 @@ macro_rules! create_lint_group_config {
                     [<$linter:snake>]: $linter,
                 )*
                 spell_check: SpellCheck<T>,
-                pub config: LintGroupConfig
+                /// User-defined rules, which are always run.
+                pub replacement_rules: ReplacementRules,
+                pub config: LintGroupConfig,
+                /// The linter options and dialect currently in effect, so that
+                /// changes to the config can be picked up.
+                applied_options: LinterOptions,
+                applied_dialect: Option<Dialect>,
             }
 
 
             impl<T: Dictionary> LintGroup<T> {
                 pub fn new(config: LintGroupConfig, dictionary: T) -> Self {
-                    Self {
+                    let mut group = Self {
                         $(
                             [<$linter:snake>]: $linter::default(),
                         )*
                         spell_check: SpellCheck::new(dictionary),
+                        replacement_rules: ReplacementRules::default(),
                         config,
-                    }
+                        applied_options: LinterOptions::default(),
+                        applied_dialect: None,
+                    };
+
+                    group.apply_options();
+                    group
                 }
             }
 
-            impl<T: Dictionary> Linter for LintGroup<T> {
-                fn lint(&mut self, document: &Document) -> Vec<Lint>{
-                    let mut lints = Vec::new();
+            impl<T: Dictionary> LintGroup<T> {
+                /// Flag the rule names in ignore directives that aren't the name of a
+                /// linter or replacement rule, since they silence nothing.
+                fn lint_unknown_rules(&self, directives: &IgnoreDirectives, document: &Document) -> Vec<Lint> {
+                    let known: Vec<String> = [$(stringify!($linter),)* "SpellCheck", "ReplacementRules"]
+                        .into_iter()
+                        .chain(self.replacement_rules.names())
+                        .map(normalize_rule_name)
+                        .collect();
+
+                    directives
+                        .unknown_rules(|rule| known.iter().any(|k| k == rule))
+                        .into_iter()
+                        .map(|span| Lint {
+                            span,
+                            lint_kind: LintKind::Miscellaneous,
+                            message: format!(
+                                "“{}” isn't the name of a rule, so it isn't ignored. Write reasons after `--`.",
+                                document.get_span_content_str(span)
+                            ),
+                            rule: Some("IgnoreDirectives".to_string()),
+                            ..Default::default()
+                        })
+                        .collect()
+                }
+            }
+
+            impl<T: Dictionary> LintGroup<T> {
+                /// Run the enabled linters whose rules `include` accepts, without
+                /// applying ignore directives.
+                fn run_linters(&mut self, document: &Document, include: impl Fn(&str) -> bool) -> Vec<Lint> {
+                    if self.config.options != self.applied_options
+                        || self.config.dialect != self.applied_dialect
+                    {
+                        self.apply_options();
+                    }
 
                     let mut config = self.config.clone();
                     config.fill_default_values();
 
+                    let mut linters: Vec<(&str, &mut dyn Linter)> = Vec::new();
+
                     $(
-                        if config.[<$linter:snake>].unwrap() {
-                            lints.append(&mut self.[<$linter:snake>].lint(document));
+                        if config.[<$linter:snake>].unwrap() && include(stringify!($linter)) {
+                            linters.push((stringify!($linter), &mut self.[<$linter:snake>]));
                         }
                     )*
 
-                    if config.spell_check.unwrap() {
-                        lints.append(&mut self.spell_check.lint(document));
+                    if config.spell_check.unwrap() && include("SpellCheck") {
+                        linters.push(("SpellCheck", &mut self.spell_check));
+                    }
+
+                    if include("ReplacementRules") {
+                        linters.push(("ReplacementRules", &mut self.replacement_rules));
                     }
 
+                    // Only user-defined rules name their own lints.
+                    let run = |(rule, linter): (&str, &mut dyn Linter)| {
+                        let mut lints = linter.lint(document);
+                        for lint in &mut lints {
+                            lint.rule.get_or_insert_with(|| rule.to_string());
+                        }
+                        lints
+                    };
+
+                    // Either way, the lints are returned in the order the linters are listed.
+                    #[cfg(feature = "concurrent")]
+                    let lints = {
+                        use rayon::prelude::*;
+                        linters.into_par_iter().flat_map_iter(run).collect()
+                    };
+                    #[cfg(not(feature = "concurrent"))]
+                    let lints = linters.into_iter().flat_map(run).collect();
 
                     lints
                 }
             }
+
+            impl<T: Dictionary> Linter for LintGroup<T> {
+                fn lint(&mut self, document: &Document) -> Vec<Lint>{
+                    let mut lints = self.run_linters(document, |_| true);
+                    self.apply_directives(&mut lints, document);
+                    lints
+                }
+            }
         }
     };
 }
@@ -133,11 +296,205 @@ create_lint_group_config!(
     BoringWords => false,
     UseGenitive => false,
     ThatWhich => true,
-    CapitalizePersonalPronouns => true
+    CapitalizePersonalPronouns => true,
+    DialectSpelling => false,
+    SubjectVerbAgreement => true,
+    PassiveVoice => false,
+    GradeLevel => false
 );
 
+/// The rules that never look beyond the paragraph a problem is in, so their
+/// lints can be cached paragraph by paragraph.
+const PARAGRAPH_LOCAL_RULES: [&str; 10] = [
+    "SpellCheck",
+    "DialectSpelling",
+    "AvoidCurses",
+    "CapitalizePersonalPronouns",
+    "SpelledNumbers",
+    "CorrectNumberSuffix",
+    "NumberSuffixCapitalization",
+    "EllipsisLength",
+    "SentenceCapitalization",
+    "GradeLevel",
+];
+
+impl<T: Dictionary> LintGroup<T> {
+    /// Lint a document, running the paragraph-local linters only on the
+    /// paragraphs that changed since `cache` was last used.
+    ///
+    /// The other linters can look beyond a single paragraph (like
+    /// [`UnclosedQuotes`], which pairs quotes across them), so they are run
+    /// over the whole document every time.
+    /// The lints found are the same as those from [`Linter::lint`], though
+    /// not necessarily in the same order.
+    pub fn lint_with_cache(
+        &mut self,
+        cache: &mut ParagraphLintCache,
+        document: &Document,
+    ) -> Vec<Lint> {
+        let is_local = |rule: &str| PARAGRAPH_LOCAL_RULES.contains(&rule);
+
+        let mut lints = cache.lint(document, |paragraph| self.run_linters(paragraph, is_local));
+        lints.extend(self.run_linters(document, |rule| !is_local(rule)));
+
+        self.apply_directives(&mut lints, document);
+        lints
+    }
+
+    /// Remove the lints silenced by ignore directives, then flag the
+    /// directives that name unknown rules.
+    fn apply_directives(&self, lints: &mut Vec<Lint>, document: &Document) {
+        let directives = IgnoreDirectives::new_from_document(document);
+
+        if !directives.is_empty() {
+            lints.retain(|lint| !directives.is_ignored(lint));
+            lints.extend(self.lint_unknown_rules(&directives, document));
+        }
+    }
+
+    /// Rebuild the configurable linters from [`LintGroupConfig::options`] and
+    /// [`LintGroupConfig::dialect`].
+    fn apply_options(&mut self) {
+        let options = &self.config.options;
+
+        self.long_sentences =
+            LongSentences::new(options.long_sentences.clone().unwrap_or_default());
+        self.boring_words = BoringWords::new(options.boring_words.clone().unwrap_or_default());
+        self.avoid_curses = AvoidCurses::new(options.avoid_curses.clone().unwrap_or_default());
+        self.wrong_quotes = WrongQuotes::new(options.wrong_quotes.clone().unwrap_or_default());
+        self.grade_level = GradeLevel::new(options.grade_level.clone().unwrap_or_default());
+
+        self.applied_options = options.clone();
+
+        self.dialect_spelling = DialectSpelling::new(self.config.dialect.unwrap_or_default());
+        self.applied_dialect = self.config.dialect;
+    }
+}
+
 impl<T: Dictionary + Default> Default for LintGroup<T> {
     fn default() -> Self {
         Self::new(LintGroupConfig::default(), T::default())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{LintGroup, LintGroupConfig};
+    use crate::linting::{Lint, Linter, LongSentencesOptions, ReplacementRules};
+    use crate::{Dialect, Document, FstDictionary};
+
+    #[test]
+    fn fills_in_rules() {
+        let document = Document::new_markdown_curated("This is a tesst of the the linter.");
+        let mut linter = LintGroup::new(LintGroupConfig::default(), FstDictionary::curated());
+
+        let mut rules: Vec<_> = linter
+            .lint(&document)
+            .into_iter()
+            .map(|lint| lint.rule.unwrap())
+            .collect();
+        rules.sort();
+
+        assert_eq!(rules, vec!["RepeatedWords", "SpellCheck"]);
+    }
+
+    #[test]
+    fn lints_in_a_stable_order() {
+        let document = Document::new_markdown_curated(
+            "This is a tesst of the the linter.\n\nAnother paragraf, with with more misteaks.",
+        );
+        let mut linter = LintGroup::new(LintGroupConfig::default(), FstDictionary::curated());
+
+        let summarize = |lints: Vec<Lint>| -> Vec<_> {
+            lints
+                .into_iter()
+                .map(|lint| (lint.rule.unwrap(), lint.span))
+                .collect()
+        };
+
+        let first = summarize(linter.lint(&document));
+
+        // Linters are reported in the order they're declared, each in document order.
+        assert_eq!(
+            first
+                .iter()
+                .map(|(rule, _)| rule.as_str())
+                .collect::<Vec<_>>(),
+            vec![
+                "RepeatedWords",
+                "RepeatedWords",
+                "SpellCheck",
+                "SpellCheck",
+                "SpellCheck"
+            ]
+        );
+        assert!(first[2].1.start < first[3].1.start && first[3].1.start < first[4].1.start);
+
+        for _ in 0..4 {
+            assert_eq!(summarize(linter.lint(&document)), first);
+        }
+    }
+
+    #[test]
+    fn applies_linter_options() {
+        let document = Document::new_markdown_curated("This is a short sentence.");
+        let mut linter = LintGroup::new(LintGroupConfig::default(), FstDictionary::curated());
+
+        assert!(linter.lint(&document).is_empty());
+
+        linter.config.options.long_sentences = Some(LongSentencesOptions { max_words: 3 });
+        let lints = linter.lint(&document);
+
+        assert_eq!(lints.len(), 1);
+        assert_eq!(lints[0].rule.as_deref(), Some("LongSentences"));
+    }
+
+    #[test]
+    fn applies_dialect() {
+        let document = Document::new_markdown_curated("What color is it?");
+        let mut config = LintGroupConfig::none();
+        config.dialect_spelling = Some(true);
+
+        let mut linter = LintGroup::new(config, FstDictionary::curated());
+        assert!(linter.lint(&document).is_empty());
+
+        linter.config.dialect = Some(Dialect::British);
+        let lints = linter.lint(&document);
+
+        assert_eq!(lints.len(), 1);
+        assert_eq!(lints[0].rule.as_deref(), Some("DialectSpelling"));
+    }
+
+    #[test]
+    fn overrides_linter_options() {
+        let mut config: LintGroupConfig =
+            serde_json::from_str(r#"{ "options": { "wrong_quotes": { "style": "straight" } } }"#)
+                .unwrap();
+        config.override_with(
+            &serde_json::from_str(r#"{ "options": { "long_sentences": { "max_words": 3 } } }"#)
+                .unwrap(),
+        );
+
+        assert!(config.options.wrong_quotes.is_some());
+        assert!(config.options.long_sentences.is_some());
+        assert_eq!(
+            serde_json::to_value(LintGroupConfig::default()).unwrap()["options"],
+            serde_json::Value::Null
+        );
+    }
+
+    #[test]
+    fn runs_replacement_rules() {
+        let document = Document::new_markdown_curated("We utilize a linter.");
+        let mut linter = LintGroup::new(LintGroupConfig::default(), FstDictionary::curated());
+        linter.replacement_rules = ReplacementRules::from_toml_str(
+            "[[rules]]\nphrase = \"utilize\"\nreplace_with = \"use\"",
+        )
+        .unwrap();
+
+        let lints = linter.lint(&document);
+
+        assert_eq!(lints.len(), 1);
+        assert_eq!(lints[0].rule.as_deref(), Some("ReplacementRules"));
+    }
+}
@@ -1,10 +1,34 @@
+use serde::{Deserialize, Serialize};
+
 use super::{Lint, LintKind, Linter};
 use crate::token::TokenStringExt;
 use crate::{Document, Span};
 
+/// Options for the [`LongSentences`] linter.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LongSentencesOptions {
+    /// The longest a sentence may be, in words, before it is flagged.
+    pub max_words: usize,
+}
+
+impl Default for LongSentencesOptions {
+    fn default() -> Self {
+        Self { max_words: 40 }
+    }
+}
+
 /// Detect and warn that the sentence is too long.
-#[derive(Debug, Clone, Copy, Default)]
-pub struct LongSentences;
+#[derive(Debug, Clone, Default)]
+pub struct LongSentences {
+    options: LongSentencesOptions,
+}
+
+impl LongSentences {
+    pub fn new(options: LongSentencesOptions) -> Self {
+        Self { options }
+    }
+}
 
 impl Linter for LongSentences {
     fn lint(&mut self, document: &Document) -> Vec<Lint> {
@@ -13,7 +37,7 @@ impl Linter for LongSentences {
         for sentence in document.iter_sentences() {
             let word_count = sentence.iter_words().count();
 
-            if word_count > 40 {
+            if word_count > self.options.max_words {
                 output.push(Lint {
                     span: Span::new(sentence[0].span.start, sentence.last().unwrap().span.end),
                     lint_kind: LintKind::Readability,
@@ -26,3 +50,27 @@ impl Linter for LongSentences {
         output
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{LongSentences, LongSentencesOptions};
+    use crate::linting::tests::assert_lint_count;
+
+    #[test]
+    fn allows_short_sentences() {
+        assert_lint_count(
+            "This sentence is short enough.",
+            LongSentences::default(),
+            0,
+        );
+    }
+
+    #[test]
+    fn respects_max_words() {
+        assert_lint_count(
+            "This sentence is short enough.",
+            LongSentences::new(LongSentencesOptions { max_words: 4 }),
+            1,
+        );
+    }
+}
@@ -357,6 +357,7 @@ impl Linter for Matcher {
                             trigger.replace_with.iter().collect::<String>()
                         ),
                         priority: 15,
+                        ..Default::default()
                     })
                 }
             }
@@ -3,8 +3,11 @@ mod avoid_curses;
 mod boring_words;
 mod capitalize_personal_pronouns;
 mod correct_number_suffix;
+mod dialect_spelling;
 mod dot_initialisms;
 mod ellipsis_length;
+mod grade_level;
+mod ignore_directives;
 mod linking_verbs;
 mod lint;
 mod lint_group;
@@ -12,43 +15,56 @@ mod long_sentences;
 mod matcher;
 mod multiple_sequential_pronouns;
 mod number_suffix_capitalization;
+mod paragraph_cache;
+mod passive_voice;
 mod pattern_linter;
 mod repeated_words;
+mod replacement_rules;
 mod sentence_capitalization;
 mod spaces;
 mod spell_check;
 mod spelled_numbers;
+mod subject_verb_agreement;
 mod terminating_conjunctions;
+mod text_pattern_linter;
 mod that_which;
 mod unclosed_quotes;
 mod use_genitive;
 mod wrong_quotes;
 
 pub use an_a::AnA;
-pub use avoid_curses::AvoidCurses;
-pub use boring_words::BoringWords;
+pub use avoid_curses::{AvoidCurses, AvoidCursesOptions};
+pub use boring_words::{BoringWords, BoringWordsOptions};
 pub use capitalize_personal_pronouns::CapitalizePersonalPronouns;
 pub use correct_number_suffix::CorrectNumberSuffix;
+pub use dialect_spelling::DialectSpelling;
 pub use dot_initialisms::DotInitialisms;
 pub use ellipsis_length::EllipsisLength;
+pub use grade_level::{GradeLevel, GradeLevelOptions};
+pub use ignore_directives::IgnoreDirectives;
 pub use linking_verbs::LinkingVerbs;
 pub use lint::{Lint, LintKind, Suggestion};
-pub use lint_group::{LintGroup, LintGroupConfig};
-pub use long_sentences::LongSentences;
+pub use lint_group::{LintGroup, LintGroupConfig, LinterOptions};
+pub use long_sentences::{LongSentences, LongSentencesOptions};
 pub use matcher::Matcher;
 pub use multiple_sequential_pronouns::MultipleSequentialPronouns;
 pub use number_suffix_capitalization::NumberSuffixCapitalization;
+pub use paragraph_cache::ParagraphLintCache;
+pub use passive_voice::PassiveVoice;
 pub use pattern_linter::PatternLinter;
 pub use repeated_words::RepeatedWords;
+pub use replacement_rules::{ReplacementRule, ReplacementRules, ReplacementRulesError};
 pub use sentence_capitalization::SentenceCapitalization;
 pub use spaces::Spaces;
 pub use spell_check::SpellCheck;
 pub use spelled_numbers::SpelledNumbers;
+pub use subject_verb_agreement::SubjectVerbAgreement;
 pub use terminating_conjunctions::TerminatingConjunctions;
+pub use text_pattern_linter::{PatternRule, TextPatternLinter};
 pub use that_which::ThatWhich;
 pub use unclosed_quotes::UnclosedQuotes;
 pub use use_genitive::UseGenitive;
-pub use wrong_quotes::WrongQuotes;
+pub use wrong_quotes::{QuoteStyle, WrongQuotes, WrongQuotesOptions};
 
 use crate::Document;
 
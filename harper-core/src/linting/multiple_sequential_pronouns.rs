@@ -25,17 +25,28 @@ impl MultipleSequentialPronouns {
         Self {
             pattern: Box::new(
                 SequencePattern::default()
-                    .then_any_word_in(pronouns.clone())
+                    .then(pronoun(pronouns.clone()))
                     .then_one_or_more(Box::new(
                         SequencePattern::default()
                             .then_whitespace()
-                            .then_any_word_in(pronouns.clone()),
+                            .then(pronoun(pronouns.clone())),
                     )),
             ),
         }
     }
 }
 
+/// Matches any of `pronouns`, unless it is being used as a determiner, like
+/// “my” in “give me my book”.
+fn pronoun(pronouns: Lrc<HashSet<&'static str>>) -> Box<dyn Pattern> {
+    Box::new(move |tok: &Token, source: &[char]| {
+        let word: String = tok.span.get_content(source).iter().collect();
+
+        pronouns.contains(word.as_str())
+            && !tok.kind.as_word().is_some_and(|meta| meta.is_determiner())
+    })
+}
+
 impl PatternLinter for MultipleSequentialPronouns {
     fn pattern(&self) -> &dyn crate::patterns::Pattern {
         self.pattern.as_ref()
@@ -59,6 +70,7 @@ impl PatternLinter for MultipleSequentialPronouns {
             message: "There are too many personal pronouns in sequence here.".to_owned(),
             priority: 63,
             suggestions,
+            ..Default::default()
         }
     }
 }
@@ -110,6 +122,11 @@ mod tests {
         )
     }
 
+    #[test]
+    fn allows_possessive_determiners() {
+        assert_lint_count("Give me my book.", MultipleSequentialPronouns::new(), 0)
+    }
+
     #[test]
     fn comma_separated() {
         assert_lint_count("To prove it, we...", MultipleSequentialPronouns::new(), 0)
@@ -0,0 +1,236 @@
+use hashbrown::HashMap;
+
+use super::Lint;
+use crate::{Document, TokenStringExt};
+
+/// Remembers the lints found in each paragraph of a document, so that only
+/// the paragraphs that changed since the last run have to be linted again.
+///
+/// Paragraphs are identified by their text, with their lints stored relative
+/// to their start, so they may move around the document freely.
+///
+/// Each paragraph is linted on its own, without the rest of the document, so
+/// its lints only depend on its text. That only gives the right results for
+/// linters that never look beyond a single paragraph, and ignore directives
+/// (which can silence lints far away from them) aren't applied at all.
+/// [`LintGroup::lint_with_cache`](super::LintGroup::lint_with_cache) takes
+/// care of both.
+///
+/// The cache knows nothing about the linter it is used with, so it should be
+/// [cleared](Self::clear) whenever the linter's configuration changes.
+#[derive(Debug, Default)]
+pub struct ParagraphLintCache {
+    paragraphs: HashMap<Vec<char>, Vec<Lint>>,
+}
+
+impl ParagraphLintCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn clear(&mut self) {
+        self.paragraphs.clear();
+    }
+
+    /// Lint each paragraph of a document that isn't already in the cache with
+    /// `lint_paragraph`, returning the lints for the whole document.
+    pub fn lint(
+        &mut self,
+        document: &Document,
+        mut lint_paragraph: impl FnMut(&Document) -> Vec<Lint>,
+    ) -> Vec<Lint> {
+        let mut paragraphs = HashMap::new();
+        let mut lints = Vec::new();
+
+        for tokens in document.iter_paragraphs() {
+            let Some(span) = tokens.span() else {
+                continue;
+            };
+
+            let text = document.get_span_content(span).to_vec();
+
+            let found = match self.paragraphs.get(&text).or(paragraphs.get(&text)) {
+                Some(found) => found.clone(),
+                None => document
+                    .excerpt(tokens)
+                    .map(|excerpt| lint_paragraph(&excerpt))
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter(|lint| lint.span.end <= text.len())
+                    .collect(),
+            };
+
+            lints.extend(found.iter().cloned().map(|mut lint| {
+                lint.span.push_by(span.start);
+                lint
+            }));
+            paragraphs.insert(text, found);
+        }
+
+        self.paragraphs = paragraphs;
+
+        lints
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ParagraphLintCache;
+    use crate::linting::{Lint, LintGroup, LintGroupConfig, Linter};
+    use crate::parsers::Markdown;
+    use crate::{Document, FstDictionary, Span};
+
+    const TEXT: &str = "This is a tesst of the cache.\n\nthis paragraph has has problems.\n\n## A heading with an mistake\n\n- A list\n- with \"quotes\" and an \"unclosed one\n\nThe end.";
+
+    fn linter() -> LintGroup<std::sync::Arc<FstDictionary>> {
+        let config = LintGroupConfig {
+            spelled_numbers: Some(true),
+            grade_level: Some(true),
+            ..Default::default()
+        };
+
+        LintGroup::new(config, FstDictionary::curated())
+    }
+
+    fn sorted(lints: &[Lint]) -> Vec<(Span, Option<String>)> {
+        let mut lints: Vec<_> = lints.iter().map(|l| (l.span, l.rule.clone())).collect();
+        lints.sort_by_key(|(s, rule)| (s.start, s.end, rule.clone()));
+        lints
+    }
+
+    #[test]
+    fn matches_linting_the_whole_document() {
+        let document = Document::new_markdown_curated(TEXT);
+
+        let expected = linter().lint(&document);
+        let found = linter().lint_with_cache(&mut ParagraphLintCache::new(), &document);
+
+        assert!(!expected.is_empty());
+        assert_eq!(sorted(&found), sorted(&expected));
+    }
+
+    /// Apply each edit in turn, making sure the cached lints always match
+    /// those found by linting the edited document from scratch.
+    fn assert_edits_match(text: &str, edits: &[(&str, &str)]) {
+        let dict = FstDictionary::curated();
+        let mut document = Document::new(text, &mut Markdown, &dict);
+        let mut text = text.to_string();
+
+        let mut cache = ParagraphLintCache::new();
+        let mut cached_linter = linter();
+        cached_linter.lint_with_cache(&mut cache, &document);
+
+        for (find, replace) in edits {
+            let byte_start = text.find(find).unwrap();
+            let start = text[..byte_start].chars().count();
+            let replacement: Vec<char> = replace.chars().collect();
+
+            document.apply_edit(
+                Span::new(start, start + find.chars().count()),
+                &replacement,
+                &mut Markdown,
+                &dict,
+            );
+            text = text.replacen(find, replace, 1);
+
+            let expected = linter().lint(&Document::new(&text, &mut Markdown, &dict));
+            let found = cached_linter.lint_with_cache(&mut cache, &document);
+
+            assert_eq!(sorted(&found), sorted(&expected), "after editing {find:?}");
+        }
+    }
+
+    #[test]
+    fn matches_linting_the_whole_document_after_edits() {
+        assert_edits_match(
+            TEXT,
+            &[
+                ("tesst", "test"),
+                ("has has", "has"),
+                ("The end.", "the end. there are 3 more"),
+                ("\"unclosed one", "\"closed\" one"),
+            ],
+        );
+    }
+
+    #[test]
+    fn matches_quotes_across_paragraphs() {
+        assert_edits_match(
+            "He said \"this goes on.\n\nAnd on, until now\" and left.\n\nThe end.",
+            &[
+                ("now\"", "now"),
+                ("until now", "until now\""),
+                ("He said \"", "He said "),
+            ],
+        );
+    }
+
+    #[test]
+    fn only_lints_changed_paragraphs() {
+        let mut cache = ParagraphLintCache::new();
+        let mut linted = 0;
+        let mut count = |_: &Document| {
+            linted += 1;
+            Vec::new()
+        };
+
+        cache.lint(
+            &Document::new_plain_english_curated("A.\n\nB.\n\nC."),
+            &mut count,
+        );
+        cache.lint(
+            &Document::new_plain_english_curated("A.\n\nD.\n\nC."),
+            &mut count,
+        );
+
+        assert_eq!(linted, 4);
+    }
+
+    #[test]
+    fn respects_new_directives() {
+        let mut cache = ParagraphLintCache::new();
+        let mut linter = linter();
+        linter.lint_with_cache(&mut cache, &Document::new_markdown_curated(TEXT));
+
+        let text = format!("<!-- harper:disable -->\n\n{TEXT}");
+        let found = linter.lint_with_cache(&mut cache, &Document::new_markdown_curated(&text));
+
+        assert!(found.is_empty());
+    }
+
+    #[test]
+    fn respects_paragraphs_moving_across_directives() {
+        let region = |a: &str, b: &str| {
+            format!("<!-- harper:disable -->\n\nFirst.\n\n{a}\n\n<!-- harper:enable -->\n\nLast.\n\n{b}\n\nThe end.")
+        };
+        let inside = region("This is a tesst.", "All is well.");
+        let outside = region("All is well.", "This is a tesst.");
+
+        let mut cache = ParagraphLintCache::new();
+        let mut cached_linter = linter();
+
+        for text in [&inside, &outside, &inside] {
+            let document = Document::new_markdown_curated(text);
+
+            let expected = linter().lint(&document);
+            let found = cached_linter.lint_with_cache(&mut cache, &document);
+
+            assert_eq!(sorted(&found), sorted(&expected));
+        }
+
+        let found =
+            cached_linter.lint_with_cache(&mut cache, &Document::new_markdown_curated(&outside));
+        assert_eq!(found.len(), 1);
+    }
+
+    #[test]
+    fn reports_unknown_rules_in_directives() {
+        let text = "<!-- harper:disable SpellCheck because -->\n\nThis is a tesst.";
+        let document = Document::new_markdown_curated(text);
+
+        let found = linter().lint_with_cache(&mut ParagraphLintCache::new(), &document);
+
+        assert_eq!(sorted(&found), sorted(&linter().lint(&document)));
+        assert_eq!(found.len(), 1);
+    }
+}
@@ -0,0 +1,202 @@
+use crate::patterns::{EitherPattern, Pattern, SequencePattern};
+use crate::{CharStringExt, Token, TokenStringExt, VerbForm};
+
+use super::{Lint, LintKind, PatternLinter};
+
+/// The forms of “be” that start a passive construction.
+const BE_FORMS: [&str; 8] = ["am", "is", "are", "was", "were", "be", "been", "being"];
+
+/// Past participles that usually describe a state, like “I am tired”, rather
+/// than something done to the subject. They are only flagged when followed
+/// by a “by” phrase.
+const ADJECTIVAL_PARTICIPLES: [&str; 14] = [
+    "tired",
+    "interested",
+    "excited",
+    "bored",
+    "pleased",
+    "worried",
+    "surprised",
+    "married",
+    "concerned",
+    "involved",
+    "based",
+    "supposed",
+    "used",
+    "done",
+];
+
+/// Flags passive constructions, like “the bug was fixed by the team”, which
+/// are often clearer in the active voice (“the team fixed the bug”).
+pub struct PassiveVoice {
+    pattern: Box<dyn Pattern>,
+}
+
+fn is_one_of(token: &Token, source: &[char], words: &[&str]) -> bool {
+    let word = token.span.get_content(source).to_lower();
+    token.kind.is_word() && words.iter().any(|w| w.chars().eq(word.iter().copied()))
+}
+
+fn is_be_form(token: &Token, source: &[char]) -> bool {
+    is_one_of(token, source, &BE_FORMS)
+}
+
+fn is_past_participle(token: &Token, _source: &[char]) -> bool {
+    // The tagger prefers adjectives after linking verbs, so the dictionary
+    // data is used instead of the tag.
+    token.kind.as_word().is_some_and(|metadata| {
+        metadata
+            .verb
+            .is_some_and(|verb| verb.is_form(VerbForm::PastParticiple))
+    })
+}
+
+fn is_passive_participle(token: &Token, source: &[char]) -> bool {
+    is_past_participle(token, source) && !is_one_of(token, source, &ADJECTIVAL_PARTICIPLES)
+}
+
+fn is_determiner(token: &Token, _source: &[char]) -> bool {
+    token.kind.as_word().is_some_and(|m| m.is_determiner())
+}
+
+/// “was written” or “was quickly written”.
+fn be_then(participle: fn(&Token, &[char]) -> bool) -> EitherPattern {
+    EitherPattern::new(vec![
+        Box::new(
+            SequencePattern::default()
+                .then(Box::new(is_be_form))
+                .then_whitespace()
+                .then_adverb()
+                .then_whitespace()
+                .then(Box::new(participle)),
+        ),
+        Box::new(
+            SequencePattern::default()
+                .then(Box::new(is_be_form))
+                .then_whitespace()
+                .then(Box::new(participle)),
+        ),
+    ])
+}
+
+impl Default for PassiveVoice {
+    fn default() -> Self {
+        // “by the team” or “by Alice”
+        let agent = EitherPattern::new(vec![
+            Box::new(
+                SequencePattern::default()
+                    .then(Box::new(is_determiner))
+                    .then_whitespace()
+                    .then_any_word(),
+            ),
+            Box::new(SequencePattern::default().then_any_word()),
+        ]);
+
+        let pattern = EitherPattern::new(vec![
+            Box::new(
+                SequencePattern::default()
+                    .then(Box::new(be_then(is_past_participle)))
+                    .then_whitespace()
+                    .then_exact_word_or_lowercase("by")
+                    .then_whitespace()
+                    .then(Box::new(agent)),
+            ),
+            Box::new(be_then(is_passive_participle)),
+        ]);
+
+        Self {
+            pattern: Box::new(pattern),
+        }
+    }
+}
+
+impl PatternLinter for PassiveVoice {
+    fn pattern(&self) -> &dyn Pattern {
+        self.pattern.as_ref()
+    }
+
+    fn match_to_lint(&self, matched_tokens: &[Token], _source: &[char]) -> Lint {
+        Lint {
+            span: matched_tokens.span().unwrap(),
+            lint_kind: LintKind::Readability,
+            message:
+                "This is in the passive voice. Consider saying who does what, in the active voice."
+                    .to_string(),
+            priority: 127,
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::assert_lint_count;
+    use super::PassiveVoice;
+    use crate::linting::Linter;
+    use crate::Document;
+
+    /// The text of each passive construction found in `text`.
+    fn passive_spans(text: &str) -> Vec<String> {
+        let document = Document::new_markdown_curated(text);
+
+        PassiveVoice::default()
+            .lint(&document)
+            .into_iter()
+            .map(|lint| document.get_span_content_str(lint.span))
+            .collect()
+    }
+
+    #[test]
+    fn catches_simple_passive() {
+        assert_eq!(passive_spans("The cake was eaten."), vec!["was eaten"]);
+    }
+
+    #[test]
+    fn includes_by_phrase() {
+        assert_eq!(
+            passive_spans("The bug was fixed by the team yesterday."),
+            vec!["was fixed by the team"]
+        );
+        assert_eq!(
+            passive_spans("The report is reviewed by Alice."),
+            vec!["is reviewed by Alice"]
+        );
+    }
+
+    #[test]
+    fn looks_past_adverbs() {
+        assert_eq!(
+            passive_spans("The code was carefully reviewed."),
+            vec!["was carefully reviewed"]
+        );
+    }
+
+    #[test]
+    fn catches_perfect_and_progressive() {
+        assert_lint_count(
+            "The file has been deleted. The road is being built.",
+            PassiveVoice::default(),
+            2,
+        );
+    }
+
+    #[test]
+    fn catches_irregular_participles() {
+        assert_lint_count("The letter was written.", PassiveVoice::default(), 1);
+    }
+
+    #[test]
+    fn allows_active_voice() {
+        assert_lint_count(
+            "The team fixed the bug. She is writing a letter. He was happy.",
+            PassiveVoice::default(),
+            0,
+        );
+    }
+
+    #[test]
+    fn allows_adjectival_participles() {
+        assert_lint_count("I am tired. We are interested.", PassiveVoice::default(), 0);
+        assert_lint_count("I was tired by the trip.", PassiveVoice::default(), 1);
+    }
+}
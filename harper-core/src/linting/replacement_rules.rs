@@ -0,0 +1,445 @@
+use std::path::Path;
+
+use serde::{Deserialize, Deserializer, Serialize};
+
+use super::text_pattern_linter::{PatternRule, TextPatternLinter};
+use super::{Lint, LintKind, Linter, Suggestion};
+use crate::parsers::{Parser, PlainEnglish};
+use crate::patterns::PatternSyntaxError;
+use crate::{CharStringExt, Document, Span, TokenKind};
+
+#[derive(Debug, thiserror::Error)]
+pub enum ReplacementRulesError {
+    #[error("Unable to read replacement rules: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Invalid TOML in replacement rules: {0}")]
+    Toml(#[from] toml::de::Error),
+    #[error("Invalid JSON in replacement rules: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("The phrase for replacement rule {0} is empty.")]
+    EmptyPhrase(usize),
+    #[error("Invalid pattern rule {0}: {1}")]
+    Pattern(usize, PatternSyntaxError),
+}
+
+/// A user-defined phrase that should be flagged, along with what it should be
+/// replaced with.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ReplacementRule {
+    /// Used as the [`Lint::rule`] of the lints this rule produces, so it can
+    /// be referred to by ignore directives and severity overrides.
+    /// Defaults to `ReplacementRules`.
+    pub name: Option<String>,
+    /// The phrase to search for.
+    pub phrase: String,
+    /// The suggested replacements, if any.
+    /// Text that already matches one of these exactly is not flagged, which
+    /// makes it possible to enforce a particular capitalization.
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub replace_with: Vec<String>,
+    /// The message shown to the user.
+    /// If not provided, one is generated from the replacements.
+    pub message: Option<String>,
+    #[serde(default)]
+    pub kind: LintKind,
+    /// Whether the phrase should only match text with the same capitalization.
+    #[serde(default)]
+    pub case_sensitive: bool,
+}
+
+pub(super) fn one_or_many<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        One(String),
+        Many(Vec<String>),
+    }
+
+    Ok(match OneOrMany::deserialize(deserializer)? {
+        OneOrMany::One(s) => vec![s],
+        OneOrMany::Many(v) => v,
+    })
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ReplacementRulesFile {
+    #[serde(default)]
+    rules: Vec<ReplacementRule>,
+    #[serde(default)]
+    patterns: Vec<PatternRule>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum PhrasePart {
+    /// Any amount of whitespace.
+    Whitespace,
+    Word(Vec<char>),
+    /// Any other token, which must match exactly.
+    Other(Vec<char>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct CompiledRule {
+    rule: ReplacementRule,
+    pattern: Vec<PhrasePart>,
+    replacements: Vec<Vec<char>>,
+}
+
+impl CompiledRule {
+    fn new(rule: ReplacementRule) -> Option<Self> {
+        let phrase: Vec<char> = rule.phrase.trim().chars().collect();
+        let tokens = PlainEnglish.parse(&phrase);
+
+        let pattern: Vec<_> = tokens
+            .iter()
+            .map(|token| {
+                let content = token.span.get_content(&phrase).to_vec();
+
+                match token.kind {
+                    TokenKind::Space(_) | TokenKind::Newline(_) => PhrasePart::Whitespace,
+                    TokenKind::Word(_) => PhrasePart::Word(content),
+                    _ => PhrasePart::Other(content),
+                }
+            })
+            .collect();
+
+        if pattern.is_empty() {
+            return None;
+        }
+
+        let replacements = rule
+            .replace_with
+            .iter()
+            .map(|r| r.chars().collect())
+            .collect();
+
+        Some(Self {
+            rule,
+            pattern,
+            replacements,
+        })
+    }
+
+    /// Check whether the rule matches the tokens starting at `index`,
+    /// returning the matched span.
+    fn matches_at(&self, document: &Document, index: usize) -> Option<Span> {
+        let mut span: Option<Span> = None;
+
+        for (offset, part) in self.pattern.iter().enumerate() {
+            let token = document.get_token(index + offset)?;
+            let content = document.get_span_content(token.span);
+
+            let is_match = match part {
+                PhrasePart::Whitespace => {
+                    matches!(token.kind, TokenKind::Space(_) | TokenKind::Newline(1))
+                }
+                PhrasePart::Word(word) if self.rule.case_sensitive => {
+                    token.kind.is_word() && content == word.as_slice()
+                }
+                PhrasePart::Word(word) => {
+                    token.kind.is_word() && content.to_lower() == word.to_lower()
+                }
+                PhrasePart::Other(other) => content == other.as_slice(),
+            };
+
+            if !is_match {
+                return None;
+            }
+
+            span = Some(match span {
+                Some(span) => Span::new(span.start, token.span.end),
+                None => token.span,
+            });
+        }
+
+        span
+    }
+
+    fn message(&self) -> String {
+        if let Some(message) = &self.rule.message {
+            return message.clone();
+        }
+
+        match self.rule.replace_with.as_slice() {
+            [] => format!("Avoid “{}”.", self.rule.phrase),
+            [replacement] => format!("Did you mean “{}”?", replacement),
+            replacements => format!(
+                "Did you mean one of {}?",
+                replacements
+                    .iter()
+                    .map(|r| format!("“{}”", r))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        }
+    }
+}
+
+/// A linter that flags phrases defined by the user, like the built-in
+/// [`Matcher`](super::Matcher) does for common mistakes.
+///
+/// Rules are usually loaded from a TOML or JSON file:
+///
+/// ```toml
+/// [[rules]]
+/// phrase = "github"
+/// replace_with = "GitHub"
+/// kind = "Capitalization"
+///
+/// [[rules]]
+/// name = "BannedPhrases"
+/// phrase = "leverage"
+/// replace_with = ["use", "apply"]
+/// message = "Our style guide prefers plainer words."
+/// ```
+///
+/// The same file may also contain [`PatternRule`]s, for mistakes that can't
+/// be described by a fixed phrase:
+///
+/// ```toml
+/// [[patterns]]
+/// pattern = '("very" | "really") [ws] "unique"'
+/// message = "Something is either unique or it isn't."
+/// replace_with = "{2}"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ReplacementRules {
+    rules: Vec<CompiledRule>,
+    patterns: Vec<TextPatternLinter>,
+}
+
+impl ReplacementRules {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load a rules file, determining its format from the extension.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ReplacementRulesError> {
+        let path = path.as_ref();
+        let source = std::fs::read_to_string(path)?;
+
+        if path.extension().is_some_and(|e| e == "json") {
+            Self::from_json_str(&source)
+        } else {
+            Self::from_toml_str(&source)
+        }
+    }
+
+    pub fn from_toml_str(source: &str) -> Result<Self, ReplacementRulesError> {
+        let file: ReplacementRulesFile = toml::from_str(source)?;
+        Self::from_file(file)
+    }
+
+    pub fn from_json_str(source: &str) -> Result<Self, ReplacementRulesError> {
+        let file: ReplacementRulesFile = serde_json::from_str(source)?;
+        Self::from_file(file)
+    }
+
+    fn from_file(file: ReplacementRulesFile) -> Result<Self, ReplacementRulesError> {
+        let mut compiled = Self::from_rules(file.rules)?;
+
+        for (index, rule) in file.patterns.into_iter().enumerate() {
+            let linter = TextPatternLinter::new(rule)
+                .map_err(|err| ReplacementRulesError::Pattern(index, err))?;
+            compiled.add_pattern(linter);
+        }
+
+        Ok(compiled)
+    }
+
+    pub fn from_rules(
+        rules: impl IntoIterator<Item = ReplacementRule>,
+    ) -> Result<Self, ReplacementRulesError> {
+        let mut compiled = Self::new();
+
+        for (index, rule) in rules.into_iter().enumerate() {
+            if !compiled.add_rule(rule) {
+                return Err(ReplacementRulesError::EmptyPhrase(index));
+            }
+        }
+
+        Ok(compiled)
+    }
+
+    /// Add a rule, returning `false` if its phrase was empty.
+    pub fn add_rule(&mut self, rule: ReplacementRule) -> bool {
+        match CompiledRule::new(rule) {
+            Some(compiled) => {
+                self.rules.push(compiled);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn add_pattern(&mut self, linter: TextPatternLinter) {
+        self.patterns.push(linter);
+    }
+
+    /// Add all the rules from `other` to `self`.
+    pub fn extend(&mut self, other: Self) {
+        self.rules.extend(other.rules);
+        self.patterns.extend(other.patterns);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty() && self.patterns.is_empty()
+    }
+
+    /// The names given to the rules and patterns, for those that have one.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.rules
+            .iter()
+            .filter_map(|rule| rule.rule.name.as_deref())
+            .chain(self.patterns.iter().filter_map(|pattern| pattern.name()))
+    }
+}
+
+impl Linter for ReplacementRules {
+    fn lint(&mut self, document: &Document) -> Vec<Lint> {
+        let mut lints = Vec::new();
+
+        for pattern in &mut self.patterns {
+            lints.append(&mut pattern.lint(document));
+        }
+
+        if self.rules.is_empty() {
+            return lints;
+        }
+
+        for index in 0..document.tokens().count() {
+            for rule in &self.rules {
+                let Some(span) = rule.matches_at(document, index) else {
+                    continue;
+                };
+
+                let content = document.get_span_content(span);
+
+                if rule.replacements.iter().any(|r| r.as_slice() == content) {
+                    continue;
+                }
+
+                lints.push(Lint {
+                    span,
+                    lint_kind: rule.rule.kind,
+                    suggestions: rule
+                        .replacements
+                        .iter()
+                        .map(|r| Suggestion::ReplaceWith(r.clone()))
+                        .collect(),
+                    message: rule.message(),
+                    priority: 15,
+                    rule: rule.rule.name.clone(),
+                });
+            }
+        }
+
+        lints
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ReplacementRules;
+    use crate::linting::tests::{assert_lint_count, assert_suggestion_result};
+    use crate::linting::{LintKind, Linter};
+    use crate::Document;
+
+    const RULES: &str = r#"
+[[rules]]
+phrase = "github"
+replace_with = "GitHub"
+kind = "Capitalization"
+
+[[rules]]
+name = "BannedPhrases"
+phrase = "circle back"
+replace_with = ["return", "revisit"]
+
+[[rules]]
+phrase = "synergy"
+message = "Please don't."
+case_sensitive = true
+"#;
+
+    fn rules() -> ReplacementRules {
+        ReplacementRules::from_toml_str(RULES).unwrap()
+    }
+
+    #[test]
+    fn enforces_capitalization() {
+        assert_suggestion_result("We host on Github.", rules(), "We host on GitHub.");
+    }
+
+    #[test]
+    fn allows_correct_capitalization() {
+        assert_lint_count("We host on GitHub.", rules(), 0);
+    }
+
+    #[test]
+    fn matches_across_whitespace() {
+        assert_suggestion_result("Let's circle  back later.", rules(), "Let's return later.");
+    }
+
+    #[test]
+    fn respects_case_sensitivity() {
+        assert_lint_count("Synergy is great.", rules(), 0);
+        assert_lint_count("We need synergy.", rules(), 1);
+    }
+
+    #[test]
+    fn uses_rule_metadata() {
+        let document = Document::new_plain_english_curated("Circle back on github.");
+        let lints = rules().lint(&document);
+
+        assert_eq!(lints.len(), 2);
+        assert_eq!(lints[0].rule.as_deref(), Some("BannedPhrases"));
+        assert_eq!(lints[0].message, "Did you mean one of “return”, “revisit”?");
+        assert!(matches!(lints[1].lint_kind, LintKind::Capitalization));
+        assert_eq!(lints[1].rule, None);
+    }
+
+    #[test]
+    fn parses_json() {
+        let rules = ReplacementRules::from_json_str(
+            r#"{ "rules": [{ "phrase": "utilize", "replace_with": "use" }] }"#,
+        )
+        .unwrap();
+
+        assert_suggestion_result("We utilize it.", rules, "We use it.");
+    }
+
+    #[test]
+    fn loads_patterns() {
+        let rules = ReplacementRules::from_toml_str(
+            r#"
+[[patterns]]
+name = "Unique"
+pattern = '("very" | "really") [ws] "unique"'
+message = "Something is either unique or it isn't."
+replace_with = "{2}"
+"#,
+        )
+        .unwrap();
+
+        assert_suggestion_result("It is very unique.", rules, "It is unique.");
+    }
+
+    #[test]
+    fn rejects_invalid_patterns() {
+        assert!(ReplacementRules::from_toml_str(
+            "[[patterns]]\npattern = \"[nonsense]\"\nmessage = \"\""
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn rejects_empty_phrases() {
+        assert!(ReplacementRules::from_toml_str("[[rules]]\nphrase = \" \"").is_err());
+    }
+}
@@ -51,6 +51,7 @@ impl Linter for SentenceCapitalization {
                                 priority: 31,
                                 message: "This sentence does not start with a capital letter"
                                     .to_string(),
+                                ..Default::default()
                             })
                         }
                     }
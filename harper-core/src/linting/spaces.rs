@@ -25,6 +25,7 @@ impl Linter for Spaces {
                             count
                         ),
                         priority: 15,
+                        ..Default::default()
                     })
                 }
             }
@@ -55,6 +56,7 @@ impl Linter for Spaces {
                     suggestions: vec![Suggestion::Remove],
                     message: "Unnecessary space at the end of the sentence.".to_string(),
                     priority: 63,
+                    ..Default::default()
                 })
             }
         }
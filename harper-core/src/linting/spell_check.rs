@@ -4,15 +4,19 @@ use smallvec::ToSmallVec;
 use super::lint::Suggestion;
 use super::{Lint, LintKind, Linter};
 use crate::document::Document;
-use crate::spell::suggest_correct_spelling;
-use crate::{CharString, Dictionary, TokenStringExt};
+use crate::spell::{
+    apply_context, score_splits, score_suggestions, sort_scored, SuggestionContext,
+};
+use crate::{CharString, Dictionary, Span, Token, TokenKind, TokenStringExt};
 
 pub struct SpellCheck<T>
 where
     T: Dictionary,
 {
     dictionary: T,
-    word_cache: HashMap<CharString, Vec<CharString>>,
+    /// Scored suggestions for each misspelled word, before the words around
+    /// each occurrence are taken into account.
+    word_cache: HashMap<CharString, Vec<(CharString, f32)>>,
 }
 
 impl<T: Dictionary> SpellCheck<T> {
@@ -25,47 +29,128 @@ impl<T: Dictionary> SpellCheck<T> {
 }
 
 impl<T: Dictionary> SpellCheck<T> {
-    fn cached_suggest_correct_spelling(&mut self, word: &[char]) -> Vec<CharString> {
-        let word = word.to_smallvec();
-
-        self.word_cache
-            .entry(word.clone())
-            .or_insert_with(|| {
-                // Back off until we find a match.
-                let mut suggestions = Vec::new();
-                let mut dist = 2;
-
-                while suggestions.is_empty() && dist < 5 {
-                    suggestions = suggest_correct_spelling(&word, 100, dist, &self.dictionary)
-                        .into_iter()
-                        .map(|v| v.to_smallvec())
-                        .collect();
-
-                    dist += 1;
-                }
+    fn suggest_correct_spelling(&self, word: &[char]) -> Vec<(CharString, f32)> {
+        // Back off until we find a match.
+        let mut suggestions = Vec::new();
+        let mut dist = 2;
 
-                suggestions
-            })
-            .clone()
+        while suggestions.is_empty() && dist < 5 {
+            suggestions = score_suggestions(word, 100, dist, &self.dictionary)
+                .into_iter()
+                .map(|(word, score)| (word.to_smallvec(), score))
+                .collect();
+
+            dist += 1;
+        }
+
+        // Run-together words, like “alot”.
+        suggestions.extend(score_splits(word, &self.dictionary));
+        sort_scored(&mut suggestions);
+
+        suggestions
     }
-}
 
-impl<T: Dictionary> Linter for SpellCheck<T> {
-    fn lint(&mut self, document: &Document) -> Vec<Lint> {
+    /// If the word at `index` was broken in two by a stray space, like
+    /// “impor tant”, get the span of both halves and the joined word.
+    fn join_broken_word(
+        &self,
+        tokens: &[Token],
+        index: usize,
+        document: &Document,
+    ) -> Option<(Span, CharString)> {
+        let around = |offset: isize| {
+            let i = index.checked_add_signed(offset)?;
+            tokens.get(i)
+        };
+
+        for (space, other) in [(1, 2), (-1, -2)] {
+            let (Some(space), Some(other)) = (around(space), around(other)) else {
+                continue;
+            };
+
+            if space.kind != TokenKind::Space(1) || !other.kind.is_word() {
+                continue;
+            }
+
+            let (first, second) = if other.span.start > tokens[index].span.start {
+                (tokens[index], *other)
+            } else {
+                (*other, tokens[index])
+            };
+
+            let mut joined: CharString = document.get_span_content(first.span).into();
+            joined.extend_from_slice(document.get_span_content(second.span));
+
+            if self.dictionary.contains_word(&joined) {
+                return Some((Span::new(first.span.start, second.span.end), joined));
+            }
+        }
+
+        None
+    }
+
+    /// Check the words among some tokens.
+    ///
+    /// Doesn't touch the cache, so it can be run on several paragraphs at
+    /// once. Instead, suggestions for words that weren't already cached are
+    /// added to `new_words`.
+    fn lint_tokens(
+        &self,
+        tokens: &[Token],
+        document: &Document,
+        new_words: &mut HashMap<CharString, Vec<(CharString, f32)>>,
+    ) -> Vec<Lint> {
         let mut lints = Vec::new();
+        // The end of the last pair of words that were joined, so the second
+        // half isn't flagged again.
+        let mut joined_until = 0;
 
-        for word in document.iter_words() {
+        for index in tokens.iter_word_indices() {
+            let word = tokens[index];
             let word_chars = document.get_span_content(word.span);
-            if self.dictionary.contains_word(word_chars) {
+            if word.span.start < joined_until || self.dictionary.contains_word(word_chars) {
                 continue;
             }
 
-            let mut possibilities = self.cached_suggest_correct_spelling(word_chars);
+            if let Some((span, joined)) = self.join_broken_word(tokens, index, document) {
+                joined_until = span.end;
+
+                lints.push(Lint {
+                    span,
+                    lint_kind: LintKind::Spelling,
+                    suggestions: vec![Suggestion::ReplaceWith(joined.to_vec())],
+                    message: format!(
+                        "Did you mean “{}”, without the space?",
+                        joined.iter().collect::<String>()
+                    ),
+                    priority: 63,
+                    ..Default::default()
+                });
 
-            if possibilities.len() > 3 {
-                possibilities.resize_with(3, || panic!());
+                continue;
             }
 
+            let key: CharString = word_chars.to_smallvec();
+
+            let mut scored = match self.word_cache.get(&key) {
+                Some(cached) => cached.clone(),
+                None => new_words
+                    .entry(key)
+                    .or_insert_with_key(|word| self.suggest_correct_spelling(word))
+                    .clone(),
+            };
+
+            let context = SuggestionContext {
+                previous: neighboring_word(tokens, index, false)
+                    .map(|t| document.get_span_content(t.span)),
+                next: neighboring_word(tokens, index, true)
+                    .map(|t| document.get_span_content(t.span)),
+            };
+            apply_context(&mut scored, context, &self.dictionary);
+
+            let mut possibilities: Vec<CharString> =
+                scored.into_iter().take(3).map(|(word, _)| word).collect();
+
             // If the misspelled word is capitalized, capitalize the results too.
             if let Some(mis_f) = word_chars.first() {
                 if mis_f.is_uppercase() {
@@ -88,9 +173,103 @@ impl<T: Dictionary> Linter for SpellCheck<T> {
                     document.get_span_content_str(word.span)
                 ),
                 priority: 63,
+                ..Default::default()
             })
         }
 
         lints
     }
 }
+
+/// The word directly before or after the token at `index`, if only
+/// whitespace separates them.
+fn neighboring_word(tokens: &[Token], index: usize, forward: bool) -> Option<Token> {
+    let neighbor = if forward {
+        tokens[index + 1..].iter().find(|t| !t.kind.is_whitespace())
+    } else {
+        tokens[..index]
+            .iter()
+            .rev()
+            .find(|t| !t.kind.is_whitespace())
+    };
+
+    neighbor.filter(|t| t.kind.is_word()).copied()
+}
+
+impl<T: Dictionary> Linter for SpellCheck<T> {
+    fn lint(&mut self, document: &Document) -> Vec<Lint> {
+        // Paragraphs are checked in parallel, then put back in order.
+        #[cfg(feature = "concurrent")]
+        let results: Vec<_> = {
+            use rayon::prelude::*;
+
+            let paragraphs: Vec<_> = document.iter_paragraphs().collect();
+            paragraphs
+                .into_par_iter()
+                .map(|tokens| {
+                    let mut new_words = HashMap::new();
+                    let lints = self.lint_tokens(tokens, document, &mut new_words);
+                    (lints, new_words)
+                })
+                .collect()
+        };
+        #[cfg(not(feature = "concurrent"))]
+        let results = {
+            let mut new_words = HashMap::new();
+            let lints = self.lint_tokens(document.get_tokens(), document, &mut new_words);
+            vec![(lints, new_words)]
+        };
+
+        let mut lints = Vec::new();
+
+        for (mut found, new_words) in results {
+            lints.append(&mut found);
+            self.word_cache.extend(new_words);
+        }
+
+        lints
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SpellCheck;
+    use crate::linting::tests::{assert_lint_count, assert_suggestion_result};
+    use crate::FstDictionary;
+
+    #[test]
+    fn splits_run_together_words() {
+        assert_suggestion_result(
+            "I like it alot.",
+            SpellCheck::new(FstDictionary::curated()),
+            "I like it a lot.",
+        );
+    }
+
+    #[test]
+    fn joins_broken_words() {
+        assert_suggestion_result(
+            "This is impor tant.",
+            SpellCheck::new(FstDictionary::curated()),
+            "This is important.",
+        );
+    }
+
+    #[test]
+    fn joins_onto_known_words() {
+        assert_suggestion_result(
+            "Please con tinue.",
+            SpellCheck::new(FstDictionary::curated()),
+            "Please continue.",
+        );
+    }
+
+    #[test]
+    fn flags_broken_words_once() {
+        assert_lint_count(
+            "This is impor tant.",
+            SpellCheck::new(FstDictionary::curated()),
+            1,
+        );
+    }
+}
@@ -23,6 +23,7 @@ impl Linter for SpelledNumbers {
                     )],
                     message: "Try to spell out numbers less than a hundred.".to_string(),
                     priority: 63,
+                    ..Default::default()
                 })
             }
         }
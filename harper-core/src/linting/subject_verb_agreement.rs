@@ -0,0 +1,412 @@
+use super::{Lint, LintKind, Linter, Suggestion};
+use crate::{CharStringExt, Document, Token, TokenStringExt, VerbForm};
+
+/// What kind of verb form a subject takes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Person {
+    /// “I”
+    First = 0,
+    /// “he”, “the list”
+    ThirdSingular = 1,
+    /// “we”, “you”, “they”, “the items”
+    Other = 2,
+}
+
+/// The forms of the helper verbs that change with their subject, in the order
+/// of [`Person`].
+const FORMS: [[&str; 3]; 8] = [
+    ["am", "is", "are"],
+    ["was", "was", "were"],
+    ["have", "has", "have"],
+    ["do", "does", "do"],
+    ["don't", "doesn't", "don't"],
+    ["wasn't", "wasn't", "weren't"],
+    ["haven't", "hasn't", "haven't"],
+    // There is no first person form of “isn't”.
+    ["", "isn't", "aren't"],
+];
+
+const PRONOUNS: [(&str, Person); 7] = [
+    ("i", Person::First),
+    ("he", Person::ThirdSingular),
+    ("she", Person::ThirdSingular),
+    ("it", Person::ThirdSingular),
+    ("we", Person::Other),
+    ("you", Person::Other),
+    ("they", Person::Other),
+];
+
+/// Words that start a noun phrase and say whether it is singular or plural.
+const SINGULAR_DETERMINERS: [&str; 6] = ["a", "an", "this", "that", "each", "every"];
+const PLURAL_DETERMINERS: [&str; 6] = ["these", "those", "many", "several", "both", "few"];
+
+/// Nouns that look plural, but usually take a singular verb (or either).
+const AMBIGUOUS_NOUNS: [&str; 8] = [
+    "news",
+    "series",
+    "species",
+    "means",
+    "physics",
+    "mathematics",
+    "economics",
+    "politics",
+];
+
+/// Nouns for a group, like “staff”, or that are their own plural, like
+/// “fish”, which can take either verb.
+const COLLECTIVE_NOUNS: [&str; 12] = [
+    "police",
+    "staff",
+    "team",
+    "crew",
+    "family",
+    "audience",
+    "committee",
+    "government",
+    "group",
+    "public",
+    "fish",
+    "deer",
+];
+
+/// Phrases that stand for an amount of whatever follows “of”, like
+/// “a number of users”, which agree with that instead.
+const QUANTIFIERS: [&str; 8] = [
+    "a number",
+    "a lot",
+    "lots",
+    "a majority",
+    "the majority",
+    "plenty",
+    "the rest",
+    "a couple",
+];
+
+/// Checks that verbs like “is” and “has” agree with their subject, as in
+/// “the list of items is” or “he doesn't”.
+///
+/// Only subjects at the start of a clause are checked, since those are the
+/// ones that can be found reliably.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SubjectVerbAgreement;
+
+impl Linter for SubjectVerbAgreement {
+    fn lint(&mut self, document: &Document) -> Vec<Lint> {
+        let mut lints = Vec::new();
+
+        for sentence in document.iter_sentences() {
+            for chunk in sentence.iter_chunks() {
+                lints.extend(lint_clause(chunk, document));
+            }
+        }
+
+        lints
+    }
+}
+
+/// Check the subject and verb at the start of a clause.
+fn lint_clause(chunk: &[Token], document: &Document) -> Option<Lint> {
+    // The leading words of the clause, up to the first punctuation.
+    let words: Vec<Token> = chunk
+        .iter()
+        .filter(|t| !t.kind.is_whitespace())
+        .take_while(|t| t.kind.is_word())
+        .copied()
+        .collect();
+
+    let text = |token: &Token| -> String {
+        document
+            .get_span_content(token.span)
+            .to_lower()
+            .iter()
+            .map(|c| if *c == '’' { '\'' } else { *c })
+            .collect()
+    };
+
+    let first = words.first()?;
+    let (person, verb_index) = match PRONOUNS.iter().find(|(p, _)| *p == text(first)) {
+        Some((_, person)) => (*person, 1),
+        None => noun_phrase(&words, &text)?,
+    };
+
+    // Look past adverbs, like “he also has”. Some, like “also”, have no
+    // part of speech in the dictionary.
+    let verb = words[verb_index..].iter().find(|w| {
+        !w.kind
+            .as_word()
+            .is_some_and(|m| m.pos_tag.is_none() || m.is_adverb())
+    })?;
+    let verb_text = text(verb);
+
+    let correct = correct_form(person, &verb_text)?;
+
+    // Keep the style of apostrophe the author used.
+    let original = document.get_span_content(verb.span);
+    let replacement: Vec<char> = correct
+        .chars()
+        .map(|c| match (c, original.contains(&'’')) {
+            ('\'', true) => '’',
+            _ => c,
+        })
+        .collect();
+
+    let subject = words[..verb_index]
+        .iter()
+        .map(|w| document.get_span_content_str(w.span))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    Some(Lint {
+        span: verb.span,
+        lint_kind: LintKind::Miscellaneous,
+        suggestions: vec![Suggestion::ReplaceWith(replacement)],
+        message: format!(
+            "“{}” doesn't agree with “{subject}”. Use “{correct}” instead.",
+            document.get_span_content_str(verb.span)
+        ),
+        priority: 31,
+        ..Default::default()
+    })
+}
+
+/// Read a noun phrase like “the list of items” from the start of `words`,
+/// returning what kind of subject it is and the index of the word after it.
+fn noun_phrase(words: &[Token], text: &impl Fn(&Token) -> String) -> Option<(Person, usize)> {
+    // In “running tests is fun”, the subject is the act of running, not the
+    // tests, so subjects headed by a gerund are left alone.
+    let first = words.first()?.kind.as_word()?;
+    if first
+        .verb
+        .is_some_and(|verb| verb.is_form(VerbForm::Gerund))
+    {
+        return None;
+    }
+
+    let (mut head, mut determiner, mut end) = simple_noun_phrase(words, 0, text)?;
+
+    let is_of = |index: usize| words.get(index).is_some_and(|w| text(w) == "of");
+
+    // In “a number of users”, the subject is “users”.
+    let quantity = match &determiner {
+        Some(determiner) => format!("{determiner} {}", text(&head)),
+        None => text(&head),
+    };
+
+    if QUANTIFIERS.contains(&quantity.as_str()) && is_of(end) {
+        (head, determiner, end) = simple_noun_phrase(words, end + 1, text)?;
+    }
+
+    // In “the list of items”, the head is “list”.
+    while is_of(end) {
+        let (_, _, next_end) = simple_noun_phrase(words, end + 1, text)?;
+        end = next_end;
+    }
+
+    // Subjects joined by “and” or “or” are too tricky to count.
+    if words
+        .get(end)
+        .is_some_and(|w| matches!(text(w).as_str(), "and" | "or" | "nor"))
+    {
+        return None;
+    }
+
+    let head_text = text(&head);
+    let head_meta = head.kind.as_word()?;
+
+    if AMBIGUOUS_NOUNS.contains(&head_text.as_str())
+        || COLLECTIVE_NOUNS.contains(&head_text.as_str())
+    {
+        return None;
+    }
+
+    let person = if head_meta.is_plural_noun()
+        || determiner
+            .as_deref()
+            .is_some_and(|d| PLURAL_DETERMINERS.contains(&d))
+    {
+        Person::Other
+    } else if head_meta.is_not_plural_noun()
+        || determiner
+            .as_deref()
+            .is_some_and(|d| SINGULAR_DETERMINERS.contains(&d))
+    {
+        Person::ThirdSingular
+    } else {
+        // Nouns without a plural form, like “sheep” or “data”, might be
+        // either.
+        return None;
+    };
+
+    Some((person, end))
+}
+
+/// Read a determiner, any adjectives and one or more nouns, starting at
+/// `start`. Returns the last noun, the determiner and the index after the
+/// phrase.
+fn simple_noun_phrase(
+    words: &[Token],
+    start: usize,
+    text: &impl Fn(&Token) -> String,
+) -> Option<(Token, Option<String>, usize)> {
+    let meta = |i: usize| words.get(i).and_then(|w| w.kind.as_word().copied());
+    let mut index = start;
+    let mut determiner = None;
+
+    let first = words.get(index)?;
+    if meta(index)?.is_determiner() || PLURAL_DETERMINERS.contains(&text(first).as_str()) {
+        determiner = Some(text(first));
+        index += 1;
+    }
+
+    while meta(index).is_some_and(|m| m.is_adjective()) {
+        index += 1;
+    }
+
+    let mut head = None;
+
+    while let Some(m) = meta(index) {
+        if !m.is_noun() || m.is_pronoun_noun() {
+            break;
+        }
+
+        head = Some(words[index]);
+        index += 1;
+    }
+
+    Some((head?, determiner, index))
+}
+
+/// The form of `verb` that agrees with `person`, if it doesn't already.
+fn correct_form(person: Person, verb: &str) -> Option<&'static str> {
+    // “If I were”, “if it were” are correct, so are left alone.
+    if verb == "were" && person != Person::Other {
+        return None;
+    }
+
+    let forms = FORMS.iter().find(|forms| forms.contains(&verb))?;
+    let correct = forms[person as usize];
+
+    if correct.is_empty() || correct == verb {
+        None
+    } else {
+        Some(correct)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SubjectVerbAgreement;
+    use crate::linting::tests::{assert_lint_count, assert_suggestion_result};
+
+    #[test]
+    fn catches_plural_verb_after_of_phrase() {
+        assert_suggestion_result(
+            "The list of items are long.",
+            SubjectVerbAgreement,
+            "The list of items is long.",
+        );
+    }
+
+    #[test]
+    fn catches_pronoun_contractions() {
+        assert_suggestion_result("He don't know.", SubjectVerbAgreement, "He doesn't know.");
+        assert_suggestion_result("She don’t care.", SubjectVerbAgreement, "She doesn’t care.");
+    }
+
+    #[test]
+    fn catches_singular_verb_with_plural_subject() {
+        assert_suggestion_result(
+            "The tests was slow.",
+            SubjectVerbAgreement,
+            "The tests were slow.",
+        );
+        assert_suggestion_result(
+            "They has a plan.",
+            SubjectVerbAgreement,
+            "They have a plan.",
+        );
+    }
+
+    #[test]
+    fn catches_first_person() {
+        assert_suggestion_result("I is here.", SubjectVerbAgreement, "I am here.");
+    }
+
+    #[test]
+    fn catches_after_comma() {
+        assert_suggestion_result(
+            "As expected, the build are green.",
+            SubjectVerbAgreement,
+            "As expected, the build is green.",
+        );
+    }
+
+    #[test]
+    fn looks_past_adverbs() {
+        assert_suggestion_result(
+            "It also have a flag.",
+            SubjectVerbAgreement,
+            "It also has a flag.",
+        );
+    }
+
+    #[test]
+    fn allows_correct_agreement() {
+        assert_lint_count(
+            "The list of items is long. He doesn't know. They have a plan.",
+            SubjectVerbAgreement,
+            0,
+        );
+    }
+
+    #[test]
+    fn allows_subjunctive() {
+        assert_lint_count("If I were you, I would stop.", SubjectVerbAgreement, 0);
+    }
+
+    #[test]
+    fn allows_compound_subjects() {
+        assert_lint_count("The cat and the dog are here.", SubjectVerbAgreement, 0);
+    }
+
+    #[test]
+    fn allows_ambiguous_plurals() {
+        assert_lint_count("The news is good.", SubjectVerbAgreement, 0);
+    }
+
+    #[test]
+    fn allows_quantifiers() {
+        assert_lint_count("A number of users are affected.", SubjectVerbAgreement, 0);
+        assert_lint_count("The majority of users are happy.", SubjectVerbAgreement, 0);
+        assert_lint_count("A lot of people are here.", SubjectVerbAgreement, 0);
+        assert_lint_count("A lot of water is wasted.", SubjectVerbAgreement, 0);
+    }
+
+    #[test]
+    fn catches_quantified_subjects() {
+        assert_suggestion_result(
+            "A number of users is affected.",
+            SubjectVerbAgreement,
+            "A number of users are affected.",
+        );
+    }
+
+    #[test]
+    fn allows_gerund_subjects() {
+        assert_lint_count("Running tests is fun.", SubjectVerbAgreement, 0);
+        assert_lint_count("Writing docs takes time.", SubjectVerbAgreement, 0);
+    }
+
+    #[test]
+    fn allows_collective_nouns() {
+        assert_lint_count("The police are here.", SubjectVerbAgreement, 0);
+        assert_lint_count("The staff are friendly.", SubjectVerbAgreement, 0);
+    }
+
+    #[test]
+    fn allows_nouns_that_are_their_own_plural() {
+        assert_lint_count("The fish are hungry.", SubjectVerbAgreement, 0);
+        assert_lint_count("The sheep are in the field.", SubjectVerbAgreement, 0);
+        assert_lint_count("The data are stored locally.", SubjectVerbAgreement, 0);
+    }
+}
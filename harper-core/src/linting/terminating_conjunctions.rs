@@ -66,6 +66,7 @@ impl PatternLinter for TerminatingConjunctions {
                  clause."
             ),
             priority: 63,
+            ..Default::default()
         }
     }
 }
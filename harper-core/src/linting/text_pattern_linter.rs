@@ -0,0 +1,228 @@
+use serde::{Deserialize, Serialize};
+
+use super::replacement_rules::one_or_many;
+use super::{Lint, LintKind, PatternLinter, Suggestion};
+use crate::patterns::{Pattern, PatternSyntaxError, TextPattern};
+use crate::{Lrc, Token, TokenStringExt};
+
+/// The definition of a [`TextPatternLinter`], usually read from a rules file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PatternRule {
+    /// Used as the [`Lint::rule`] of the lints this rule produces.
+    pub name: Option<String>,
+    /// The pattern to search for, written in the syntax described by
+    /// [`TextPattern`].
+    pub pattern: String,
+    /// A template for the message shown to the user.
+    pub message: String,
+    /// Templates for the suggested replacements, if any.
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub replace_with: Vec<String>,
+    #[serde(default)]
+    pub kind: LintKind,
+}
+
+/// A [`PatternLinter`] defined entirely by a [`PatternRule`].
+///
+/// The message and replacements are templates, where `{0}`, `{1}` and so on
+/// are replaced by the text matched by the corresponding top-level element
+/// of the pattern. Literal braces are written as `{{` and `}}`.
+///
+/// For example, the rule below turns "the car which I drove" into "the car
+/// that I drove":
+///
+/// ```toml
+/// pattern = '[word:noun] [ws] "which" [ws] [word:pronoun]'
+/// message = "Consider “that” instead of “which” for restrictive clauses."
+/// replace_with = "{0} that {4}"
+/// ```
+#[derive(Debug, Clone)]
+pub struct TextPatternLinter {
+    rule: PatternRule,
+    pattern: Lrc<TextPattern>,
+}
+
+impl TextPatternLinter {
+    pub fn new(rule: PatternRule) -> Result<Self, PatternSyntaxError> {
+        let pattern = TextPattern::parse(&rule.pattern)?;
+        let element_count = pattern.element_count();
+
+        for template in std::iter::once(&rule.message).chain(&rule.replace_with) {
+            for (_, capture) in parse_template(template) {
+                if let Some(index) = capture {
+                    if index >= element_count {
+                        return Err(PatternSyntaxError::InvalidCapture(index, element_count));
+                    }
+                }
+            }
+        }
+
+        Ok(Self {
+            rule,
+            pattern: Lrc::new(pattern),
+        })
+    }
+
+    pub fn name(&self) -> Option<&str> {
+        self.rule.name.as_deref()
+    }
+}
+
+/// Two linters are equal if they were created from the same rule.
+impl PartialEq for TextPatternLinter {
+    fn eq(&self, other: &Self) -> bool {
+        self.rule == other.rule
+    }
+}
+
+impl PatternLinter for TextPatternLinter {
+    fn pattern(&self) -> &dyn Pattern {
+        self.pattern.as_ref()
+    }
+
+    fn match_to_lint(&self, matched_tokens: &[Token], source: &[char]) -> Lint {
+        let lengths = self
+            .pattern
+            .match_elements(matched_tokens, source)
+            .unwrap_or_default();
+
+        let mut captures = Vec::with_capacity(lengths.len());
+        let mut cursor = 0;
+
+        for len in lengths {
+            let tokens = &matched_tokens[cursor..cursor + len];
+            captures.push(
+                tokens
+                    .span()
+                    .map(|span| span.get_content_string(source))
+                    .unwrap_or_default(),
+            );
+            cursor += len;
+        }
+
+        Lint {
+            span: matched_tokens.span().unwrap(),
+            lint_kind: self.rule.kind,
+            suggestions: self
+                .rule
+                .replace_with
+                .iter()
+                .map(|t| Suggestion::ReplaceWith(render_template(t, &captures).chars().collect()))
+                .collect(),
+            message: render_template(&self.rule.message, &captures),
+            priority: 31,
+            rule: self.rule.name.clone(),
+        }
+    }
+}
+
+/// Split a template into literal text and references to captures.
+fn parse_template(template: &str) -> Vec<(String, Option<usize>)> {
+    let mut parts = Vec::new();
+    let mut literal = String::new();
+    let mut chars = template.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                literal.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                literal.push('}');
+            }
+            '{' => {
+                let mut digits = String::new();
+
+                while let Some(d) = chars.next_if(|d| d.is_ascii_digit()) {
+                    digits.push(d);
+                }
+
+                match (digits.parse(), chars.peek()) {
+                    (Ok(index), Some('}')) => {
+                        chars.next();
+                        parts.push((std::mem::take(&mut literal), Some(index)));
+                    }
+                    _ => {
+                        literal.push('{');
+                        literal.push_str(&digits);
+                    }
+                }
+            }
+            _ => literal.push(c),
+        }
+    }
+
+    parts.push((literal, None));
+    parts
+}
+
+fn render_template(template: &str, captures: &[String]) -> String {
+    let mut output = String::new();
+
+    for (literal, capture) in parse_template(template) {
+        output.push_str(&literal);
+
+        if let Some(text) = capture.and_then(|index| captures.get(index)) {
+            output.push_str(text);
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{render_template, PatternRule, TextPatternLinter};
+    use crate::linting::tests::{assert_lint_count, assert_suggestion_result};
+    use crate::linting::LintKind;
+    use crate::patterns::PatternSyntaxError;
+
+    fn that_which() -> TextPatternLinter {
+        TextPatternLinter::new(PatternRule {
+            name: Some("ThatWhich".to_string()),
+            pattern: r#"[word:noun] [ws] "which" [ws] [word:pronoun]"#.to_string(),
+            message: "Consider “that” after “{0}”.".to_string(),
+            replace_with: vec!["{0} that {4}".to_string()],
+            kind: LintKind::Readability,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn renders_templates() {
+        let captures = vec!["car".to_string(), "I".to_string()];
+
+        assert_eq!(render_template("{0} that {1}", &captures), "car that I");
+        assert_eq!(render_template("{{0}} {x} {", &captures), "{0} {x} {");
+    }
+
+    #[test]
+    fn applies_suggestion_template() {
+        assert_suggestion_result(
+            "This is the car which I drove.",
+            that_which(),
+            "This is the car that I drove.",
+        );
+    }
+
+    #[test]
+    fn ignores_non_matches() {
+        assert_lint_count("Which car did you drive?", that_which(), 0);
+    }
+
+    #[test]
+    fn rejects_invalid_captures() {
+        let result = TextPatternLinter::new(PatternRule {
+            name: None,
+            pattern: r#""which""#.to_string(),
+            message: "Avoid {1}.".to_string(),
+            replace_with: Vec::new(),
+            kind: LintKind::Miscellaneous,
+        });
+
+        assert_eq!(result.err(), Some(PatternSyntaxError::InvalidCapture(1, 1)));
+    }
+}
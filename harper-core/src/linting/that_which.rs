@@ -54,6 +54,7 @@ impl PatternLinter for ThatWhich {
             suggestions: vec![Suggestion::ReplaceWith(suggestion)],
             message: "“that that” sometimes means “that which”, which is clearer.".to_string(),
             priority: 126,
+            ..Default::default()
         }
     }
 }
@@ -19,6 +19,7 @@ impl Linter for UnclosedQuotes {
                     suggestions: vec![],
                     message: "This quote has no termination.".to_string(),
                     priority: 255,
+                    ..Default::default()
                 })
             }
         }
@@ -55,6 +55,7 @@ impl PatternLinter for UseGenitive {
             suggestions: vec![Suggestion::ReplaceWith(vec!['t', 'h', 'e', 'i', 'r'])],
             message: "Use the genitive case.".to_string(),
             priority: 31,
+            ..Default::default()
         }
     }
 }
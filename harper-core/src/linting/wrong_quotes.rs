@@ -1,21 +1,59 @@
+use serde::{Deserialize, Serialize};
+
 use super::{Lint, Linter, Suggestion};
 use crate::document::Document;
 use crate::{Token, TokenStringExt};
 
+/// The kind of quotation marks a document should use.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QuoteStyle {
+    /// “Curly” quotes, which differ at the start and end of a quotation.
+    #[default]
+    Curly,
+    /// "Straight" quotes, which are the same on both sides.
+    Straight,
+}
+
+/// Options for the [`WrongQuotes`] linter.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct WrongQuotesOptions {
+    /// The quotation marks that should be used.
+    pub style: QuoteStyle,
+}
+
 #[derive(Debug, Clone, Copy, Default)]
-pub struct WrongQuotes;
+pub struct WrongQuotes {
+    style: QuoteStyle,
+}
+
+impl WrongQuotes {
+    pub fn new(options: WrongQuotesOptions) -> Self {
+        Self {
+            style: options.style,
+        }
+    }
+}
 
 impl Linter for WrongQuotes {
     fn lint(&mut self, document: &Document) -> Vec<Lint> {
         document
             .iter_quote_indices()
             .zip(document.iter_quotes())
-            .filter_map(|(quote_idx, quote_token)| lint_quote(document, quote_idx, quote_token))
+            .filter_map(|(quote_idx, quote_token)| {
+                lint_quote(document, quote_idx, quote_token, self.style)
+            })
             .collect()
     }
 }
 
-fn lint_quote(document: &Document, quote_idx: usize, quote_token: Token) -> Option<Lint> {
+fn lint_quote(
+    document: &Document,
+    quote_idx: usize,
+    quote_token: Token,
+    style: QuoteStyle,
+) -> Option<Lint> {
     let quote = quote_token.kind.as_quote().unwrap();
 
     let twin_loc = quote.twin_loc?;
@@ -23,7 +61,11 @@ fn lint_quote(document: &Document, quote_idx: usize, quote_token: Token) -> Opti
 
     let quote_char = *document.get_span_content(quote_token.span).first()?;
 
-    let should_be = if is_left { '“' } else { '”' };
+    let should_be = match (style, is_left) {
+        (QuoteStyle::Curly, true) => '“',
+        (QuoteStyle::Curly, false) => '”',
+        (QuoteStyle::Straight, _) => '"',
+    };
 
     if quote_char != should_be {
         Some(Lint {
@@ -36,3 +78,28 @@ fn lint_quote(document: &Document, quote_idx: usize, quote_token: Token) -> Opti
         None
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{QuoteStyle, WrongQuotes, WrongQuotesOptions};
+    use crate::linting::tests::{assert_lint_count, assert_suggestion_result};
+
+    #[test]
+    fn prefers_curly_quotes() {
+        assert_suggestion_result(
+            "He said \"hello\" to me.",
+            WrongQuotes::default(),
+            "He said “hello” to me.",
+        );
+    }
+
+    #[test]
+    fn prefers_straight_quotes_when_configured() {
+        let linter = WrongQuotes::new(WrongQuotesOptions {
+            style: QuoteStyle::Straight,
+        });
+
+        assert_lint_count("He said “hello” to me.", linter, 2);
+        assert_lint_count("He said \"hello\" to me.", linter, 0);
+    }
+}
@@ -25,11 +25,7 @@ impl Markdown {
             let mut cursor = pipe_idx - 2;
             let mut open_bracket = None;
 
-            loop {
-                let Some((a, b)) = tokens.get(cursor).zip(tokens.get(cursor + 1)) else {
-                    break;
-                };
-
+            while let Some((a, b)) = tokens.get(cursor).zip(tokens.get(cursor + 1)) {
                 if a.kind.is_newline() {
                     break;
                 }
@@ -48,11 +44,7 @@ impl Markdown {
             cursor = pipe_idx + 1;
             let mut close_bracket = None;
 
-            loop {
-                let Some((a, b)) = tokens.get(cursor).zip(tokens.get(cursor + 1)) else {
-                    break;
-                };
-
+            while let Some((a, b)) = tokens.get(cursor).zip(tokens.get(cursor + 1)) {
                 if a.kind.is_newline() {
                     break;
                 }
@@ -85,11 +77,7 @@ impl Markdown {
 
         let mut cursor = 0;
 
-        loop {
-            let Some((a, b)) = tokens.get(cursor).zip(tokens.get(cursor + 1)) else {
-                break;
-            };
-
+        while let Some((a, b)) = tokens.get(cursor).zip(tokens.get(cursor + 1)) {
             if let Some(open_brackets_idx) = open_brackets {
                 if a.kind.is_newline() {
                     open_brackets = None;
@@ -170,8 +158,14 @@ impl Parser for Markdown {
                 | pulldown_cmark::Event::End(pulldown_cmark::TagEnd::Item)
                 | pulldown_cmark::Event::End(pulldown_cmark::TagEnd::Heading(_))
                 | pulldown_cmark::Event::End(pulldown_cmark::TagEnd::TableCell) => {
+                    // The range covers the whole element. Putting the break at its end,
+                    // rather than where its last run of text starts, keeps the tokens
+                    // in the order they appear in the source.
+                    let end_chars =
+                        traversed_chars + source_str[traversed_bytes..range.end].chars().count();
+
                     tokens.push(Token {
-                        span: Span::new_with_len(traversed_chars, 0),
+                        span: Span::new_with_len(end_chars, 0),
                         kind: TokenKind::Newline(2),
                     });
                     stack.pop();
@@ -260,7 +254,7 @@ impl Parser for Markdown {
 mod tests {
     use super::super::StrParser;
     use super::Markdown;
-    use crate::{Punctuation, TokenKind, TokenStringExt};
+    use crate::{Punctuation, Span, TokenKind, TokenStringExt};
 
     #[test]
     fn survives_emojis() {
@@ -282,6 +276,28 @@ mod tests {
         assert!(!tokens.last().unwrap().kind.is_newline());
     }
 
+    #[test]
+    fn paragraph_breaks_follow_their_paragraph() {
+        let source = "A **bold** claim.\n\n- An item\n\nThe end.";
+
+        let tokens = Markdown.parse_str(source);
+        let breaks: Vec<_> = tokens
+            .iter()
+            .filter(|t| t.kind == TokenKind::Newline(2))
+            .map(|t| t.span)
+            .collect();
+
+        assert_eq!(
+            breaks,
+            vec![
+                Span::new_with_len(18, 0),
+                Span::new_with_len(19, 0),
+                Span::new_with_len(30, 0)
+            ]
+        );
+        assert!(tokens.windows(2).all(|w| w[0].span.end <= w[1].span.start));
+    }
+
     #[test]
     fn math_becomes_unlintable() {
         let source = r#"$\Katex$ $\text{is}$ $\text{great}$."#;
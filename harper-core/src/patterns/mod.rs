@@ -8,6 +8,7 @@ mod either_pattern;
 mod naive_pattern_group;
 mod repeating_pattern;
 mod sequence_pattern;
+mod text_pattern;
 mod token_kind_pattern_group;
 mod whitespace_pattern;
 mod word_pattern_group;
@@ -19,6 +20,7 @@ pub use either_pattern::EitherPattern;
 pub use naive_pattern_group::NaivePatternGroup;
 pub use repeating_pattern::RepeatingPattern;
 pub use sequence_pattern::SequencePattern;
+pub use text_pattern::{PatternSyntaxError, TextPattern};
 pub use token_kind_pattern_group::TokenKindPatternGroup;
 pub use whitespace_pattern::WhitespacePattern;
 pub use word_pattern_group::WordPatternGroup;
@@ -44,25 +44,12 @@ impl SequencePattern {
     gen_then_from_is!(adverb);
     gen_then_from_is!(adjective);
 
-    pub fn then_exact_word(mut self, word: &'static str) -> Self {
-        self.token_patterns
-            .push(Box::new(|tok: &Token, source: &[char]| {
-                if !tok.kind.is_word() {
-                    return false;
-                }
-
-                let tok_chars = tok.span.get_content(source);
-
-                let mut w_char_count = 0;
-                for (i, w_char) in word.chars().enumerate() {
-                    w_char_count += 1;
+    pub fn then_exact_word(mut self, word: &str) -> Self {
+        let word: Vec<char> = word.chars().collect();
 
-                    if tok_chars.get(i).cloned() != Some(w_char) {
-                        return false;
-                    }
-                }
-
-                w_char_count == tok_chars.len()
+        self.token_patterns
+            .push(Box::new(move |tok: &Token, source: &[char]| {
+                tok.kind.is_word() && tok.span.get_content(source) == word.as_slice()
             }));
         self
     }
@@ -0,0 +1,441 @@
+use super::{EitherPattern, Pattern, RepeatingPattern, SequencePattern, WhitespacePattern};
+use crate::parsers::{Parser, PlainEnglish};
+use crate::{CharStringExt, Token, TokenKind};
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum PatternSyntaxError {
+    #[error("The pattern is empty.")]
+    Empty,
+    #[error("Unexpected end of pattern.")]
+    UnexpectedEnd,
+    #[error("Unexpected character “{0}” at position {1}.")]
+    UnexpectedChar(char, usize),
+    #[error("Unknown token kind “{0}”.")]
+    UnknownKind(String),
+    #[error("Unknown predicate “{0}”.")]
+    UnknownPredicate(String),
+    #[error("Element {0} is referenced, but the pattern only has {1} elements.")]
+    InvalidCapture(usize, usize),
+}
+
+/// A [`Pattern`] compiled from a small textual syntax, so rules can be
+/// written without touching Rust.
+///
+/// ```text
+/// [word:noun] [ws] "which" [ws] [word]
+/// ```
+///
+/// A pattern is a sequence of elements, separated by any amount of space:
+///
+/// - `"text"` matches the text, ignoring capitalization. It may span several
+///   words (e.g. `"as well as"`).
+/// - `[word]`, `[ws]`, `[punct]`, `[number]`, `[comma]`, `[period]` and
+///   `[any]` match a single token of that kind (`[ws]` matches a run of
+///   whitespace).
+/// - `[word:noun,!pronoun]` additionally checks the word's metadata.
+///   The available predicates are `noun`, `verb`, `linking_verb`,
+///   `adjective`, `adverb`, `pronoun`, `conjunction`, `swear` and
+///   `likely_homograph`. Each may be prefixed with `is_` or negated with `!`.
+/// - `(a | b c)` matches the first alternative that does.
+/// - A trailing `+` matches one or more repetitions of an element.
+pub struct TextPattern {
+    source: String,
+    elements: Vec<Box<dyn Pattern>>,
+}
+
+impl std::fmt::Debug for TextPattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("TextPattern").field(&self.source).finish()
+    }
+}
+
+impl TextPattern {
+    pub fn parse(source: &str) -> Result<Self, PatternSyntaxError> {
+        let chars: Vec<char> = source.chars().collect();
+        let mut parser = PatternParser {
+            source: &chars,
+            cursor: 0,
+        };
+
+        let elements = parser.parse_sequence()?;
+
+        parser.skip_whitespace();
+        if let Some(c) = parser.peek() {
+            return Err(PatternSyntaxError::UnexpectedChar(c, parser.cursor));
+        }
+
+        if elements.is_empty() {
+            return Err(PatternSyntaxError::Empty);
+        }
+
+        Ok(Self {
+            source: source.to_string(),
+            elements,
+        })
+    }
+
+    /// The text the pattern was compiled from.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// The number of top-level elements in the pattern.
+    pub fn element_count(&self) -> usize {
+        self.elements.len()
+    }
+
+    /// Match the pattern against the start of `tokens`, returning the number
+    /// of tokens consumed by each top-level element.
+    pub fn match_elements(&self, tokens: &[Token], source: &[char]) -> Option<Vec<usize>> {
+        let mut lengths = Vec::with_capacity(self.elements.len());
+        let mut tok_cursor = 0;
+
+        for element in &self.elements {
+            let len = element.matches(&tokens[tok_cursor..], source);
+
+            if len == 0 {
+                return None;
+            }
+
+            lengths.push(len);
+            tok_cursor += len;
+        }
+
+        Some(lengths)
+    }
+}
+
+impl Pattern for TextPattern {
+    fn matches(&self, tokens: &[Token], source: &[char]) -> usize {
+        self.match_elements(tokens, source)
+            .map(|lengths| lengths.iter().sum())
+            .unwrap_or(0)
+    }
+}
+
+struct PatternParser<'a> {
+    source: &'a [char],
+    cursor: usize,
+}
+
+impl PatternParser<'_> {
+    fn peek(&self) -> Option<char> {
+        self.source.get(self.cursor).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(|c| c.is_whitespace()) {
+            self.cursor += 1;
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), PatternSyntaxError> {
+        self.skip_whitespace();
+
+        match self.peek() {
+            Some(c) if c == expected => {
+                self.cursor += 1;
+                Ok(())
+            }
+            Some(c) => Err(PatternSyntaxError::UnexpectedChar(c, self.cursor)),
+            None => Err(PatternSyntaxError::UnexpectedEnd),
+        }
+    }
+
+    /// Parse elements until the end of input, a `|` or a `)`.
+    fn parse_sequence(&mut self) -> Result<Vec<Box<dyn Pattern>>, PatternSyntaxError> {
+        let mut elements = Vec::new();
+
+        loop {
+            self.skip_whitespace();
+
+            match self.peek() {
+                None | Some('|') | Some(')') => return Ok(elements),
+                _ => elements.push(self.parse_element()?),
+            }
+        }
+    }
+
+    fn parse_element(&mut self) -> Result<Box<dyn Pattern>, PatternSyntaxError> {
+        let atom = match self.peek() {
+            Some('"') => self.parse_literal()?,
+            Some('[') => self.parse_selector()?,
+            Some('(') => self.parse_group()?,
+            Some(c) => return Err(PatternSyntaxError::UnexpectedChar(c, self.cursor)),
+            None => return Err(PatternSyntaxError::UnexpectedEnd),
+        };
+
+        if self.peek() == Some('+') {
+            self.cursor += 1;
+            return Ok(Box::new(RepeatingPattern::new(atom)));
+        }
+
+        Ok(atom)
+    }
+
+    fn parse_literal(&mut self) -> Result<Box<dyn Pattern>, PatternSyntaxError> {
+        self.expect('"')?;
+
+        let start = self.cursor;
+        while self.peek().is_some_and(|c| c != '"') {
+            self.cursor += 1;
+        }
+        let text = &self.source[start..self.cursor];
+
+        self.expect('"')?;
+
+        let tokens = PlainEnglish.parse(text);
+
+        if tokens.is_empty() {
+            return Err(PatternSyntaxError::Empty);
+        }
+
+        let mut pattern = SequencePattern::default();
+
+        for token in tokens {
+            let content = token.span.get_content(text).to_lower();
+
+            pattern = if token.kind.is_whitespace() {
+                pattern.then(Box::new(WhitespacePattern))
+            } else {
+                pattern.then(Box::new(move |tok: &Token, source: &[char]| {
+                    tok.span.get_content(source).to_lower() == content
+                }))
+            };
+        }
+
+        Ok(Box::new(pattern))
+    }
+
+    fn parse_selector(&mut self) -> Result<Box<dyn Pattern>, PatternSyntaxError> {
+        self.expect('[')?;
+
+        let kind = self.parse_identifier()?;
+        let mut predicates = Vec::new();
+
+        self.skip_whitespace();
+        if self.peek() == Some(':') {
+            self.cursor += 1;
+
+            loop {
+                self.skip_whitespace();
+
+                let negated = self.peek() == Some('!');
+                if negated {
+                    self.cursor += 1;
+                }
+
+                let name = self.parse_identifier()?;
+                let predicate = word_predicate(&name)
+                    .ok_or_else(|| PatternSyntaxError::UnknownPredicate(name.clone()))?;
+                predicates.push((predicate, negated));
+
+                self.skip_whitespace();
+                if self.peek() == Some(',') {
+                    self.cursor += 1;
+                } else {
+                    break;
+                }
+            }
+        }
+
+        self.expect(']')?;
+
+        if kind == "ws" {
+            if !predicates.is_empty() {
+                return Err(PatternSyntaxError::UnknownPredicate(kind));
+            }
+
+            return Ok(Box::new(WhitespacePattern));
+        }
+
+        let kind_check = token_kind_check(&kind).ok_or(PatternSyntaxError::UnknownKind(kind))?;
+
+        Ok(Box::new(move |tok: &Token, _source: &[char]| {
+            kind_check(&tok.kind)
+                && predicates
+                    .iter()
+                    .all(|(predicate, negated)| predicate(&tok.kind) != *negated)
+        }))
+    }
+
+    fn parse_group(&mut self) -> Result<Box<dyn Pattern>, PatternSyntaxError> {
+        self.expect('(')?;
+
+        let mut alternatives: Vec<Box<dyn Pattern>> = Vec::new();
+
+        loop {
+            let elements = self.parse_sequence()?;
+
+            if elements.is_empty() {
+                return Err(PatternSyntaxError::Empty);
+            }
+
+            let sequence = elements
+                .into_iter()
+                .fold(SequencePattern::default(), |seq, el| seq.then(el));
+            alternatives.push(Box::new(sequence));
+
+            match self.peek() {
+                Some('|') => self.cursor += 1,
+                _ => break,
+            }
+        }
+
+        self.expect(')')?;
+
+        Ok(Box::new(EitherPattern::new(alternatives)))
+    }
+
+    fn parse_identifier(&mut self) -> Result<String, PatternSyntaxError> {
+        self.skip_whitespace();
+
+        let start = self.cursor;
+        while self
+            .peek()
+            .is_some_and(|c| c.is_ascii_alphanumeric() || c == '_')
+        {
+            self.cursor += 1;
+        }
+
+        if start == self.cursor {
+            return match self.peek() {
+                Some(c) => Err(PatternSyntaxError::UnexpectedChar(c, self.cursor)),
+                None => Err(PatternSyntaxError::UnexpectedEnd),
+            };
+        }
+
+        Ok(self.source[start..self.cursor].iter().collect())
+    }
+}
+
+type KindCheck = fn(&TokenKind) -> bool;
+
+fn token_kind_check(name: &str) -> Option<KindCheck> {
+    let check: KindCheck = match name {
+        "word" => TokenKind::is_word,
+        "punct" => TokenKind::is_punctuation,
+        "number" => TokenKind::is_number,
+        "comma" => TokenKind::is_comma,
+        "period" => TokenKind::is_period,
+        "any" => |_| true,
+        _ => return None,
+    };
+
+    Some(check)
+}
+
+fn word_predicate(name: &str) -> Option<KindCheck> {
+    let check: KindCheck = match name.strip_prefix("is_").unwrap_or(name) {
+        "noun" => TokenKind::is_noun,
+        "verb" => TokenKind::is_verb,
+        "linking_verb" => TokenKind::is_linking_verb,
+        "adjective" => TokenKind::is_adjective,
+        "adverb" => TokenKind::is_adverb,
+        "pronoun" => TokenKind::is_pronoun,
+        "conjunction" => TokenKind::is_conjunction,
+        "swear" => TokenKind::is_swear,
+        "likely_homograph" => TokenKind::is_likely_homograph,
+        _ => return None,
+    };
+
+    Some(check)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PatternSyntaxError, TextPattern};
+    use crate::patterns::{Pattern, PatternExt};
+    use crate::Document;
+
+    fn match_count(pattern: &str, text: &str) -> usize {
+        let pattern = TextPattern::parse(pattern).unwrap();
+        let document = Document::new_plain_english_curated(text);
+
+        pattern
+            .find_all_matches(document.get_tokens(), document.get_source())
+            .len()
+    }
+
+    #[test]
+    fn matches_literals_ignoring_case() {
+        assert_eq!(match_count(r#""which""#, "Which one? That which is."), 2);
+    }
+
+    #[test]
+    fn matches_multi_word_literals() {
+        assert_eq!(
+            match_count(r#""as well as""#, "Apples as well as pears."),
+            1
+        );
+    }
+
+    #[test]
+    fn matches_word_metadata() {
+        assert_eq!(
+            match_count(r#"[word:noun] [ws] "which""#, "The car which I drove."),
+            1
+        );
+        assert_eq!(
+            match_count(r#"[word:!noun] [ws] "which""#, "The car which I drove."),
+            0
+        );
+    }
+
+    #[test]
+    fn matches_alternatives() {
+        assert_eq!(
+            match_count(
+                r#"("very" | "really") [ws] "unique""#,
+                "It is really unique."
+            ),
+            1
+        );
+    }
+
+    #[test]
+    fn matches_repetitions() {
+        let pattern = TextPattern::parse(r#""very" ([ws] "very")+"#).unwrap();
+        let document = Document::new_plain_english_curated("very very very good");
+
+        assert_eq!(
+            pattern.matches(document.get_tokens(), document.get_source()),
+            5
+        );
+    }
+
+    #[test]
+    fn reports_element_lengths() {
+        let pattern = TextPattern::parse(r#"[word] [ws] "as well as""#).unwrap();
+        let document = Document::new_plain_english_curated("pears as well as apples");
+
+        assert_eq!(
+            pattern.match_elements(document.get_tokens(), document.get_source()),
+            Some(vec![1, 1, 5])
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_syntax() {
+        assert_eq!(
+            TextPattern::parse("").err(),
+            Some(PatternSyntaxError::Empty)
+        );
+        assert_eq!(
+            TextPattern::parse("[word").err(),
+            Some(PatternSyntaxError::UnexpectedEnd)
+        );
+        assert_eq!(
+            TextPattern::parse("[thing]").err(),
+            Some(PatternSyntaxError::UnknownKind("thing".to_string()))
+        );
+        assert_eq!(
+            TextPattern::parse("[word:fancy]").err(),
+            Some(PatternSyntaxError::UnknownPredicate("fancy".to_string()))
+        );
+        assert_eq!(
+            TextPattern::parse("word").err(),
+            Some(PatternSyntaxError::UnexpectedChar('w', 0))
+        );
+    }
+}
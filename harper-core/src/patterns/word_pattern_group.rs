@@ -28,7 +28,7 @@ impl WordPatternGroup<NaivePatternGroup> {
     }
 
     /// Add a pattern that matches just a word on its own, without anything else required to match.
-    pub fn add_word(&mut self, word: &'static str) {
+    pub fn add_word(&mut self, word: &str) {
         self.add(
             word,
             Box::new(SequencePattern::default().then_exact_word(word)),
@@ -0,0 +1,319 @@
+//! A small rule-based part-of-speech tagger.
+//!
+//! The dictionary lists every category a word can belong to, so “record” is
+//! both a noun and a verb. The tagger looks at the words on either side to
+//! pick the one it most likely is in its sentence, and stores it in
+//! [`WordMetadata::pos_tag`].
+
+use crate::{CharString, CharStringExt, PartOfSpeech, Token, TokenKind, WordMetadata};
+
+/// Words that always start a noun phrase.
+const ARTICLES: [&str; 3] = ["a", "an", "the"];
+
+/// Words that start a noun phrase when one follows, but can also stand on
+/// their own (“that is mine”, “I saw her”).
+const DETERMINERS: [&str; 17] = [
+    "this", "that", "these", "those", "my", "your", "his", "her", "its", "our", "their", "every",
+    "each", "some", "any", "no", "another",
+];
+
+/// Pronouns that can only be the subject of a sentence, so are usually
+/// followed by a verb.
+const SUBJECT_PRONOUNS: [&str; 5] = ["i", "we", "they", "he", "she"];
+
+/// Helper verbs, which are always verbs and are usually followed by one.
+const MODALS: [&str; 9] = [
+    "will", "would", "can", "could", "should", "shall", "may", "might", "must",
+];
+
+/// Other words after which a homograph is most likely a verb.
+const VERB_INTRODUCERS: [&str; 5] = ["to", "do", "does", "did", "you"];
+
+/// Pronouns that can be the object of a verb.
+const OBJECT_PRONOUNS: [&str; 6] = ["me", "him", "us", "them", "it", "you"];
+
+/// Words the dictionary doesn't list as adverbs, but always are.
+const UNLISTED_ADVERBS: [&str; 7] = [
+    "not", "never", "also", "always", "already", "often", "usually",
+];
+
+/// The tag chosen when nothing around a word helps, in order of preference.
+const FALLBACK_ORDER: [PartOfSpeech; 5] = [
+    PartOfSpeech::Noun,
+    PartOfSpeech::Verb,
+    PartOfSpeech::Adjective,
+    PartOfSpeech::Adverb,
+    PartOfSpeech::Conjunction,
+];
+
+/// Checks whether a lowercase word can be a determiner, like “the” or “my”.
+pub(crate) fn is_determiner(word: &[char]) -> bool {
+    is_one_of(word, &ARTICLES) || is_one_of(word, &DETERMINERS)
+}
+
+/// Checks whether a lowercase word is a pronoun that can only be a subject.
+pub(crate) fn is_subject_pronoun(word: &[char]) -> bool {
+    is_one_of(word, &SUBJECT_PRONOUNS)
+}
+
+fn is_one_of(word: &[char], list: &[&str]) -> bool {
+    list.iter().any(|w| w.chars().eq(word.iter().copied()))
+}
+
+/// A word next to the one being tagged.
+struct Neighbor {
+    /// The word, in lowercase.
+    text: CharString,
+    metadata: WordMetadata,
+}
+
+impl Neighbor {
+    fn new(token: &Token, source: &[char]) -> Option<Self> {
+        let TokenKind::Word(metadata) = token.kind else {
+            return None;
+        };
+
+        Some(Self {
+            text: token.span.get_content(source).to_lower(),
+            metadata,
+        })
+    }
+
+    /// Whether this word could continue a noun phrase started by a
+    /// determiner.
+    fn could_follow_determiner(&self) -> bool {
+        let metadata = &self.metadata;
+
+        (metadata.noun.is_some() || metadata.adjective.is_some())
+            && !metadata.is_pronoun_noun()
+            && !is_determiner(&self.text)
+    }
+}
+
+/// Tag every word in `tokens` with its most likely part of speech.
+///
+/// Words are only considered neighbors when nothing but whitespace separates
+/// them, so punctuation and paragraph breaks reset the context.
+pub(crate) fn tag_parts_of_speech(tokens: &mut [Token], source: &[char]) {
+    let mut previous: Option<Neighbor> = None;
+
+    for index in 0..tokens.len() {
+        if tokens[index].kind.is_whitespace() {
+            continue;
+        }
+
+        let Some(mut current) = Neighbor::new(&tokens[index], source) else {
+            previous = None;
+            continue;
+        };
+
+        let next = tokens[index + 1..]
+            .iter()
+            .find(|t| !t.kind.is_whitespace())
+            .and_then(|t| Neighbor::new(t, source));
+
+        current.metadata.pos_tag = choose_tag(&current, previous.as_ref(), next.as_ref());
+        tokens[index].kind = TokenKind::Word(current.metadata);
+
+        // Adverbs rarely decide what follows them, so look through them:
+        // in “is not guilty”, “guilty” follows “is”.
+        if current.metadata.pos_tag != Some(PartOfSpeech::Adverb) {
+            previous = Some(current);
+        }
+    }
+}
+
+fn choose_tag(
+    word: &Neighbor,
+    previous: Option<&Neighbor>,
+    next: Option<&Neighbor>,
+) -> Option<PartOfSpeech> {
+    use PartOfSpeech::*;
+
+    if is_one_of(&word.text, &ARTICLES)
+        || (is_one_of(&word.text, &DETERMINERS)
+            && next.is_some_and(Neighbor::could_follow_determiner))
+    {
+        return Some(Determiner);
+    }
+
+    if is_one_of(&word.text, &UNLISTED_ADVERBS) {
+        return Some(Adverb);
+    }
+
+    let metadata = &word.metadata;
+    let candidates: Vec<PartOfSpeech> = FALLBACK_ORDER
+        .into_iter()
+        .filter(|tag| match tag {
+            Noun => metadata.noun.is_some(),
+            Verb => metadata.verb.is_some(),
+            Adjective => metadata.adjective.is_some(),
+            Adverb => metadata.adverb.is_some(),
+            Conjunction => metadata.conjunction.is_some(),
+            Determiner => false,
+        })
+        .collect();
+
+    // Most words ending in “-ly” that the dictionary knows nothing about,
+    // like “carefully”, are adverbs.
+    if candidates.is_empty() && word.text.ends_with(&['l', 'y']) {
+        return Some(Adverb);
+    }
+
+    if candidates.len() <= 1 {
+        return candidates.first().copied();
+    }
+
+    let prefer = |order: &[PartOfSpeech]| order.iter().copied().find(|t| candidates.contains(t));
+
+    // “the dark night”, “the record”
+    if let Some(previous) = previous {
+        if matches!(previous.metadata.pos_tag, Some(Determiner | Adjective)) {
+            let found = if next.is_some_and(Neighbor::could_follow_determiner) {
+                prefer(&[Adjective, Noun])
+            } else {
+                prefer(&[Noun, Adjective])
+            };
+
+            if found.is_some() {
+                return found;
+            }
+        }
+    }
+
+    // “is”, “should”
+    if metadata.is_linking_verb() || is_one_of(&word.text, &MODALS) {
+        if let Some(found) = prefer(&[Verb]) {
+            return Some(found);
+        }
+    }
+
+    if let Some(previous) = previous {
+        let found = if previous.metadata.is_linking_verb() {
+            // “is light”, “was working”
+            prefer(&[Adjective, Verb, Noun])
+        } else if is_subject_pronoun(&previous.text)
+            || is_one_of(&previous.text, &MODALS)
+            || is_one_of(&previous.text, &VERB_INTRODUCERS)
+        {
+            // “I lead”, “to record”
+            prefer(&[Verb])
+        } else if previous.metadata.pos_tag == Some(Verb) {
+            // “they play records”
+            prefer(&[Noun, Adjective, Adverb])
+        } else {
+            None
+        };
+
+        if found.is_some() {
+            return found;
+        }
+    }
+
+    if let Some(next) = next {
+        // “record the song”, “lead them”
+        if is_determiner(&next.text) || is_one_of(&next.text, &OBJECT_PRONOUNS) {
+            if let Some(found) = prefer(&[Verb]) {
+                return Some(found);
+            }
+        }
+    }
+
+    if word.text.ends_with(&['l', 'y']) {
+        if let Some(found) = prefer(&[Adverb]) {
+            return Some(found);
+        }
+    }
+
+    prefer(&FALLBACK_ORDER)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Document, PartOfSpeech, TokenStringExt};
+
+    /// The tag of each word in `text`.
+    fn tags(text: &str) -> Vec<(String, Option<PartOfSpeech>)> {
+        let document = Document::new_plain_english_curated(text);
+
+        document
+            .iter_words()
+            .map(|word| {
+                (
+                    document.get_span_content_str(word.span),
+                    word.kind.as_word().unwrap().pos_tag,
+                )
+            })
+            .collect()
+    }
+
+    fn tag_of(text: &str, word: &str) -> Option<PartOfSpeech> {
+        tags(text)
+            .into_iter()
+            .find(|(w, _)| w == word)
+            .and_then(|(_, tag)| tag)
+    }
+
+    #[test]
+    fn tags_homographs_after_determiners_as_nouns() {
+        assert_eq!(
+            tag_of("The record was long.", "record"),
+            Some(PartOfSpeech::Noun)
+        );
+        assert_eq!(
+            tag_of("Her lead was small.", "lead"),
+            Some(PartOfSpeech::Noun)
+        );
+    }
+
+    #[test]
+    fn tags_homographs_after_subjects_as_verbs() {
+        assert_eq!(
+            tag_of("They record music.", "record"),
+            Some(PartOfSpeech::Verb)
+        );
+        assert_eq!(tag_of("I want to lead.", "lead"), Some(PartOfSpeech::Verb));
+        assert_eq!(tag_of("Lead the team.", "Lead"), Some(PartOfSpeech::Verb));
+    }
+
+    #[test]
+    fn tags_determiners() {
+        assert_eq!(
+            tag_of("Give me my book.", "my"),
+            Some(PartOfSpeech::Determiner)
+        );
+        assert_ne!(tag_of("I saw her.", "her"), Some(PartOfSpeech::Determiner));
+        assert_ne!(
+            tag_of("That is mine.", "That"),
+            Some(PartOfSpeech::Determiner)
+        );
+    }
+
+    #[test]
+    fn tags_unlisted_adverbs() {
+        assert_eq!(tag_of("It also works.", "also"), Some(PartOfSpeech::Adverb));
+        assert_eq!(
+            tag_of("It was carefully reviewed.", "carefully"),
+            Some(PartOfSpeech::Adverb)
+        );
+    }
+
+    #[test]
+    fn resets_at_punctuation() {
+        assert_eq!(
+            tag_of("I stopped. Record it.", "Record"),
+            Some(PartOfSpeech::Verb)
+        );
+    }
+
+    #[test]
+    fn tagged_queries_are_precise() {
+        let document = Document::new_plain_english_curated("They record music.");
+        let record = document.iter_words().nth(1).unwrap();
+        let metadata = record.kind.as_word().unwrap();
+
+        assert!(metadata.is_verb());
+        assert!(!metadata.is_noun());
+        assert!(metadata.is_likely_homograph());
+    }
+}
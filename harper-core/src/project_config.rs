@@ -0,0 +1,364 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use serde::{Deserialize, Serialize};
+
+use crate::linting::{Lint, LintGroupConfig};
+use crate::severity::{Severity, SeverityOverrides};
+
+/// The names of the files [`ProjectConfig::discover`] searches for, in order
+/// of preference.
+pub const PROJECT_CONFIG_FILENAMES: [&str; 2] = ["harper.toml", ".harper.json"];
+
+#[derive(Debug, thiserror::Error)]
+pub enum ProjectConfigError {
+    #[error("Unable to read project configuration: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Invalid TOML in project configuration: {0}")]
+    Toml(#[from] toml::de::Error),
+    #[error("Invalid JSON in project configuration: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("Invalid ignore pattern in project configuration: {0}")]
+    Glob(#[from] globset::Error),
+}
+
+/// Settings shared by everyone working on a project, read from a
+/// `harper.toml` or `.harper.json` file.
+///
+/// Since it reads from the filesystem, it is only available with the
+/// `project-config` feature.
+///
+/// ```toml
+/// dictionary = "dictionary.txt"
+/// hunspell_dictionaries = ["dictionaries/medical.dic"]
+/// compiled_dictionaries = ["dictionaries/product.fst"]
+/// replacement_rules = "harper-rules.toml"
+/// ignore = ["vendor/**", "CHANGELOG.md"]
+///
+/// [linters]
+/// long_sentences = false
+///
+/// [linters.options.boring_words]
+/// words = ["very", "really"]
+///
+/// [language_linters.markdown]
+/// passive_voice = true
+///
+/// [severity]
+/// SpellCheck = "warning"
+/// Readability = "hint"
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProjectConfig {
+    /// Linters to enable or disable, using the same keys as
+    /// [`LintGroupConfig`].
+    #[serde(default)]
+    pub linters: LintGroupConfig,
+    /// Linters to enable or disable for files in a particular language, keyed
+    /// by language ID (like `markdown` or `rust`). These take precedence over
+    /// [`Self::linters`].
+    #[serde(default)]
+    pub language_linters: BTreeMap<String, LintGroupConfig>,
+    /// A line-separated word list containing project-specific words, in the
+    /// format read by [`FullDictionary::from_word_list`](crate::FullDictionary::from_word_list).
+    /// Relative paths are resolved against the directory containing the
+    /// configuration file.
+    pub dictionary: Option<PathBuf>,
+    /// Hunspell dictionaries, given as the path to each `.dic` file.
+    /// The `.aff` file of the same name is read alongside it.
+    /// Relative paths are resolved against the directory containing the
+    /// configuration file.
+    #[serde(default)]
+    pub hunspell_dictionaries: Vec<PathBuf>,
+    /// Dictionaries compiled with `harper-cli compile-dict`, given as the
+    /// path to each `.fst` file.
+    /// The `.meta` file of the same name is read alongside it.
+    /// Relative paths are resolved against the directory containing the
+    /// configuration file.
+    #[serde(default)]
+    pub compiled_dictionaries: Vec<PathBuf>,
+    /// A file of [`ReplacementRules`](crate::linting::ReplacementRules).
+    /// Relative paths are resolved against the directory containing the
+    /// configuration file.
+    pub replacement_rules: Option<PathBuf>,
+    /// Overrides the severity of lints, keyed by [`Lint::rule`] or
+    /// [`LintKind`](crate::linting::LintKind).
+    #[serde(default)]
+    pub severity: SeverityOverrides,
+    /// Glob patterns, relative to the directory containing the configuration
+    /// file, of files that should not be linted.
+    #[serde(default)]
+    pub ignore: Vec<String>,
+    /// The directory containing the configuration file.
+    #[serde(skip)]
+    root: PathBuf,
+    #[serde(skip)]
+    ignore_set: GlobSet,
+}
+
+impl ProjectConfig {
+    /// Search for a configuration file in the directory of the provided path
+    /// and each of its ancestors, loading the first one found.
+    pub fn discover(path: impl AsRef<Path>) -> Result<Option<Self>, ProjectConfigError> {
+        let path = std::path::absolute(path)?;
+
+        let start = if path.is_dir() {
+            path.as_path()
+        } else {
+            match path.parent() {
+                Some(parent) => parent,
+                None => return Ok(None),
+            }
+        };
+
+        for dir in start.ancestors() {
+            for filename in PROJECT_CONFIG_FILENAMES {
+                let candidate = dir.join(filename);
+
+                if candidate.is_file() {
+                    return Self::load(&candidate).map(Some);
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Load a configuration file, determining its format from the extension.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ProjectConfigError> {
+        let path = std::path::absolute(path)?;
+        let source = std::fs::read_to_string(&path)?;
+        let root = path.parent().unwrap_or(Path::new("")).to_path_buf();
+
+        if path.extension().is_some_and(|e| e == "json") {
+            Self::from_json_str(&source, root)
+        } else {
+            Self::from_toml_str(&source, root)
+        }
+    }
+
+    pub fn from_toml_str(
+        source: &str,
+        root: impl Into<PathBuf>,
+    ) -> Result<Self, ProjectConfigError> {
+        let config: Self = toml::from_str(source)?;
+        config.with_root(root.into())
+    }
+
+    pub fn from_json_str(
+        source: &str,
+        root: impl Into<PathBuf>,
+    ) -> Result<Self, ProjectConfigError> {
+        let config: Self = serde_json::from_str(source)?;
+        config.with_root(root.into())
+    }
+
+    fn with_root(mut self, root: PathBuf) -> Result<Self, ProjectConfigError> {
+        let mut builder = GlobSetBuilder::new();
+
+        for pattern in &self.ignore {
+            builder.add(Glob::new(pattern)?);
+        }
+
+        self.ignore_set = builder.build()?;
+        self.root = root;
+
+        Ok(self)
+    }
+
+    /// The directory containing the configuration file.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// The linters configured for files with the given language ID: the
+    /// project's [`Self::linters`], with those for the language on top.
+    pub fn linters_for(&self, language_id: Option<&str>) -> LintGroupConfig {
+        let mut linters = self.linters.clone();
+
+        if let Some(language_linters) = language_id.and_then(|id| self.language_linters.get(id)) {
+            linters.override_with(language_linters);
+        }
+
+        linters
+    }
+
+    /// The resolved location of the project dictionary, if one was configured.
+    pub fn dictionary_path(&self) -> Option<PathBuf> {
+        self.dictionary.as_ref().map(|p| self.root.join(p))
+    }
+
+    /// The resolved locations of the configured Hunspell dictionaries, as
+    /// pairs of `.aff` and `.dic` files.
+    pub fn hunspell_dictionary_paths(&self) -> Vec<(PathBuf, PathBuf)> {
+        self.hunspell_dictionaries
+            .iter()
+            .map(|dic| {
+                let dic = self.root.join(dic);
+                (dic.with_extension("aff"), dic)
+            })
+            .collect()
+    }
+
+    /// The resolved locations of the configured compiled dictionaries, as
+    /// pairs of `.fst` and `.meta` files.
+    pub fn compiled_dictionary_paths(&self) -> Vec<(PathBuf, PathBuf)> {
+        self.compiled_dictionaries
+            .iter()
+            .map(|fst| {
+                let fst = self.root.join(fst);
+                (fst.clone(), fst.with_extension("meta"))
+            })
+            .collect()
+    }
+
+    /// The resolved location of the replacement rules file, if one was
+    /// configured.
+    pub fn replacement_rules_path(&self) -> Option<PathBuf> {
+        self.replacement_rules.as_ref().map(|p| self.root.join(p))
+    }
+
+    /// Check whether a file matches any of the configured ignore patterns.
+    pub fn is_ignored(&self, path: impl AsRef<Path>) -> bool {
+        let path = std::path::absolute(path.as_ref()).unwrap_or(path.as_ref().to_path_buf());
+        let relative = path.strip_prefix(&self.root).unwrap_or(&path);
+
+        self.ignore_set.is_match(relative)
+    }
+
+    /// Get the configured severity for a lint, if it has been overridden.
+    pub fn severity_for(&self, lint: &Lint) -> Option<Severity> {
+        self.severity.get(lint)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::{ProjectConfig, Severity};
+    use crate::linting::Lint;
+
+    const EXAMPLE: &str = r#"
+dictionary = "dictionary.txt"
+hunspell_dictionaries = ["dictionaries/medical.dic"]
+compiled_dictionaries = ["dictionaries/product.fst"]
+replacement_rules = "rules/harper.toml"
+ignore = ["vendor/**", "CHANGELOG.md"]
+
+[linters]
+long_sentences = false
+
+[severity]
+SpellCheck = "warning"
+"#;
+
+    #[test]
+    fn parses_toml() {
+        let config = ProjectConfig::from_toml_str(EXAMPLE, "/project").unwrap();
+
+        assert_eq!(config.linters.long_sentences, Some(false));
+        assert_eq!(config.linters.spell_check, None);
+        assert_eq!(
+            config.dictionary_path(),
+            Some(Path::new("/project/dictionary.txt").to_path_buf())
+        );
+        assert_eq!(
+            config.replacement_rules_path(),
+            Some(Path::new("/project/rules/harper.toml").to_path_buf())
+        );
+        assert_eq!(
+            config.hunspell_dictionary_paths(),
+            vec![(
+                Path::new("/project/dictionaries/medical.aff").to_path_buf(),
+                Path::new("/project/dictionaries/medical.dic").to_path_buf()
+            )]
+        );
+        assert_eq!(
+            config.compiled_dictionary_paths(),
+            vec![(
+                Path::new("/project/dictionaries/product.fst").to_path_buf(),
+                Path::new("/project/dictionaries/product.meta").to_path_buf()
+            )]
+        );
+    }
+
+    #[test]
+    fn parses_linter_options() {
+        let config = ProjectConfig::from_toml_str(
+            "[linters.options.long_sentences]\nmax_words = 30",
+            "/project",
+        )
+        .unwrap();
+
+        assert_eq!(
+            config
+                .linters
+                .options
+                .long_sentences
+                .map(|options| options.max_words),
+            Some(30)
+        );
+    }
+
+    #[test]
+    fn applies_language_linters_on_top() {
+        let config = ProjectConfig::from_toml_str(
+            "[linters]\npassive_voice = false\nlong_sentences = false\n\n[language_linters.markdown]\npassive_voice = true",
+            "/project",
+        )
+        .unwrap();
+
+        let markdown = config.linters_for(Some("markdown"));
+        assert_eq!(markdown.passive_voice, Some(true));
+        assert_eq!(markdown.long_sentences, Some(false));
+
+        assert_eq!(config.linters_for(Some("rust")).passive_voice, Some(false));
+        assert_eq!(config.linters_for(None).passive_voice, Some(false));
+    }
+
+    #[test]
+    fn parses_json() {
+        let config = ProjectConfig::from_json_str(
+            r#"{ "linters": { "an_a": false }, "ignore": ["*.txt"] }"#,
+            "/project",
+        )
+        .unwrap();
+
+        assert_eq!(config.linters.an_a, Some(false));
+        assert!(config.is_ignored("/project/notes.txt"));
+    }
+
+    #[test]
+    fn rejects_unknown_keys() {
+        assert!(ProjectConfig::from_toml_str("linterz = 1", "/project").is_err());
+    }
+
+    #[test]
+    fn matches_ignore_patterns() {
+        let config = ProjectConfig::from_toml_str(EXAMPLE, "/project").unwrap();
+
+        assert!(config.is_ignored("/project/vendor/lib/README.md"));
+        assert!(config.is_ignored("/project/CHANGELOG.md"));
+        assert!(!config.is_ignored("/project/README.md"));
+    }
+
+    #[test]
+    fn overrides_severity_by_rule() {
+        let config = ProjectConfig::from_toml_str(EXAMPLE, "/project").unwrap();
+
+        let spelling = Lint {
+            rule: Some("SpellCheck".to_string()),
+            ..Default::default()
+        };
+        let other = Lint {
+            rule: Some("AnA".to_string()),
+            ..Default::default()
+        };
+
+        assert_eq!(config.severity_for(&spelling), Some(Severity::Warning));
+        assert_eq!(config.severity_for(&other), None);
+    }
+}
@@ -0,0 +1,392 @@
+//! Readability metrics, like the Flesch–Kincaid grade level, for a whole
+//! [`Document`] and each of its paragraphs and sentences.
+
+use std::iter::Sum;
+use std::ops::Add;
+
+use serde::{Deserialize, Serialize};
+
+use crate::linting::{PassiveVoice, PatternLinter};
+use crate::patterns::Pattern;
+use crate::{CharStringExt, Document, Span, Token, TokenStringExt};
+
+/// Words with at least this many syllables count as complex in the Gunning
+/// Fog index.
+const COMPLEX_SYLLABLES: usize = 3;
+
+thread_local! {
+    // The pattern is built once and shared, since scores are counted for
+    // every paragraph and sentence, often on each edit.
+    static PASSIVE_VOICE: PassiveVoice = PassiveVoice::default();
+}
+
+/// The counts that readability scores are calculated from.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TextCounts {
+    pub words: usize,
+    pub sentences: usize,
+    pub syllables: usize,
+    /// Words of three or more syllables, not counting proper nouns.
+    pub complex_words: usize,
+    /// Sentences containing a passive construction, like “was written”.
+    pub passive_sentences: usize,
+}
+
+impl TextCounts {
+    /// Count the words and sentences in some tokens.
+    ///
+    /// Only sentences containing at least one word are counted.
+    pub fn from_tokens(tokens: &[Token], source: &[char]) -> Self {
+        tokens
+            .iter_sentences()
+            .map(|sentence| Self::from_sentence(sentence, source))
+            .sum()
+    }
+
+    /// Count the words in a single sentence, which is only counted itself if
+    /// it contains at least one word.
+    fn from_sentence(sentence: &[Token], source: &[char]) -> Self {
+        let mut counts = Self::default();
+
+        for word in sentence.iter_words() {
+            let syllables = count_syllables(word.span.get_content(source));
+            counts.words += 1;
+            counts.syllables += syllables;
+
+            let is_proper = word.kind.as_word().is_some_and(|m| m.is_proper_noun());
+            if syllables >= COMPLEX_SYLLABLES && !is_proper {
+                counts.complex_words += 1;
+            }
+        }
+
+        if counts.words == 0 {
+            return counts;
+        }
+
+        counts.sentences = 1;
+
+        if PASSIVE_VOICE
+            .with(|passive_voice| contains_match(passive_voice.pattern(), sentence, source))
+        {
+            counts.passive_sentences = 1;
+        }
+
+        counts
+    }
+
+    /// The average number of words in each sentence.
+    pub fn average_sentence_length(&self) -> Option<f32> {
+        ratio(self.words, self.sentences)
+    }
+
+    /// The average number of syllables in each word.
+    pub fn average_syllables_per_word(&self) -> Option<f32> {
+        ratio(self.syllables, self.words)
+    }
+
+    /// The share of sentences, from zero to one, that use the passive voice.
+    pub fn passive_ratio(&self) -> Option<f32> {
+        ratio(self.passive_sentences, self.sentences)
+    }
+
+    /// The Flesch reading ease score, where higher is easier. Most text
+    /// scores between 0 and 100.
+    pub fn flesch_reading_ease(&self) -> Option<f32> {
+        Some(
+            206.835
+                - 1.015 * self.average_sentence_length()?
+                - 84.6 * self.average_syllables_per_word()?,
+        )
+    }
+
+    /// The Flesch–Kincaid grade level: roughly, the U.S. school grade needed
+    /// to understand the text.
+    pub fn flesch_kincaid_grade(&self) -> Option<f32> {
+        Some(
+            0.39 * self.average_sentence_length()? + 11.8 * self.average_syllables_per_word()?
+                - 15.59,
+        )
+    }
+
+    /// The Gunning Fog index: the years of formal education needed to
+    /// understand the text on a first reading.
+    pub fn gunning_fog(&self) -> Option<f32> {
+        Some(
+            0.4 * (self.average_sentence_length()?
+                + 100.0 * ratio(self.complex_words, self.words)?),
+        )
+    }
+
+    /// The counts and scores, ready to be displayed.
+    pub fn to_scores(&self) -> ReadabilityScores {
+        ReadabilityScores {
+            counts: *self,
+            average_sentence_length: self.average_sentence_length(),
+            average_syllables_per_word: self.average_syllables_per_word(),
+            passive_ratio: self.passive_ratio(),
+            flesch_reading_ease: self.flesch_reading_ease(),
+            flesch_kincaid_grade: self.flesch_kincaid_grade(),
+            gunning_fog: self.gunning_fog(),
+        }
+    }
+}
+
+impl Add for TextCounts {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            words: self.words + other.words,
+            sentences: self.sentences + other.sentences,
+            syllables: self.syllables + other.syllables,
+            complex_words: self.complex_words + other.complex_words,
+            passive_sentences: self.passive_sentences + other.passive_sentences,
+        }
+    }
+}
+
+impl Sum for TextCounts {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), Add::add)
+    }
+}
+
+fn ratio(numerator: usize, denominator: usize) -> Option<f32> {
+    (denominator > 0).then(|| numerator as f32 / denominator as f32)
+}
+
+fn contains_match(pattern: &dyn Pattern, tokens: &[Token], source: &[char]) -> bool {
+    (0..tokens.len()).any(|i| pattern.matches(&tokens[i..], source) > 0)
+}
+
+/// A piece of text's counts, along with every score calculated from them.
+///
+/// Scores are [`None`] when the text has no words or sentences.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ReadabilityScores {
+    #[serde(flatten)]
+    pub counts: TextCounts,
+    pub average_sentence_length: Option<f32>,
+    pub average_syllables_per_word: Option<f32>,
+    pub passive_ratio: Option<f32>,
+    pub flesch_reading_ease: Option<f32>,
+    pub flesch_kincaid_grade: Option<f32>,
+    pub gunning_fog: Option<f32>,
+}
+
+/// The readability of a single sentence.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SentenceReadability {
+    pub span: Span,
+    pub scores: ReadabilityScores,
+}
+
+/// The readability of a paragraph, and of each sentence in it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ParagraphReadability {
+    pub span: Span,
+    pub scores: ReadabilityScores,
+    pub sentences: Vec<SentenceReadability>,
+}
+
+/// The readability of a whole document, and of each paragraph in it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReadabilityReport {
+    pub scores: ReadabilityScores,
+    pub paragraphs: Vec<ParagraphReadability>,
+}
+
+impl ReadabilityReport {
+    /// Measure the readability of a document.
+    ///
+    /// Paragraphs and sentences without any words are left out.
+    pub fn new(document: &Document) -> Self {
+        let source = document.get_source();
+
+        // Each sentence is only counted once, and paragraphs and the document
+        // are scored from the sum of their sentences' counts.
+        let mut document_counts = TextCounts::default();
+        let mut paragraphs = Vec::new();
+
+        for paragraph in document.iter_paragraphs() {
+            let mut paragraph_counts = TextCounts::default();
+            let mut sentences = Vec::new();
+
+            for sentence in paragraph.iter_sentences() {
+                let counts = TextCounts::from_sentence(sentence, source);
+
+                if counts.words == 0 {
+                    continue;
+                }
+
+                paragraph_counts = paragraph_counts + counts;
+
+                if let Some(span) = sentence.span() {
+                    sentences.push(SentenceReadability {
+                        span,
+                        scores: counts.to_scores(),
+                    });
+                }
+            }
+
+            document_counts = document_counts + paragraph_counts;
+
+            if paragraph_counts.words == 0 {
+                continue;
+            }
+
+            let Some(span) = paragraph.span() else {
+                continue;
+            };
+
+            paragraphs.push(ParagraphReadability {
+                span,
+                scores: paragraph_counts.to_scores(),
+                sentences,
+            });
+        }
+
+        Self {
+            scores: document_counts.to_scores(),
+            paragraphs,
+        }
+    }
+}
+
+fn is_vowel(c: char) -> bool {
+    matches!(c, 'a' | 'e' | 'i' | 'o' | 'u' | 'y')
+}
+
+/// Estimate the number of syllables in a word, by counting groups of vowels.
+///
+/// Every word has at least one syllable.
+pub fn count_syllables(word: &[char]) -> usize {
+    let letters: Vec<char> = word
+        .to_lower()
+        .iter()
+        .copied()
+        .filter(|c| c.is_alphabetic())
+        .collect();
+
+    let mut syllables = 0;
+    let mut previous_vowel = false;
+
+    for &c in &letters {
+        let vowel = is_vowel(c);
+
+        if vowel && !previous_vowel {
+            syllables += 1;
+        }
+
+        previous_vowel = vowel;
+    }
+
+    // Endings that are usually silent, like in “make”, “walked” and “makes”,
+    // but not “table”, “wanted” or “boxes”.
+    match letters.as_slice() {
+        [.., c, 'l', 'e'] if !is_vowel(*c) => (),
+        [.., c, 'e'] if !is_vowel(*c) => syllables -= 1,
+        [.., c, 'e', 'd'] if !matches!(c, 't' | 'd') && !is_vowel(*c) => syllables -= 1,
+        [.., c, 'e', 's'] if !matches!(c, 's' | 'x' | 'z' | 'c' | 'g' | 'h') && !is_vowel(*c) => {
+            syllables -= 1
+        }
+        _ => (),
+    }
+
+    syllables.max(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{count_syllables, ReadabilityReport, TextCounts};
+    use crate::Document;
+
+    fn syllables(word: &str) -> usize {
+        count_syllables(&word.chars().collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn counts_syllables() {
+        assert_eq!(syllables("the"), 1);
+        assert_eq!(syllables("cat"), 1);
+        assert_eq!(syllables("make"), 1);
+        assert_eq!(syllables("table"), 2);
+        assert_eq!(syllables("reading"), 2);
+        assert_eq!(syllables("walked"), 1);
+        assert_eq!(syllables("wanted"), 2);
+        assert_eq!(syllables("makes"), 1);
+        assert_eq!(syllables("boxes"), 2);
+        assert_eq!(syllables("beautiful"), 3);
+        assert_eq!(syllables("readability"), 5);
+    }
+
+    #[test]
+    fn simple_text_scores_low() {
+        let document = Document::new_markdown_curated("The cat sat on the mat. It was warm.");
+        let scores = ReadabilityReport::new(&document).scores;
+
+        assert_eq!(scores.counts.words, 9);
+        assert_eq!(scores.counts.sentences, 2);
+        assert_eq!(scores.average_sentence_length, Some(4.5));
+        assert!(scores.flesch_kincaid_grade.unwrap() < 2.0);
+        assert!(scores.flesch_reading_ease.unwrap() > 100.0);
+    }
+
+    #[test]
+    fn complex_text_scores_high() {
+        let document = Document::new_markdown_curated(
+            "Institutional considerations necessitate comprehensive organizational evaluation of the administrative infrastructure.",
+        );
+        let scores = ReadabilityReport::new(&document).scores;
+
+        assert!(scores.flesch_kincaid_grade.unwrap() > 16.0);
+        assert!(scores.gunning_fog.unwrap() > 16.0);
+    }
+
+    #[test]
+    fn counts_passive_sentences() {
+        let document = Document::new_markdown_curated(
+            "The bug was fixed. We shipped it. The docs were updated.",
+        );
+        let scores = ReadabilityReport::new(&document).scores;
+
+        assert_eq!(scores.counts.passive_sentences, 2);
+        assert!((scores.passive_ratio.unwrap() - 2.0 / 3.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn reports_each_paragraph_and_sentence() {
+        let document =
+            Document::new_markdown_curated("One short line. And another.\n\nA second paragraph.");
+        let report = ReadabilityReport::new(&document);
+
+        assert_eq!(report.paragraphs.len(), 2);
+        assert_eq!(report.paragraphs[0].sentences.len(), 2);
+        assert_eq!(report.paragraphs[1].sentences.len(), 1);
+        assert_eq!(
+            document.get_span_content_str(report.paragraphs[1].span),
+            "A second paragraph."
+        );
+    }
+
+    #[test]
+    fn document_counts_are_the_sum_of_its_sentences() {
+        let document = Document::new_markdown_curated(
+            "The bug was fixed. We shipped it.\n\nThe docs were updated by a comprehensive review.",
+        );
+        let report = ReadabilityReport::new(&document);
+
+        assert_eq!(
+            report.scores.counts,
+            TextCounts::from_tokens(document.get_tokens(), document.get_source())
+        );
+    }
+
+    #[test]
+    fn empty_text_has_no_scores() {
+        let scores = TextCounts::default().to_scores();
+
+        assert_eq!(scores.flesch_kincaid_grade, None);
+        assert_eq!(scores.gunning_fog, None);
+    }
+}
@@ -0,0 +1,122 @@
+use hashbrown::HashMap;
+use serde::{Deserialize, Serialize};
+
+use crate::linting::Lint;
+
+/// How urgently a lint should be brought to the user's attention.
+///
+/// Variants are ordered from most to least urgent.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(rename_all = "camelCase")]
+pub enum Severity {
+    Error,
+    Warning,
+    Information,
+    Hint,
+}
+
+/// Overrides the [`Severity`] of lints, keyed either by [`Lint::rule`]
+/// (e.g. `SpellCheck`) or by the name of a
+/// [`LintKind`](crate::linting::LintKind) (e.g. `Readability`).
+///
+/// When both match a lint, the one for its rule is used.
+///
+/// ```toml
+/// Spelling = "warning"
+/// Readability = "hint"
+/// BannedTerms = "error"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct SeverityOverrides(HashMap<String, Severity>);
+
+impl SeverityOverrides {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Set the severity for a rule or [`LintKind`](crate::linting::LintKind).
+    pub fn insert(&mut self, key: impl Into<String>, severity: Severity) {
+        self.0.insert(key.into(), severity);
+    }
+
+    /// Get the overridden severity of a lint, if there is one.
+    pub fn get(&self, lint: &Lint) -> Option<Severity> {
+        lint.rule
+            .as_deref()
+            .and_then(|rule| self.0.get(rule))
+            .or_else(|| self.0.get(lint.lint_kind.to_string().as_str()))
+            .copied()
+    }
+
+    /// Replaces the severities in `self` with any that are set in `other`.
+    pub fn override_with(&mut self, other: &Self) {
+        self.0.extend(
+            other
+                .0
+                .iter()
+                .map(|(key, severity)| (key.clone(), *severity)),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Severity, SeverityOverrides};
+    use crate::linting::{Lint, LintKind};
+
+    fn lint(rule: &str, lint_kind: LintKind) -> Lint {
+        Lint {
+            rule: Some(rule.to_string()),
+            lint_kind,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn prefers_rules_over_kinds() {
+        let overrides: SeverityOverrides = serde_json::from_str(
+            r#"{ "Spelling": "warning", "Readability": "hint", "SpellCheck": "error" }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            overrides.get(&lint("SpellCheck", LintKind::Spelling)),
+            Some(Severity::Error)
+        );
+        assert_eq!(
+            overrides.get(&lint("BannedTerms", LintKind::Spelling)),
+            Some(Severity::Warning)
+        );
+        assert_eq!(
+            overrides.get(&lint("LongSentences", LintKind::Readability)),
+            Some(Severity::Hint)
+        );
+        assert_eq!(overrides.get(&lint("AnA", LintKind::Miscellaneous)), None);
+    }
+
+    #[test]
+    fn overrides_by_key() {
+        let mut base = SeverityOverrides::new();
+        base.insert("Spelling", Severity::Hint);
+        base.insert("AnA", Severity::Hint);
+
+        let mut other = SeverityOverrides::new();
+        other.insert("Spelling", Severity::Error);
+
+        base.override_with(&other);
+
+        assert_eq!(
+            base.get(&lint("SpellCheck", LintKind::Spelling)),
+            Some(Severity::Error)
+        );
+        assert_eq!(
+            base.get(&lint("AnA", LintKind::Miscellaneous)),
+            Some(Severity::Hint)
+        );
+    }
+}
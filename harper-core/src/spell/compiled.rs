@@ -0,0 +1,132 @@
+//! The format of compiled dictionaries, and the words of the curated
+//! dictionary that is compiled into the Harper binary.
+//!
+//! This is also included by the build script, which compiles the curated
+//! dictionary for each dialect, so it may only use modules that don't need a
+//! [`Dictionary`](crate::Dictionary).
+
+use hashbrown::HashMap;
+use serde::{Deserialize, Serialize};
+
+use super::dialect_spellings::dialect_spellings;
+use super::hunspell::{parse_default_attribute_list, parse_default_word_list};
+use super::inflection::annotate_inflections;
+use crate::{CharString, Dialect, WordMetadata};
+
+/// The version of the metadata file that accompanies the FST of a compiled
+/// dictionary.
+pub(crate) const COMPILED_VERSION: u32 = 2;
+
+/// The metadata that accompanies the FST of a compiled dictionary.
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct CompiledMetadata {
+    pub version: u32,
+    /// The number of words in the FST.
+    pub word_count: usize,
+    /// The checksum the FST ends with, so metadata written for a different
+    /// FST isn't used with this one.
+    pub fst_checksum: u32,
+    /// Each distinct set of metadata in the dictionary.
+    pub metadata: Vec<WordMetadata>,
+}
+
+impl CompiledMetadata {
+    pub fn new(fst: &[u8], word_count: usize, metadata: Vec<WordMetadata>) -> Self {
+        Self {
+            version: COMPILED_VERSION,
+            word_count,
+            fst_checksum: fst_checksum(fst),
+            metadata,
+        }
+    }
+}
+
+/// The checksum that `fst` writes at the end of every FST it builds.
+pub(crate) fn fst_checksum(fst: &[u8]) -> u32 {
+    match fst {
+        [.., a, b, c, d] => u32::from_le_bytes([*a, *b, *c, *d]),
+        _ => 0,
+    }
+}
+
+/// Combine the index of a word and the index of its metadata into a single
+/// FST value.
+///
+/// Values grow with the index of the word, so the FST can share them between
+/// neighbouring words and stay small.
+pub(crate) fn pack_value(word_index: usize, metadata_index: usize, metadata_len: usize) -> u64 {
+    (word_index * metadata_len + metadata_index) as u64
+}
+
+/// Split an FST value into the index of a word and the index of its metadata.
+pub(crate) fn unpack_value(value: u64, metadata_len: usize) -> (usize, usize) {
+    let value = value as usize;
+    (value / metadata_len, value % metadata_len)
+}
+
+/// An FST that maps each word to its index and the index of its metadata,
+/// along with the words and the distinct metadata it refers to.
+pub(crate) struct CompiledWords {
+    pub fst: Vec<u8>,
+    pub words: Vec<CharString>,
+    pub metadata: Vec<WordMetadata>,
+}
+
+impl CompiledWords {
+    pub fn new(words: HashMap<CharString, WordMetadata>) -> Self {
+        let mut words: Vec<(CharString, WordMetadata)> = words.into_iter().collect();
+        words.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+        words.dedup_by(|(a, _), (b, _)| a == b);
+
+        let mut distinct = HashMap::new();
+        let mut metadata = Vec::new();
+        let metadata_indices: Vec<usize> = words
+            .iter()
+            .map(|(_, word_metadata)| {
+                *distinct.entry(*word_metadata).or_insert_with(|| {
+                    metadata.push(*word_metadata);
+                    metadata.len() - 1
+                })
+            })
+            .collect();
+
+        let mut builder = fst::MapBuilder::memory();
+        for (index, ((word, _), metadata_index)) in words.iter().zip(metadata_indices).enumerate() {
+            let word = word.iter().collect::<String>();
+            builder
+                .insert(word, pack_value(index, metadata_index, metadata.len()))
+                .expect("Insertion not in lexicographical order!");
+        }
+
+        Self {
+            fst: builder.into_inner().unwrap(),
+            words: words.into_iter().map(|(word, _)| word).collect(),
+            metadata,
+        }
+    }
+}
+
+/// Expand the curated word list for a dialect with its affix rules, and
+/// label its inflected forms and the spellings `dialect` uses.
+pub(crate) fn curated_words(dialect: Dialect) -> HashMap<CharString, WordMetadata> {
+    let word_list = parse_default_word_list().unwrap();
+    let attr_list = parse_default_attribute_list();
+
+    // There will be at _least_ this number of words
+    let mut word_map = HashMap::with_capacity(word_list.len());
+    attr_list.expand_marked_words(word_list.iter().cloned(), &mut word_map);
+    annotate_inflections(&mut word_map, &word_list);
+
+    // The American spellings are kept, so they can be flagged with a more
+    // helpful message than a spelling error.
+    for (american, respelling) in dialect_spellings(dialect) {
+        let Some(metadata) = word_map.get(american).copied() else {
+            continue;
+        };
+
+        let entry = word_map.entry(respelling.clone()).or_default();
+        *entry = entry.or(&metadata);
+    }
+
+    word_map
+}
@@ -0,0 +1,196 @@
+use hashbrown::HashMap;
+use lazy_static::lazy_static;
+use serde::Deserialize;
+
+use super::hunspell::{parse_default_attribute_list, parse_default_word_list};
+use crate::{CharString, Dialect};
+
+/// A word that is spelled differently outside of American English.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SpellingEntry {
+    american: String,
+    british: String,
+    /// Defaults to the British spelling.
+    australian: Option<String>,
+    /// Defaults to the British spelling.
+    canadian: Option<String>,
+}
+
+impl SpellingEntry {
+    fn spelling(&self, dialect: Dialect) -> &str {
+        match dialect {
+            Dialect::American => &self.american,
+            Dialect::British => &self.british,
+            Dialect::Australian => self.australian.as_deref().unwrap_or(&self.british),
+            Dialect::Canadian => self.canadian.as_deref().unwrap_or(&self.british),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SpellingTable {
+    spellings: Vec<SpellingEntry>,
+}
+
+/// Pair the American spelling of each word in `dialects.json` with its
+/// spelling in every other dialect, including the forms produced by the
+/// affixes it is marked with in the curated dictionary.
+///
+/// Only words that are spelled differently are included.
+fn uncached_dialect_spellings() -> HashMap<Dialect, Vec<(CharString, CharString)>> {
+    let table: SpellingTable = serde_json::from_str(include_str!("../../dialects.json"))
+        .expect("The built-in dialect spellings should always be valid.");
+
+    let word_list = parse_default_word_list().unwrap();
+    let attr_list = parse_default_attribute_list();
+
+    let marked_words: HashMap<_, _> = word_list.iter().map(|w| (&w.letters, w)).collect();
+
+    let mut spellings = HashMap::new();
+
+    for dialect in Dialect::ALL {
+        let mut pairs = Vec::new();
+
+        for entry in &table.spellings {
+            let american: CharString = entry.american.chars().collect();
+            let respelling: CharString = entry.spelling(dialect).chars().collect();
+
+            if american == respelling {
+                continue;
+            }
+
+            // Inflected forms that differ are listed on their own.
+            match marked_words.get(&american) {
+                Some(word) => attr_list.expand_respelled_word(word, &respelling, &mut pairs),
+                None => pairs.push((american, respelling)),
+            }
+        }
+
+        pairs.retain(|(american, respelling)| american != respelling);
+        pairs.sort();
+        pairs.dedup();
+
+        spellings.insert(dialect, pairs);
+    }
+
+    spellings
+}
+
+/// Map the spellings used by other dialects to the ones used by each
+/// dialect.
+fn uncached_dialect_corrections() -> HashMap<Dialect, HashMap<CharString, CharString>> {
+    let mut corrections: HashMap<_, HashMap<_, _>> = HashMap::new();
+
+    for dialect in Dialect::ALL {
+        for (american, respelling) in dialect_spellings(dialect) {
+            corrections
+                .entry(dialect)
+                .or_default()
+                .insert(american.clone(), respelling.clone());
+
+            corrections
+                .entry(Dialect::American)
+                .or_default()
+                .entry(respelling.clone())
+                .or_insert(american.clone());
+        }
+    }
+
+    corrections
+}
+
+lazy_static! {
+    static ref SPELLINGS: HashMap<Dialect, Vec<(CharString, CharString)>> =
+        uncached_dialect_spellings();
+    static ref CORRECTIONS: HashMap<Dialect, HashMap<CharString, CharString>> =
+        uncached_dialect_corrections();
+}
+
+/// Pairs of lowercase words, each spelled the American way and the way it is
+/// spelled in `dialect`, where the two differ.
+pub(crate) fn dialect_spellings(dialect: Dialect) -> &'static [(CharString, CharString)] {
+    SPELLINGS
+        .get(&dialect)
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+}
+
+/// Maps lowercase words, as spelled in other dialects, to how they are
+/// spelled in `dialect`.
+pub(crate) fn dialect_corrections(dialect: Dialect) -> &'static HashMap<CharString, CharString> {
+    lazy_static! {
+        static ref EMPTY: HashMap<CharString, CharString> = HashMap::new();
+    }
+
+    CORRECTIONS.get(&dialect).unwrap_or(&EMPTY)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{dialect_corrections, dialect_spellings};
+    use crate::spell::Dictionary;
+    use crate::{CharString, Dialect, FstDictionary};
+
+    fn chars(word: &str) -> CharString {
+        word.chars().collect()
+    }
+
+    fn has_pair(dialect: Dialect, american: &str, respelling: &str) -> bool {
+        dialect_spellings(dialect).contains(&(chars(american), chars(respelling)))
+    }
+
+    #[test]
+    fn expands_inflected_forms() {
+        assert!(has_pair(Dialect::British, "color", "colour"));
+        assert!(has_pair(Dialect::British, "colors", "colours"));
+        assert!(has_pair(Dialect::British, "discolored", "discoloured"));
+        assert!(has_pair(Dialect::British, "organizing", "organising"));
+        assert!(has_pair(Dialect::British, "centered", "centred"));
+    }
+
+    #[test]
+    fn respects_canadian_spellings() {
+        assert!(has_pair(Dialect::Canadian, "color", "colour"));
+        assert!(!has_pair(Dialect::Canadian, "organize", "organise"));
+    }
+
+    #[test]
+    fn american_is_unchanged() {
+        assert!(dialect_spellings(Dialect::American).is_empty());
+    }
+
+    #[test]
+    fn corrects_towards_each_dialect() {
+        assert_eq!(
+            dialect_corrections(Dialect::British).get(&chars("colored")),
+            Some(&chars("coloured"))
+        );
+        assert_eq!(
+            dialect_corrections(Dialect::American).get(&chars("organised")),
+            Some(&chars("organized"))
+        );
+    }
+
+    #[test]
+    fn dictionaries_accept_their_spellings() {
+        let american = FstDictionary::curated();
+
+        for dialect in Dialect::ALL {
+            let dictionary = FstDictionary::curated_dialect(dialect);
+
+            for (american_spelling, respelling) in dialect_spellings(dialect) {
+                assert!(
+                    american.contains_word(american_spelling),
+                    "{:?} is missing from the curated dictionary",
+                    american_spelling
+                );
+                assert!(dictionary.contains_word(respelling));
+            }
+        }
+
+        assert!(!american.contains_word_str("colour"));
+        assert!(FstDictionary::curated_dialect(Dialect::British).contains_word_str("colour"));
+    }
+}
@@ -1,7 +1,7 @@
 use blanket::blanket;
 
-use super::FuzzyMatchResult;
-use crate::WordMetadata;
+use super::{inflection, FuzzyMatchResult};
+use crate::{CharString, VerbForm, WordMetadata};
 
 #[blanket(derive(Arc))]
 pub trait Dictionary: Send + Sync {
@@ -15,14 +15,14 @@ pub trait Dictionary: Send + Sync {
         word: &[char],
         max_distance: u8,
         max_results: usize,
-    ) -> Vec<FuzzyMatchResult>;
+    ) -> Vec<FuzzyMatchResult<'_>>;
     /// Gets best fuzzy match from dictionary
     fn fuzzy_match_str(
         &self,
         word: &str,
         max_distance: u8,
         max_results: usize,
-    ) -> Vec<FuzzyMatchResult>;
+    ) -> Vec<FuzzyMatchResult<'_>>;
     /// Get the associated [`WordMetadata`] for a given word.
     /// If the word isn't in the dictionary, the resulting metadata will be
     /// empty.
@@ -37,4 +37,29 @@ pub trait Dictionary: Send + Sync {
 
     /// Iterate over all the words in the dictionary of a given length
     fn words_with_len_iter(&self, len: usize) -> Box<dyn Iterator<Item = &'_ [char]> + Send + '_>;
+
+    /// Get the uninflected form of a word: the base form of a verb (“went”
+    /// becomes “go”) or the singular form of a noun (“children” becomes
+    /// “child”).
+    /// Words that aren't inflected are returned as they are, as long as
+    /// they are in the dictionary.
+    fn get_lemma(&self, word: &[char]) -> Option<CharString> {
+        inflection::lemma(self, word)
+    }
+    /// Get a verb, in any form, in the given form (“go” becomes “went” in
+    /// [`VerbForm::Past`]).
+    /// Returns [`None`] if the result isn't in the dictionary.
+    fn get_verb_form(&self, verb: &[char], form: VerbForm) -> Option<CharString> {
+        inflection::verb_form(self, verb, form)
+    }
+    /// Get the plural form of a noun.
+    /// Returns [`None`] if the result isn't in the dictionary.
+    fn get_plural(&self, noun: &[char]) -> Option<CharString> {
+        inflection::plural(self, noun)
+    }
+    /// Get the singular form of a noun.
+    /// Returns [`None`] if the result isn't in the dictionary.
+    fn get_singular(&self, noun: &[char]) -> Option<CharString> {
+        inflection::singular(self, noun)
+    }
 }
@@ -1,45 +1,102 @@
 use super::{
-    hunspell::{parse_default_attribute_list, parse_default_word_list},
-    seq_to_normalized, FullDictionary,
+    compiled::{
+        curated_words, fst_checksum, unpack_value, CompiledMetadata, CompiledWords,
+        COMPILED_VERSION,
+    },
+    hunspell, seq_to_normalized,
 };
 use fst::{map::StreamWithState, IntoStreamer, Map as FstMap, Streamer};
 use hashbrown::HashMap;
 use lazy_static::lazy_static;
 use levenshtein_automata::{LevenshteinAutomatonBuilder, DFA};
-use std::{cell::RefCell, sync::Arc};
+use memmap2::Mmap;
+use std::{
+    cell::RefCell,
+    fs::File,
+    io::Write,
+    path::Path,
+    sync::{Arc, OnceLock},
+};
 
-use crate::{CharString, CharStringExt, WordMetadata};
+use crate::{CharString, CharStringExt, Dialect, WordMetadata};
 
 use super::Dictionary;
 use super::FuzzyMatchResult;
+use super::HunspellError;
 
 pub struct FstDictionary {
-    /// Underlying FullDictionary used for everything except fuzzy finding
-    full_dict: Arc<FullDictionary>,
-    /// Used for fuzzy-finding the index of words or metadata
-    word_map: FstMap<Vec<u8>>,
-    /// Used for fuzzy-finding the index of words or metadata
-    words: Vec<(CharString, WordMetadata)>,
+    /// Maps each word to its index in [`Self::words`] and the index of its
+    /// metadata in [`Self::metadata`], combined by [`pack_value`].
+    word_map: FstMap<FstBytes>,
+    /// Each distinct set of metadata in the dictionary.
+    metadata: Vec<WordMetadata>,
+    /// The words of the FST, in order.
+    /// Only decoded when they are needed for fuzzy matching or iteration, so
+    /// a compiled dictionary can answer lookups straight from the FST.
+    words: OnceLock<Vec<CharString>>,
 }
 
-/// The uncached function that is used to produce the original copy of the
-/// curated dictionary.
-fn uncached_inner_new() -> Arc<FstDictionary> {
-    let word_list = parse_default_word_list().unwrap();
-    let attr_list = parse_default_attribute_list();
+/// The bytes of an FST, which may be owned by the dictionary or borrowed from
+/// somewhere else, like a memory-mapped file.
+#[derive(Clone)]
+struct FstBytes(Arc<dyn AsRef<[u8]> + Send + Sync>);
 
-    // There will be at _least_ this number of words
-    let mut word_map = HashMap::with_capacity(word_list.len());
-    attr_list.expand_marked_words(word_list, &mut word_map);
+impl AsRef<[u8]> for FstBytes {
+    fn as_ref(&self) -> &[u8] {
+        (*self.0).as_ref()
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CompiledDictionaryError {
+    #[error("Unable to read compiled dictionary: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Invalid FST in compiled dictionary: {0}")]
+    Fst(#[from] fst::Error),
+    #[error("Invalid metadata in compiled dictionary: {0}")]
+    Metadata(#[from] serde_json::Error),
+    #[error("Compiled dictionary has version {0}, but only version {COMPILED_VERSION} is supported. Try compiling it again.")]
+    UnsupportedVersion(u32),
+    #[error("The FST and metadata of the compiled dictionary do not match.")]
+    Mismatched,
+}
 
-    Arc::new(FstDictionary::new(word_map))
+/// Load the copy of the curated dictionary for a dialect that the build
+/// script compiled into the Harper binary.
+fn uncached_inner_new(dialect: Dialect) -> Arc<FstDictionary> {
+    let (fst, metadata): (&'static [u8], &'static [u8]) = match dialect {
+        Dialect::American => (
+            include_bytes!(concat!(env!("OUT_DIR"), "/american.fst")),
+            include_bytes!(concat!(env!("OUT_DIR"), "/american.meta")),
+        ),
+        Dialect::British => (
+            include_bytes!(concat!(env!("OUT_DIR"), "/british.fst")),
+            include_bytes!(concat!(env!("OUT_DIR"), "/british.meta")),
+        ),
+        Dialect::Australian => (
+            include_bytes!(concat!(env!("OUT_DIR"), "/australian.fst")),
+            include_bytes!(concat!(env!("OUT_DIR"), "/australian.meta")),
+        ),
+        Dialect::Canadian => (
+            include_bytes!(concat!(env!("OUT_DIR"), "/canadian.fst")),
+            include_bytes!(concat!(env!("OUT_DIR"), "/canadian.meta")),
+        ),
+    };
+
+    Arc::new(
+        FstDictionary::from_compiled(fst, metadata)
+            .expect("The build script should always compile a valid curated dictionary."),
+    )
 }
 
 const EXPECTED_DISTANCE: u8 = 3;
 const TRANSPOSITION_COST_ONE: bool = false;
 
 lazy_static! {
-    static ref DICT: Arc<FstDictionary> = uncached_inner_new();
+    static ref DICT: Arc<FstDictionary> = uncached_inner_new(Dialect::American);
+    static ref BRITISH_DICT: Arc<FstDictionary> = uncached_inner_new(Dialect::British);
+    static ref AUSTRALIAN_DICT: Arc<FstDictionary> = uncached_inner_new(Dialect::Australian);
+    static ref CANADIAN_DICT: Arc<FstDictionary> = uncached_inner_new(Dialect::Canadian);
 }
 
 thread_local! {
@@ -54,8 +111,11 @@ thread_local! {
 }
 
 impl PartialEq for FstDictionary {
+    /// Dictionaries built from the same words compare equal, since both the
+    /// FST and the order of the distinct metadata follow the sorted words.
     fn eq(&self, other: &Self) -> bool {
-        self.full_dict == other.full_dict
+        self.word_map.as_fst().as_bytes() == other.word_map.as_fst().as_bytes()
+            && self.metadata == other.metadata
     }
 }
 
@@ -66,30 +126,172 @@ impl FstDictionary {
         (*DICT).clone()
     }
 
-    pub fn new(new_words: HashMap<CharString, WordMetadata>) -> Self {
-        let mut words: Vec<(CharString, WordMetadata)> = new_words.into_iter().collect();
-        words.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
-        words.dedup_by(|(a, _), (b, _)| a == b);
-
-        let mut builder = fst::MapBuilder::memory();
-        for (index, (word, _)) in words.iter().enumerate() {
-            let word = word.iter().collect::<String>();
-            builder
-                .insert(word, index as u64)
-                .expect("Insertion not in lexicographical order!");
+    /// Create a dictionary from the curated dictionary included in the Harper
+    /// binary, which also accepts the spellings used by `dialect`.
+    pub fn curated_dialect(dialect: Dialect) -> Arc<Self> {
+        match dialect {
+            Dialect::American => (*DICT).clone(),
+            Dialect::British => (*BRITISH_DICT).clone(),
+            Dialect::Australian => (*AUSTRALIAN_DICT).clone(),
+            Dialect::Canadian => (*CANADIAN_DICT).clone(),
         }
+    }
 
-        let mut full_dict = FullDictionary::new();
-        full_dict.extend_words(words.iter().cloned());
+    /// Build the curated dictionary for a dialect from its word list and affix
+    /// rules, instead of loading the copy compiled into the Harper binary.
+    ///
+    /// This is slow, and the copy in the binary is compiled from the same
+    /// sources by the build script, so it is rarely needed.
+    pub fn compile_curated(dialect: Dialect) -> Self {
+        Self::new(curated_words(dialect))
+    }
 
-        let fst_bytes = builder.into_inner().unwrap();
-        let word_map = FstMap::new(fst_bytes).expect("Unable to build FST map.");
+    /// Create a dictionary from the contents of a Hunspell `.aff` file and
+    /// the `.dic` file that uses it.
+    pub fn from_hunspell(aff: &str, dic: &str) -> Result<Self, HunspellError> {
+        Ok(Self::new(hunspell::parse_hunspell(aff, dic)?))
+    }
+
+    /// Read a Hunspell dictionary from its `.aff` and `.dic` files.
+    pub fn from_hunspell_files(
+        aff_path: impl AsRef<Path>,
+        dic_path: impl AsRef<Path>,
+    ) -> Result<Self, HunspellError> {
+        Ok(Self::new(hunspell::read_hunspell(aff_path, dic_path)?))
+    }
+
+    pub fn new(new_words: HashMap<CharString, WordMetadata>) -> Self {
+        let CompiledWords {
+            fst,
+            words,
+            metadata,
+        } = CompiledWords::new(new_words);
+
+        let word_map = FstMap::new(FstBytes(Arc::new(fst))).expect("Unable to build FST map.");
 
         FstDictionary {
-            full_dict: Arc::new(full_dict),
             word_map,
-            words,
+            metadata,
+            words: OnceLock::from(words),
+        }
+    }
+
+    /// Write the dictionary in a form that can be loaded without expanding
+    /// or sorting its words again: the FST itself, followed by the metadata of
+    /// its words.
+    ///
+    /// See [`Self::from_compiled`].
+    pub fn write_compiled(
+        &self,
+        mut fst: impl Write,
+        metadata: impl Write,
+    ) -> Result<(), CompiledDictionaryError> {
+        let fst_bytes = self.word_map.as_fst().as_bytes();
+        fst.write_all(fst_bytes)?;
+
+        let compiled = CompiledMetadata::new(fst_bytes, self.word_map.len(), self.metadata.clone());
+
+        serde_json::to_writer(metadata, &compiled)?;
+
+        Ok(())
+    }
+
+    /// Load a dictionary written by [`Self::write_compiled`].
+    ///
+    /// The FST is used in place, so it can be backed by a memory-mapped file
+    /// or bytes included in the binary, and words are looked up in it
+    /// directly.
+    pub fn from_compiled(
+        fst: impl AsRef<[u8]> + Send + Sync + 'static,
+        metadata: &[u8],
+    ) -> Result<Self, CompiledDictionaryError> {
+        let word_map = FstMap::new(FstBytes(Arc::new(fst)))?;
+        let compiled: CompiledMetadata = serde_json::from_slice(metadata)?;
+
+        if compiled.version != COMPILED_VERSION {
+            return Err(CompiledDictionaryError::UnsupportedVersion(
+                compiled.version,
+            ));
         }
+
+        if compiled.word_count != word_map.len()
+            || compiled.fst_checksum != fst_checksum(word_map.as_fst().as_bytes())
+            || (compiled.metadata.is_empty() && !word_map.is_empty())
+        {
+            return Err(CompiledDictionaryError::Mismatched);
+        }
+
+        Ok(FstDictionary {
+            word_map,
+            metadata: compiled.metadata,
+            words: OnceLock::new(),
+        })
+    }
+
+    /// Read a dictionary written by [`Self::write_compiled`] from disk.
+    ///
+    /// The FST is mapped into memory rather than read, so only the parts
+    /// of it that are used are ever loaded. The file must not change while
+    /// the dictionary is in use, so long-lived processes, which can't rule
+    /// that out, should use [`Self::read_compiled`] instead.
+    pub fn load_compiled(
+        fst_path: impl AsRef<Path>,
+        metadata_path: impl AsRef<Path>,
+    ) -> Result<Self, CompiledDictionaryError> {
+        let file = File::open(fst_path)?;
+        // SAFETY: The mapping is only ever read. `harper-cli compile-dict`
+        // replaces compiled dictionaries by renaming a new file over them,
+        // which leaves existing mappings of the old one intact. Anything that
+        // truncates or writes to the file in place while it is mapped would
+        // still change it under us, which is why this is only used by
+        // short-lived processes.
+        let fst = unsafe { Mmap::map(&file)? };
+        let metadata = std::fs::read(metadata_path)?;
+
+        Self::from_compiled(fst, &metadata)
+    }
+
+    /// Read a dictionary written by [`Self::write_compiled`] from disk into
+    /// memory, so it isn't affected by later changes to the files.
+    pub fn read_compiled(
+        fst_path: impl AsRef<Path>,
+        metadata_path: impl AsRef<Path>,
+    ) -> Result<Self, CompiledDictionaryError> {
+        let fst = std::fs::read(fst_path)?;
+        let metadata = std::fs::read(metadata_path)?;
+
+        Self::from_compiled(fst, &metadata)
+    }
+
+    /// The words of the dictionary in the order of the FST, decoding them
+    /// from it the first time they are needed.
+    fn words(&self) -> &[CharString] {
+        self.words.get_or_init(|| {
+            let mut words = Vec::with_capacity(self.word_map.len());
+            let mut stream = self.word_map.keys();
+
+            while let Some(word) = stream.next() {
+                words.push(String::from_utf8_lossy(word).chars().collect());
+            }
+
+            words
+        })
+    }
+
+    /// Look up the metadata of a word exactly as it is written.
+    fn exact_metadata(&self, word: &[char]) -> Option<WordMetadata> {
+        let word: String = word.iter().collect();
+        let (_, metadata_index) = unpack_value(self.word_map.get(word)?, self.metadata.len());
+
+        self.metadata.get(metadata_index).copied()
+    }
+
+    /// Look up the metadata of a word, falling back to its lowercase form.
+    fn lookup_metadata(&self, word: &[char]) -> Option<WordMetadata> {
+        let normalized = seq_to_normalized(word);
+
+        self.exact_metadata(&normalized)
+            .or_else(|| self.exact_metadata(&normalized.to_lower()))
     }
 }
 
@@ -125,19 +327,21 @@ fn stream_distances_vec(stream: &mut StreamWithState<&DFA>, dfa: &DFA) -> Vec<(u
 
 impl Dictionary for FstDictionary {
     fn contains_word(&self, word: &[char]) -> bool {
-        self.full_dict.contains_word(word)
+        self.lookup_metadata(word).is_some()
     }
 
     fn contains_word_str(&self, word: &str) -> bool {
-        self.full_dict.contains_word_str(word)
+        let chars: CharString = word.chars().collect();
+        self.contains_word(&chars)
     }
 
     fn get_word_metadata(&self, word: &[char]) -> WordMetadata {
-        self.full_dict.get_word_metadata(word)
+        self.lookup_metadata(word).unwrap_or_default()
     }
 
     fn get_word_metadata_str(&self, word: &str) -> WordMetadata {
-        self.full_dict.get_word_metadata_str(word)
+        let chars: CharString = word.chars().collect();
+        self.get_word_metadata(&chars)
     }
 
     fn fuzzy_match(
@@ -145,7 +349,7 @@ impl Dictionary for FstDictionary {
         word: &[char],
         max_distance: u8,
         max_results: usize,
-    ) -> Vec<FuzzyMatchResult> {
+    ) -> Vec<FuzzyMatchResult<'_>> {
         let misspelled_word_charslice = seq_to_normalized(word);
         let misspelled_word_string = misspelled_word_charslice.to_string();
 
@@ -161,22 +365,31 @@ impl Dictionary for FstDictionary {
         let upper_dists = stream_distances_vec(&mut word_indexes_stream, &dfa);
         let lower_dists = stream_distances_vec(&mut word_indexes_lowercase_stream, &dfa_lowercase);
 
+        let words = self.words();
         let mut merged = Vec::with_capacity(upper_dists.len());
 
         // Merge the two results
-        for ((i_u, dist_u), (i_l, dist_l)) in upper_dists.into_iter().zip(lower_dists.into_iter()) {
-            let (chosen_index, edit_distance) = if dist_u <= dist_l {
+        for ((i_u, dist_u), (i_l, dist_l)) in upper_dists.into_iter().zip(lower_dists) {
+            let (chosen_value, edit_distance) = if dist_u <= dist_l {
                 (i_u, dist_u)
             } else {
                 (i_l, dist_l)
             };
 
-            let (word, metadata) = &self.words[chosen_index as usize];
+            let (word_index, metadata_index) = unpack_value(chosen_value, self.metadata.len());
+
+            let Some(word) = words.get(word_index) else {
+                continue;
+            };
 
             merged.push(FuzzyMatchResult {
                 word,
                 edit_distance,
-                metadata: *metadata,
+                metadata: self
+                    .metadata
+                    .get(metadata_index)
+                    .copied()
+                    .unwrap_or_default(),
             })
         }
 
@@ -192,7 +405,7 @@ impl Dictionary for FstDictionary {
         word: &str,
         max_distance: u8,
         max_results: usize,
-    ) -> Vec<FuzzyMatchResult> {
+    ) -> Vec<FuzzyMatchResult<'_>> {
         self.fuzzy_match(
             word.chars().collect::<Vec<_>>().as_slice(),
             max_distance,
@@ -201,11 +414,16 @@ impl Dictionary for FstDictionary {
     }
 
     fn words_iter(&self) -> Box<dyn Iterator<Item = &'_ [char]> + Send + '_> {
-        self.full_dict.words_iter()
+        Box::new(self.words().iter().map(|v| v.as_slice()))
     }
 
     fn words_with_len_iter(&self, len: usize) -> Box<dyn Iterator<Item = &'_ [char]> + Send + '_> {
-        self.full_dict.words_with_len_iter(len)
+        Box::new(
+            self.words()
+                .iter()
+                .filter(move |v| v.len() == len)
+                .map(|v| v.as_slice()),
+        )
     }
 }
 
@@ -216,7 +434,10 @@ mod tests {
     use crate::CharStringExt;
     use crate::{spell::seq_to_normalized, Dictionary};
 
-    use super::FstDictionary;
+    use hashbrown::HashMap;
+
+    use super::{CompiledDictionaryError, FstDictionary};
+    use crate::{Dialect, WordMetadata};
 
     #[test]
     fn fst_map_contains_all_in_full_dict() {
@@ -253,6 +474,119 @@ mod tests {
         );
     }
 
+    #[test]
+    fn compiled_round_trip() {
+        let dict = FstDictionary::curated();
+
+        let mut fst = Vec::new();
+        let mut metadata = Vec::new();
+        dict.write_compiled(&mut fst, &mut metadata).unwrap();
+
+        let compiled = FstDictionary::from_compiled(fst, &metadata).unwrap();
+
+        assert!(compiled == *dict);
+        assert_eq!(compiled.words(), dict.words());
+        assert_eq!(
+            compiled.get_word_metadata_str("giants"),
+            dict.get_word_metadata_str("giants")
+        );
+        assert_eq!(
+            compiled.fuzzy_match_str("helo", 2, 10).len(),
+            dict.fuzzy_match_str("helo", 2, 10).len()
+        );
+    }
+
+    #[test]
+    fn loads_compiled_from_disk() {
+        let dict = FstDictionary::from_hunspell("SFX S Y 1\nSFX S 0 s .\n", "2\ngiant/S\nhello\n")
+            .unwrap();
+
+        let dir = std::env::temp_dir().join(format!("harper-compiled-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let fst_path = dir.join("words.fst");
+        let metadata_path = dir.join("words.meta");
+
+        dict.write_compiled(
+            std::fs::File::create(&fst_path).unwrap(),
+            std::fs::File::create(&metadata_path).unwrap(),
+        )
+        .unwrap();
+
+        let loaded = FstDictionary::load_compiled(&fst_path, &metadata_path).unwrap();
+        let read = FstDictionary::read_compiled(&fst_path, &metadata_path).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        for loaded in [loaded, read] {
+            assert!(loaded == dict);
+            assert!(loaded.contains_word_str("giants"));
+            assert!(loaded.contains_word_str("Hello"));
+            assert!(!loaded.contains_word_str("hellos"));
+            assert_eq!(loaded.words_iter().count(), 3);
+        }
+    }
+
+    #[test]
+    fn curated_is_compiled_from_sources() {
+        for dialect in [
+            Dialect::American,
+            Dialect::British,
+            Dialect::Australian,
+            Dialect::Canadian,
+        ] {
+            assert!(
+                FstDictionary::compile_curated(dialect) == *FstDictionary::curated_dialect(dialect),
+                "The {dialect:?} dictionary compiled by the build script doesn't match its sources."
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_mismatched_metadata() {
+        let mut fst = Vec::new();
+        let mut metadata = Vec::new();
+        FstDictionary::curated()
+            .write_compiled(&mut fst, &mut metadata)
+            .unwrap();
+
+        let mut other_metadata = Vec::new();
+        FstDictionary::new(HashMap::from([(
+            "hello".chars().collect(),
+            WordMetadata::default(),
+        )]))
+        .write_compiled(Vec::new(), &mut other_metadata)
+        .unwrap();
+
+        assert!(matches!(
+            FstDictionary::from_compiled(fst, &other_metadata),
+            Err(CompiledDictionaryError::Mismatched)
+        ));
+    }
+
+    #[test]
+    fn rejects_metadata_of_another_fst_with_as_many_words() {
+        let compile = |words: &[&str]| {
+            let mut fst = Vec::new();
+            let mut metadata = Vec::new();
+            FstDictionary::new(
+                words
+                    .iter()
+                    .map(|word| (word.chars().collect(), WordMetadata::default()))
+                    .collect(),
+            )
+            .write_compiled(&mut fst, &mut metadata)
+            .unwrap();
+            (fst, metadata)
+        };
+
+        let (fst, _) = compile(&["giant", "hello"]);
+        let (_, other_metadata) = compile(&["apple", "zebra"]);
+
+        assert!(matches!(
+            FstDictionary::from_compiled(fst, &other_metadata),
+            Err(CompiledDictionaryError::Mismatched)
+        ));
+    }
+
     #[test]
     fn fuzzy_result_sorted_by_edit_distance() {
         let dict = FstDictionary::curated();
@@ -1,18 +1,21 @@
 use super::{
     edit_distance_min_alloc,
-    hunspell::{parse_default_attribute_list, parse_default_word_list},
-    seq_to_normalized,
+    hunspell::{self, parse_default_attribute_list, parse_default_word_list},
+    inflection::annotate_inflections,
+    seq_to_normalized, HunspellError,
 };
 use hashbrown::HashMap;
 use itertools::Itertools;
 use lazy_static::lazy_static;
 use smallvec::{SmallVec, ToSmallVec};
+use std::path::Path;
 use std::sync::Arc;
 
 use crate::{CharString, CharStringExt, WordMetadata};
 
 use super::dictionary::Dictionary;
 use super::FuzzyMatchResult;
+use super::WordListWarning;
 
 /// A full, fat dictionary.
 /// All elements are stored in-memory.
@@ -42,7 +45,8 @@ fn uncached_inner_new() -> Arc<FullDictionary> {
     // There will be at _least_ this number of words
     let mut word_map = HashMap::with_capacity(word_list.len());
 
-    attr_list.expand_marked_words(word_list, &mut word_map);
+    attr_list.expand_marked_words(word_list.iter().cloned(), &mut word_map);
+    annotate_inflections(&mut word_map, &word_list);
 
     let mut words: Vec<CharString> = word_map.iter().map(|(v, _)| v.clone()).collect();
 
@@ -77,6 +81,47 @@ impl FullDictionary {
         (*DICT).clone()
     }
 
+    /// Create a dictionary from the contents of a Hunspell `.aff` file and
+    /// the `.dic` file that uses it.
+    pub fn from_hunspell(aff: &str, dic: &str) -> Result<Self, HunspellError> {
+        let mut dict = Self::new();
+        dict.extend_words(hunspell::parse_hunspell(aff, dic)?);
+        Ok(dict)
+    }
+
+    /// Create a dictionary from a line-separated word list.
+    ///
+    /// Each word may be marked with flags from the curated dictionary's affix
+    /// list, to also accept its other forms, and followed by part-of-speech
+    /// fields:
+    ///
+    /// ```text
+    /// kubelet/SM
+    /// Kubernetes po:proper_noun
+    /// TCP\/IP
+    /// ```
+    ///
+    /// Slashes that are part of a word are escaped with a backslash.
+    /// Entries with unknown flags or parts of speech are read as well as they
+    /// can be, and reported alongside the dictionary.
+    pub fn from_word_list(source: &str) -> (Self, Vec<WordListWarning>) {
+        let (words, warnings) = hunspell::expand_annotated_word_list(source);
+
+        let mut dict = Self::new();
+        dict.extend_words(words);
+        (dict, warnings)
+    }
+
+    /// Read a Hunspell dictionary from its `.aff` and `.dic` files.
+    pub fn from_hunspell_files(
+        aff_path: impl AsRef<Path>,
+        dic_path: impl AsRef<Path>,
+    ) -> Result<Self, HunspellError> {
+        let mut dict = Self::new();
+        dict.extend_words(hunspell::read_hunspell(aff_path, dic_path)?);
+        Ok(dict)
+    }
+
     /// Appends words to the dictionary.
     /// It is significantly faster to append many words with one call than many
     /// distinct calls to this function.
@@ -174,7 +219,7 @@ impl Dictionary for FullDictionary {
         word: &[char],
         max_distance: u8,
         max_results: usize,
-    ) -> Vec<FuzzyMatchResult> {
+    ) -> Vec<FuzzyMatchResult<'_>> {
         let misspelled_charslice = seq_to_normalized(word);
         let misspelled_charslice_lower = misspelled_charslice.to_lower();
 
@@ -229,7 +274,7 @@ impl Dictionary for FullDictionary {
         word: &str,
         max_distance: u8,
         max_results: usize,
-    ) -> Vec<FuzzyMatchResult> {
+    ) -> Vec<FuzzyMatchResult<'_>> {
         let word: Vec<_> = word.chars().collect();
         self.fuzzy_match(&word, max_distance, max_results)
     }
@@ -0,0 +1,261 @@
+use hashbrown::HashMap;
+
+use super::affix_replacement::AffixReplacement;
+use super::attribute_list::AttributeList;
+use super::expansion::Expansion;
+use super::matcher::Matcher;
+use super::Error;
+use crate::WordMetadata;
+
+/// [`AttributeList`] keys affixes by a single `char`, so flags written with
+/// more than one character are mapped onto characters from this private use
+/// area instead.
+const FIRST_INTERNED_FLAG: u32 = 0xF0000;
+
+/// How flags are written, as set by the `FLAG` directive.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+enum FlagFormat {
+    /// Each character is a flag.
+    #[default]
+    Char,
+    /// Each pair of characters is a flag.
+    Long,
+    /// Flags are comma-separated numbers.
+    Numeric,
+}
+
+/// A Hunspell `.aff` file.
+///
+/// Only the directives needed to expand a word list are read: `FLAG`, `AF`,
+/// `PFX` and `SFX`. Everything else (suggestion tables, compounding rules,
+/// etc.) is ignored.
+#[derive(Debug, Clone)]
+pub struct AffixFile {
+    pub attributes: AttributeList,
+    flag_format: FlagFormat,
+    /// Flag vectors defined with the `AF` directive.
+    /// Alias `n` is stored at index `n - 1`.
+    aliases: Vec<Vec<char>>,
+    /// The characters long and numeric flags have been mapped onto.
+    interned: HashMap<String, char>,
+}
+
+impl AffixFile {
+    pub fn parse(source: &str) -> Result<Self, Error> {
+        let mut file = Self {
+            attributes: AttributeList::new_hunspell(HashMap::new()),
+            flag_format: FlagFormat::default(),
+            aliases: Vec::new(),
+            interned: HashMap::new(),
+        };
+
+        let mut affixes: HashMap<char, Expansion> = HashMap::new();
+        let mut seen_alias_count = false;
+
+        for (index, line) in source.lines().enumerate() {
+            let line_number = index + 1;
+            let fields: Vec<&str> = line.split_whitespace().collect();
+
+            let Some(directive) = fields.first() else {
+                continue;
+            };
+
+            match directive.trim_start_matches('\u{feff}') {
+                "FLAG" => {
+                    file.flag_format = match fields.get(1) {
+                        Some(&"long") => FlagFormat::Long,
+                        Some(&"num") => FlagFormat::Numeric,
+                        Some(&"UTF-8") => FlagFormat::Char,
+                        Some(other) => return Err(Error::UnknownFlagType(other.to_string())),
+                        None => return Err(Error::UnexpectedEndOfLine),
+                    }
+                }
+                "AF" => {
+                    let value = fields.get(1).ok_or(Error::UnexpectedEndOfLine)?;
+
+                    // The first `AF` line gives the number of aliases that follow.
+                    if !seen_alias_count {
+                        seen_alias_count = true;
+                        continue;
+                    }
+
+                    let flags = file.parse_flags(value)?;
+                    file.aliases.push(flags);
+                }
+                kind @ ("PFX" | "SFX") => {
+                    let flag = fields
+                        .get(1)
+                        .ok_or(Error::MalformedAffix { line: line_number })?;
+                    let flag = file.parse_flag(flag)?;
+
+                    match affixes.get_mut(&flag) {
+                        // The first line of an affix is its header:
+                        // `SFX flag cross_product count`
+                        None => {
+                            let cross_product = match fields.get(2) {
+                                Some(&"Y") => true,
+                                Some(&"N") => false,
+                                _ => return Err(Error::ExpectedBoolean),
+                            };
+
+                            affixes.insert(
+                                flag,
+                                Expansion {
+                                    suffix: kind == "SFX",
+                                    cross_product,
+                                    replacements: Vec::new(),
+                                    adds_metadata: WordMetadata::default(),
+                                    gifts_metadata: WordMetadata::default(),
+                                },
+                            );
+                        }
+                        // Each line after it is a rule:
+                        // `SFX flag strip add[/continuation] [condition]`
+                        Some(expansion) => {
+                            let (Some(strip), Some(add)) = (fields.get(2), fields.get(3)) else {
+                                return Err(Error::MalformedAffix { line: line_number });
+                            };
+
+                            let (add, continuation) = match add.split_once('/') {
+                                Some((add, flags)) => (add, file.parse_word_flags(flags)?),
+                                None => (*add, Vec::new()),
+                            };
+                            let condition = fields.get(4).copied().unwrap_or(".");
+
+                            expansion.replacements.push(AffixReplacement {
+                                remove: parse_affix_text(strip),
+                                add: parse_affix_text(add),
+                                condition: Matcher::parse(condition)?,
+                                continuation,
+                            });
+                        }
+                    }
+                }
+                _ => (),
+            }
+        }
+
+        file.attributes = AttributeList::new_hunspell(affixes);
+
+        Ok(file)
+    }
+
+    /// Parse the flags of a word in the `.dic` file, which may be an alias
+    /// defined with the `AF` directive.
+    pub fn parse_word_flags(&mut self, flags: &str) -> Result<Vec<char>, Error> {
+        if self.aliases.is_empty() || flags.is_empty() {
+            return self.parse_flags(flags);
+        }
+
+        let alias: usize = flags
+            .parse()
+            .map_err(|_| Error::MalformedFlag(flags.to_string()))?;
+
+        alias
+            .checked_sub(1)
+            .and_then(|index| self.aliases.get(index))
+            .cloned()
+            .ok_or(Error::UnknownFlagAlias(alias))
+    }
+
+    fn parse_flag(&mut self, flag: &str) -> Result<char, Error> {
+        match self.parse_flags(flag)?.as_slice() {
+            [flag] => Ok(*flag),
+            _ => Err(Error::MalformedFlag(flag.to_string())),
+        }
+    }
+
+    fn parse_flags(&mut self, flags: &str) -> Result<Vec<char>, Error> {
+        match self.flag_format {
+            FlagFormat::Char => Ok(flags.chars().collect()),
+            FlagFormat::Long => {
+                let chars: Vec<char> = flags.chars().collect();
+
+                if !chars.len().is_multiple_of(2) {
+                    return Err(Error::MalformedFlag(flags.to_string()));
+                }
+
+                Ok(chars
+                    .chunks(2)
+                    .map(|pair| self.intern(pair.iter().collect()))
+                    .collect())
+            }
+            FlagFormat::Numeric => flags
+                .split(',')
+                .map(|flag| {
+                    flag.parse::<u16>()
+                        .map(|n| self.intern(n.to_string()))
+                        .map_err(|_| Error::MalformedFlag(flags.to_string()))
+                })
+                .collect(),
+        }
+    }
+
+    fn intern(&mut self, flag: String) -> char {
+        let next = FIRST_INTERNED_FLAG + self.interned.len() as u32;
+
+        *self
+            .interned
+            .entry(flag)
+            .or_insert_with(|| char::from_u32(next).unwrap())
+    }
+}
+
+/// Hunspell writes an empty strip or affix as `0`.
+fn parse_affix_text(text: &str) -> Vec<char> {
+    if text == "0" {
+        Vec::new()
+    } else {
+        text.chars().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AffixFile;
+    use crate::spell::hunspell::Error;
+
+    #[test]
+    fn parses_long_flags() {
+        let mut file = AffixFile::parse("FLAG long\nSFX Aa Y 1\nSFX Aa 0 s .").unwrap();
+
+        let flags = file.parse_word_flags("AaBb").unwrap();
+        assert_eq!(flags.len(), 2);
+        assert_eq!(flags, file.parse_word_flags("AaBb").unwrap());
+        assert!(matches!(
+            file.parse_word_flags("Aab"),
+            Err(Error::MalformedFlag(_))
+        ));
+    }
+
+    #[test]
+    fn parses_numeric_flags() {
+        let mut file = AffixFile::parse("FLAG num\nSFX 101 Y 1\nSFX 101 0 s .").unwrap();
+
+        assert_eq!(file.parse_word_flags("101,7").unwrap().len(), 2);
+        assert_eq!(
+            file.parse_word_flags("101").unwrap(),
+            file.parse_word_flags("101").unwrap()
+        );
+    }
+
+    #[test]
+    fn resolves_aliases() {
+        let mut file = AffixFile::parse("AF 2\nAF AB\nAF C").unwrap();
+
+        assert_eq!(file.parse_word_flags("1").unwrap(), vec!['A', 'B']);
+        assert_eq!(file.parse_word_flags("2").unwrap(), vec!['C']);
+        assert!(matches!(
+            file.parse_word_flags("3"),
+            Err(Error::UnknownFlagAlias(3))
+        ));
+    }
+
+    #[test]
+    fn rejects_malformed_rules() {
+        assert!(matches!(
+            AffixFile::parse("SFX A Y 1\nSFX A 0"),
+            Err(Error::MalformedAffix { line: 2 })
+        ));
+    }
+}
@@ -8,6 +8,9 @@ pub struct AffixReplacement {
     pub remove: Vec<char>,
     pub add: Vec<char>,
     pub condition: Matcher,
+    /// Flags for further affixes that can be applied to the word this
+    /// replacement makes (Hunspell's continuation classes).
+    pub continuation: Vec<char>,
 }
 
 impl AffixReplacement {
@@ -16,6 +19,7 @@ impl AffixReplacement {
             remove: self.remove.iter().collect(),
             add: self.add.iter().collect(),
             condition: self.condition.to_string(),
+            continuation: self.continuation.iter().collect(),
         }
     }
 }
@@ -27,6 +31,8 @@ pub struct HumanReadableAffixReplacement {
     pub remove: String,
     pub add: String,
     pub condition: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub continuation: String,
 }
 
 impl HumanReadableAffixReplacement {
@@ -35,6 +41,7 @@ impl HumanReadableAffixReplacement {
             remove: self.remove.chars().collect(),
             add: self.add.chars().collect(),
             condition: Matcher::parse(&self.condition)?,
+            continuation: self.continuation.chars().collect(),
         })
     }
 }
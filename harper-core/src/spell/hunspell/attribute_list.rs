@@ -12,9 +12,34 @@ use crate::{CharString, Span, WordMetadata};
 pub struct AttributeList {
     /// Key = Affix Flag
     affixes: HashMap<char, Expansion>,
+    /// Whether, like Hunspell, a prefix and suffix are only combined when
+    /// both allow cross products, rather than when the one applied first
+    /// does. Harper's own affix list is written for the latter.
+    strict_cross_products: bool,
 }
 
 impl AttributeList {
+    pub(super) fn new(affixes: HashMap<char, Expansion>) -> Self {
+        Self {
+            affixes,
+            strict_cross_products: false,
+        }
+    }
+
+    /// Create a list that combines affixes the way Hunspell does, for
+    /// dictionaries written for it.
+    pub(super) fn new_hunspell(affixes: HashMap<char, Expansion>) -> Self {
+        Self {
+            affixes,
+            strict_cross_products: true,
+        }
+    }
+
+    /// Whether `flag` marks one of the affixes in the list.
+    pub(crate) fn has_flag(&self, flag: char) -> bool {
+        self.affixes.contains_key(&flag)
+    }
+
     pub fn into_human_readable(self) -> HumanReadableAttributeList {
         HumanReadableAttributeList {
             affixes: self
@@ -38,37 +63,44 @@ impl AttributeList {
         dest.reserve(word.attributes.len() + 1);
         let mut gifted_metadata = WordMetadata::default();
 
+        // The parts of speech of the word itself, which decide what its
+        // inflections are.
+        let mut parent_metadata = dest.get(&word.letters).copied().unwrap_or_default();
+        for expansion in word.attributes.iter().filter_map(|a| self.affixes.get(a)) {
+            parent_metadata.append(&expansion.gifts_metadata);
+        }
+
         for attr in &word.attributes {
             let Some(expansion) = self.affixes.get(attr) else {
                 continue;
             };
 
             gifted_metadata.append(&expansion.gifts_metadata);
+            let adds_metadata = expansion.adds_metadata.inflecting(&parent_metadata);
             let mut new_words: HashMap<CharString, WordMetadata> = HashMap::new();
+            let mut continued = Vec::new();
 
             for replacement in &expansion.replacements {
                 if let Some(replaced) =
                     Self::apply_replacement(replacement, &word.letters, expansion.suffix)
                 {
+                    if !replacement.continuation.is_empty() {
+                        continued.push(MarkedWord {
+                            letters: replaced.clone(),
+                            attributes: replacement.continuation.clone(),
+                        });
+                    }
+
                     if let Some(val) = new_words.get_mut(&replaced) {
-                        val.append(&expansion.adds_metadata);
+                        val.append(&adds_metadata);
                     } else {
-                        new_words.insert(replaced, expansion.adds_metadata);
+                        new_words.insert(replaced, adds_metadata);
                     }
                 }
             }
 
             if expansion.cross_product {
-                let mut opp_attr = Vec::new();
-
-                for attr in &word.attributes {
-                    let Some(attr_def) = self.affixes.get(attr) else {
-                        continue;
-                    };
-                    if attr_def.suffix != expansion.suffix {
-                        opp_attr.push(*attr);
-                    }
-                }
+                let opp_attr = self.cross_product_attributes(&word.attributes, expansion);
 
                 for (new_word, metadata) in new_words {
                     self.expand_marked_word(
@@ -90,6 +122,10 @@ impl AttributeList {
                     }
                 }
             }
+
+            for continued_word in continued {
+                self.expand_marked_word(continued_word, dest);
+            }
         }
 
         if let Some(prev_val) = dest.get(&word.letters) {
@@ -99,6 +135,70 @@ impl AttributeList {
         }
     }
 
+    /// Expand a [`MarkedWord`] alongside another spelling of the same word,
+    /// which takes the same affixes.
+    ///
+    /// Each form of `word` (including itself) is appended to `dest`, paired
+    /// with the equivalent form of `respelling`.
+    pub fn expand_respelled_word(
+        &self,
+        word: &MarkedWord,
+        respelling: &[char],
+        dest: &mut Vec<(CharString, CharString)>,
+    ) {
+        dest.push((word.letters.clone(), respelling.to_smallvec()));
+
+        for attr in &word.attributes {
+            let Some(expansion) = self.affixes.get(attr) else {
+                continue;
+            };
+
+            // The spellings may end differently, so they won't always meet
+            // the same conditions. Forms are paired when the same replacement
+            // applies to both spellings. Otherwise, they are only paired when
+            // each spelling has a single form left, like "centered" and
+            // "centred".
+            let mut pairs = Vec::new();
+            let mut word_only = Vec::new();
+            let mut respelling_only = Vec::new();
+
+            for replacement in &expansion.replacements {
+                match (
+                    Self::apply_replacement(replacement, &word.letters, expansion.suffix),
+                    Self::apply_replacement(replacement, respelling, expansion.suffix),
+                ) {
+                    (Some(new_word), Some(new_respelling)) => {
+                        pairs.push((new_word, new_respelling))
+                    }
+                    (Some(new_word), None) => word_only.push(new_word),
+                    (None, Some(new_respelling)) => respelling_only.push(new_respelling),
+                    (None, None) => (),
+                }
+            }
+
+            if let ([new_word], [new_respelling]) = (&word_only[..], &respelling_only[..]) {
+                pairs.push((new_word.clone(), new_respelling.clone()));
+            }
+
+            if expansion.cross_product {
+                let opp_attr = self.cross_product_attributes(&word.attributes, expansion);
+
+                for (new_word, new_respelling) in pairs {
+                    self.expand_respelled_word(
+                        &MarkedWord {
+                            letters: new_word,
+                            attributes: opp_attr.clone(),
+                        },
+                        &new_respelling,
+                        dest,
+                    );
+                }
+            } else {
+                dest.extend(pairs);
+            }
+        }
+    }
+
     /// Expand an iterator of marked words into strings.
     /// Note that this does __not__ guarantee that produced words will be
     /// unique.
@@ -112,6 +212,72 @@ impl AttributeList {
         }
     }
 
+    /// Whether `attribute` is a suffix that adds metadata matching `adds`.
+    pub(crate) fn is_suffix_adding(
+        &self,
+        attribute: char,
+        adds: impl Fn(&WordMetadata) -> bool,
+    ) -> bool {
+        self.affixes
+            .get(&attribute)
+            .is_some_and(|expansion| expansion.suffix && adds(&expansion.adds_metadata))
+    }
+
+    /// Every word the suffixes that add metadata matching `adds` would make
+    /// of `letters`, whether or not it is marked with them.
+    pub(crate) fn inflect(
+        &self,
+        letters: &[char],
+        adds: impl Fn(&WordMetadata) -> bool,
+    ) -> Vec<CharString> {
+        self.affixes
+            .values()
+            .filter(|expansion| expansion.suffix && adds(&expansion.adds_metadata))
+            .flat_map(|expansion| &expansion.replacements)
+            .filter_map(|replacement| Self::apply_replacement(replacement, letters, true))
+            .collect()
+    }
+
+    /// The words that the suffixes that add metadata matching `adds` would
+    /// turn into `word`. The opposite of [`Self::inflect`].
+    pub(crate) fn uninflect(
+        &self,
+        word: &[char],
+        adds: impl Fn(&WordMetadata) -> bool,
+    ) -> Vec<CharString> {
+        self.affixes
+            .values()
+            .filter(|expansion| expansion.suffix && adds(&expansion.adds_metadata))
+            .flat_map(|expansion| &expansion.replacements)
+            .filter_map(|replacement| {
+                let stem = word.strip_suffix(replacement.add.as_slice())?;
+
+                let mut stem = stem.to_smallvec();
+                stem.extend_from_slice(&replacement.remove);
+
+                // The replacement must actually apply to the stem.
+                (Self::apply_replacement(replacement, &stem, true).as_deref() == Some(word))
+                    .then_some(stem)
+            })
+            .collect()
+    }
+
+    /// The attributes that can be combined with a word produced by
+    /// `expansion`: affixes on the opposite end of the word that, if
+    /// [`Self::strict_cross_products`] is set, also allow cross products.
+    fn cross_product_attributes(&self, attributes: &[char], expansion: &Expansion) -> Vec<char> {
+        attributes
+            .iter()
+            .filter(|attr| {
+                self.affixes.get(*attr).is_some_and(|def| {
+                    def.suffix != expansion.suffix
+                        && (def.cross_product || !self.strict_cross_products)
+                })
+            })
+            .copied()
+            .collect()
+    }
+
     fn apply_replacement(
         replacement: &AffixReplacement,
         letters: &[char],
@@ -181,6 +347,6 @@ impl HumanReadableAttributeList {
             affixes.insert(affix, expansion.into_normal()?);
         }
 
-        Ok(AttributeList { affixes })
+        Ok(AttributeList::new(affixes))
     }
 }
@@ -1,6 +1,6 @@
 use super::matcher;
 
-#[derive(Debug, Clone, Copy, thiserror::Error)]
+#[derive(Debug, thiserror::Error)]
 pub enum Error {
     #[error("The provided file's item count was malformed.")]
     MalformedItemCount,
@@ -14,4 +14,18 @@ pub enum Error {
     UnexpectedEndOfLine,
     #[error("An error occured with a condition: {0}")]
     Matcher(#[from] matcher::Error),
+    #[error("Malformed affix rule on line {line} of the affix file.")]
+    MalformedAffix { line: usize },
+    #[error("Unknown flag type “{0}”. Expected “long”, “num” or “UTF-8”.")]
+    UnknownFlagType(String),
+    #[error("Malformed flag “{0}”.")]
+    MalformedFlag(String),
+    #[error("Flag alias {0} is not defined in the affix file.")]
+    UnknownFlagAlias(usize),
+    #[error("Unsupported character encoding “{0}”. Expected “UTF-8” or “ISO8859-1”.")]
+    UnsupportedEncoding(String),
+    #[error("The dictionary is not valid UTF-8.")]
+    InvalidUtf8,
+    #[error("Unable to read dictionary: {0}")]
+    Io(#[from] std::io::Error),
 }
@@ -1,3 +1,4 @@
+mod aff_file;
 mod affix_replacement;
 mod attribute_list;
 mod error;
@@ -5,12 +6,18 @@ mod expansion;
 mod matcher;
 mod word_list;
 
+use std::path::Path;
+
+use hashbrown::HashMap;
+
+use aff_file::AffixFile;
 pub use attribute_list::AttributeList;
 use attribute_list::HumanReadableAttributeList;
 pub use error::Error;
 
-use self::word_list::parse_word_list;
-pub use self::word_list::MarkedWord;
+use self::word_list::{parse_annotated_word_list, parse_dic_file, parse_word_list};
+pub use self::word_list::{MarkedWord, WordListWarning};
+use crate::{CharString, WordMetadata};
 
 pub fn parse_default_word_list() -> Result<Vec<MarkedWord>, Error> {
     parse_word_list(include_str!("../../../dictionary.dict"))
@@ -26,15 +33,108 @@ pub fn parse_default_attribute_list() -> AttributeList {
         .expect("All expressions in the built-in attribute list should be valid.")
 }
 
+/// Parse a Hunspell dictionary from the contents of its `.aff` and `.dic`
+/// files, expanding it into every word it accepts.
+pub fn parse_hunspell(aff: &str, dic: &str) -> Result<HashMap<CharString, WordMetadata>, Error> {
+    let mut affix_file = AffixFile::parse(aff)?;
+    let words = parse_dic_file(dic, &mut affix_file)?;
+
+    let mut expanded = HashMap::with_capacity(words.len());
+    affix_file
+        .attributes
+        .expand_marked_words(words, &mut expanded);
+
+    Ok(expanded)
+}
+
+/// Parse a word list whose words may be marked with flags from the curated
+/// affix list and part-of-speech fields (e.g. `kubelet/SM po:noun`),
+/// expanding it into every word it accepts.
+///
+/// Entries with unknown flags or parts of speech are read as well as they
+/// can be, and reported along with the words.
+pub fn expand_annotated_word_list(
+    source: &str,
+) -> (HashMap<CharString, WordMetadata>, Vec<WordListWarning>) {
+    let attributes = parse_default_attribute_list();
+    let mut expanded = HashMap::new();
+
+    let (words, warnings) = parse_annotated_word_list(source, |flag| attributes.has_flag(flag));
+
+    for (word, metadata) in words {
+        // The part of speech describes the word itself, not its derived forms,
+        // but does decide which inflections they are.
+        expanded
+            .entry(word.letters.clone())
+            .or_insert_with(WordMetadata::default)
+            .append(&metadata);
+
+        attributes.expand_marked_word(word, &mut expanded);
+    }
+
+    (expanded, warnings)
+}
+
+/// Read a Hunspell dictionary from disk, expanding it into every word it
+/// accepts.
+///
+/// Both files are decoded using the encoding named by the `SET` directive of
+/// the `.aff` file.
+pub fn read_hunspell(
+    aff_path: impl AsRef<Path>,
+    dic_path: impl AsRef<Path>,
+) -> Result<HashMap<CharString, WordMetadata>, Error> {
+    let aff = std::fs::read(aff_path)?;
+    let dic = std::fs::read(dic_path)?;
+
+    let encoding = declared_encoding(&aff);
+
+    parse_hunspell(
+        &decode(&aff, encoding.as_deref())?,
+        &decode(&dic, encoding.as_deref())?,
+    )
+}
+
+/// Find the encoding named by the `SET` directive of an `.aff` file.
+fn declared_encoding(aff: &[u8]) -> Option<String> {
+    aff.split(|b| *b == b'\n').find_map(|line| {
+        let line = String::from_utf8_lossy(line);
+        let mut fields = line.split_whitespace();
+
+        match fields.next() {
+            Some("SET") => fields.next().map(str::to_string),
+            _ => None,
+        }
+    })
+}
+
+/// Decode a dictionary file.
+/// Without a declared encoding, UTF-8 is tried before ISO 8859-1, which is
+/// Hunspell's default.
+fn decode(bytes: &[u8], encoding: Option<&str>) -> Result<String, Error> {
+    let latin1 = || bytes.iter().map(|b| char::from(*b)).collect();
+
+    match encoding.map(str::to_ascii_uppercase).as_deref() {
+        Some("UTF-8" | "UTF8") => String::from_utf8(bytes.to_vec()).map_err(|_| Error::InvalidUtf8),
+        Some("ISO8859-1" | "ISO-8859-1" | "LATIN1") => Ok(latin1()),
+        Some(_) => Err(Error::UnsupportedEncoding(encoding.unwrap().to_string())),
+        None => Ok(String::from_utf8(bytes.to_vec()).unwrap_or_else(|_| latin1())),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use hashbrown::{HashMap, HashSet};
     use serde_json::json;
 
+    use super::aff_file::AffixFile;
     use super::word_list::parse_word_list;
-    use super::{parse_default_attribute_list, parse_default_word_list};
+    use super::{
+        decode, expand_annotated_word_list, parse_default_attribute_list, parse_default_word_list,
+        parse_hunspell, MarkedWord,
+    };
     use crate::spell::hunspell::attribute_list::HumanReadableAttributeList;
-    use crate::{CharString, WordMetadata};
+    use crate::{CharString, VerbForm, WordMetadata};
 
     pub const TEST_WORD_LIST: &str = "3\nhello\ntry/B\nwork/AB";
 
@@ -182,6 +282,22 @@ mod tests {
         build_expanded();
     }
 
+    /// Unlike Hunspell files, the curated affix list combines a prefix and a
+    /// suffix whenever the one applied first allows it.
+    #[test]
+    fn curated_cross_products_need_one_affix() {
+        let expanded = build_expanded();
+
+        // Words get added over time, but a smaller dictionary means the
+        // affixes have stopped combining the way they should.
+        assert!(expanded.contains_key(&split("unworldliest")));
+        assert!(
+            expanded.len() >= 124_243,
+            "The curated dictionary shrank to {} words.",
+            expanded.len()
+        );
+    }
+
     #[test]
     fn expanded_contains_giants() {
         assert!(build_expanded().contains_key(&split("giants")));
@@ -219,6 +335,19 @@ mod tests {
         assert!(!has.unwrap().is_noun(),)
     }
 
+    #[test]
+    fn giants_is_not_a_verb() {
+        let expanded = build_expanded();
+
+        let giants = expanded.get(&split("giants")).unwrap();
+        assert!(giants.is_plural_noun());
+        assert!(!giants.is_verb());
+
+        let walks = expanded.get(&split("walks")).unwrap();
+        assert!(walks.is_plural_noun());
+        assert!(walks.is_verb_form(VerbForm::ThirdPersonSingular));
+    }
+
     #[test]
     fn is_is_linking_verb() {
         let expanded = build_expanded();
@@ -233,4 +362,170 @@ mod tests {
     fn split(text: &str) -> CharString {
         text.chars().collect()
     }
+
+    const TEST_AFF: &str = "SET UTF-8
+TRY esianrtolcdugmphbyfvkwz
+
+# Prefixes
+PFX A Y 1
+PFX A 0 re .
+
+PFX I N 2
+PFX I 0 il l
+PFX I 0 in [^l]
+
+# Suffixes
+SFX D Y 4
+SFX D 0 d e
+SFX D y ied [^aeiou]y
+SFX D 0 ed [^ey]
+SFX D 0 ed [aeiou]y
+
+SFX S Y 3
+SFX S y ies [^aeiou]y
+SFX S 0 s [aeiou]y
+SFX S 0 s [^sxzhy]
+";
+
+    const TEST_DIC: &str = "4
+try/D
+play/ADS
+legal/I
+logical/IS	po:adjective
+";
+
+    fn expand_test_dictionary(aff: &str, dic: &str) -> HashSet<String> {
+        parse_hunspell(aff, dic)
+            .unwrap()
+            .into_keys()
+            .map(|v| v.into_iter().collect())
+            .collect()
+    }
+
+    #[test]
+    fn expands_hunspell_files() {
+        let expanded = expand_test_dictionary(TEST_AFF, TEST_DIC);
+
+        assert_eq!(
+            expanded,
+            [
+                "try",
+                "tried",
+                "play",
+                "played",
+                "plays",
+                "replay",
+                "replayed",
+                "replays",
+                "legal",
+                "illegal",
+                "logical",
+                "logicals",
+                "illogical"
+            ]
+            .into_iter()
+            .map(String::from)
+            .collect()
+        )
+    }
+
+    #[test]
+    fn expands_long_flags_and_aliases() {
+        let aff = "FLAG long\nAF 1\nAF AaDd\nPFX Aa Y 1\nPFX Aa 0 re .\nSFX Dd Y 1\nSFX Dd 0 ed .";
+        let expanded = expand_test_dictionary(aff, "1\nwork/1");
+
+        assert!(expanded.contains("work"));
+        assert!(expanded.contains("rework"));
+        assert!(expanded.contains("worked"));
+        assert!(expanded.contains("reworked"));
+    }
+
+    /// Like Hunspell, a prefix and a suffix are only combined when both allow
+    /// cross products.
+    #[test]
+    fn cross_products_need_both_affixes() {
+        let aff = "PFX A Y 1\nPFX A 0 re .\nSFX D N 1\nSFX D 0 ed .\nPFX U N 1\nPFX U 0 un .\nSFX S Y 1\nSFX S 0 s .";
+        let expanded = expand_test_dictionary(aff, "2\nwork/AD\nlock/US");
+
+        assert_eq!(
+            expanded,
+            ["work", "rework", "worked", "lock", "unlock", "locks"]
+                .into_iter()
+                .map(String::from)
+                .collect()
+        );
+    }
+
+    #[test]
+    fn expands_continuation_classes() {
+        let aff = "FLAG long\nSFX Aa Y 1\nSFX Aa 0 able/Ss .\nSFX Ss Y 1\nSFX Ss 0 s .";
+        let expanded = expand_test_dictionary(aff, "1\ndrink/Aa");
+
+        assert_eq!(
+            expanded,
+            ["drink", "drinkable", "drinkables"]
+                .into_iter()
+                .map(String::from)
+                .collect()
+        );
+    }
+
+    /// Each form of a respelled word is paired with the form that the same
+    /// replacement makes of the respelling.
+    #[test]
+    fn pairs_respelled_forms_by_replacement() {
+        let affix_file = AffixFile::parse("SFX S Y 2\nSFX S 0 ful [^u]r\nSFX S 0 s .").unwrap();
+
+        let mut pairs = Vec::new();
+        affix_file.attributes.expand_respelled_word(
+            &MarkedWord {
+                letters: split("color"),
+                attributes: vec!['S'],
+            },
+            &split("colour"),
+            &mut pairs,
+        );
+
+        assert_eq!(
+            pairs,
+            vec![
+                (split("color"), split("colour")),
+                (split("colors"), split("colours"))
+            ]
+        );
+    }
+
+    #[test]
+    fn decodes_latin1() {
+        assert_eq!(decode(b"caf\xe9", Some("ISO8859-1")).unwrap(), "caf\u{e9}");
+        assert_eq!(decode(b"caf\xe9", None).unwrap(), "caf\u{e9}");
+        assert!(decode(b"caf\xe9", Some("UTF-8")).is_err());
+    }
+
+    #[test]
+    fn expands_annotated_word_lists() {
+        let (expanded, warnings) =
+            expand_annotated_word_list("kubelet/SM po:noun\nKubernetes po:proper_noun");
+
+        assert!(warnings.is_empty());
+
+        assert!(expanded.get(&split("kubelet")).unwrap().is_noun());
+        assert!(expanded.get(&split("kubelets")).unwrap().is_plural_noun());
+        assert!(expanded
+            .get(&split("kubelet's"))
+            .unwrap()
+            .is_possessive_noun());
+        assert!(expanded.get(&split("Kubernetes")).unwrap().is_proper_noun());
+    }
+
+    #[test]
+    fn keeps_slashes_in_annotated_words() {
+        let (expanded, warnings) = expand_annotated_word_list("TCP\\/IP\nand/or");
+
+        let mut words: Vec<String> = expanded.keys().map(|word| word.iter().collect()).collect();
+        words.sort();
+
+        assert_eq!(words, ["TCP/IP", "and/or"]);
+        assert_eq!(warnings.len(), 1);
+    }
 }
@@ -1,5 +1,7 @@
+use super::aff_file::AffixFile;
 use super::Error;
-use crate::CharString;
+use crate::word_metadata::{AdjectiveData, AdverbData, ConjunctionData};
+use crate::{CharString, NounData, VerbData, WordMetadata};
 
 #[derive(Debug, Clone)]
 pub struct MarkedWord {
@@ -38,10 +40,210 @@ pub fn parse_word_list(source: &str) -> Result<Vec<MarkedWord>, Error> {
     Ok(words)
 }
 
+/// Parse the word list of a Hunspell `.dic` file, reading its flags the way
+/// the accompanying `.aff` file describes.
+pub fn parse_dic_file(source: &str, affix_file: &mut AffixFile) -> Result<Vec<MarkedWord>, Error> {
+    let mut lines = source.trim_start_matches('\u{feff}').lines();
+
+    let approx_item_count = lines
+        .next()
+        .ok_or(Error::MalformedItemCount)?
+        .trim()
+        .parse()
+        .map_err(|_| Error::MalformedItemCount)?;
+
+    let mut words = Vec::with_capacity(approx_item_count);
+
+    for line in lines {
+        // Lines starting with a tab are comments.
+        if line.starts_with('\t') {
+            continue;
+        }
+
+        // Anything after the first whitespace is morphological data.
+        let Some(entry) = line.split_whitespace().next() else {
+            continue;
+        };
+
+        let (letters, flags) = split_dic_entry(entry);
+
+        words.push(MarkedWord {
+            letters,
+            attributes: affix_file.parse_word_flags(flags)?,
+        })
+    }
+
+    Ok(words)
+}
+
+/// A problem with an entry of a word list read by
+/// [`parse_annotated_word_list`], which was read as well as it could be.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum WordListWarning {
+    #[error("Line {line}: “{flags}” aren't all affix flags, so “{entry}” was read as a single word. Write `\\/` for a slash that is part of a word.")]
+    UnknownFlags {
+        line: usize,
+        entry: String,
+        flags: String,
+    },
+    #[error("Line {line}: unknown part of speech “{name}”.")]
+    UnknownPartOfSpeech { line: usize, name: String },
+}
+
+/// Parse a word list that doesn't start with an item count, where each word
+/// may be marked with flags from the curated affix list and followed by
+/// part-of-speech fields:
+///
+/// ```text
+/// kubelet/SM
+/// Kubernetes po:proper_noun
+/// TCP\/IP
+/// ```
+///
+/// Slashes that are part of a word are escaped with a backslash. Entries
+/// with a slash that isn't followed by flags that all pass `is_flag`, like
+/// “and/or”, are read as a single word and reported.
+///
+/// The metadata of each word is that given by its part-of-speech fields.
+/// Unrecognized fields are ignored, unless they are `po:` fields with an
+/// unknown part of speech, which are reported.
+pub fn parse_annotated_word_list(
+    source: &str,
+    is_flag: impl Fn(char) -> bool,
+) -> (Vec<(MarkedWord, WordMetadata)>, Vec<WordListWarning>) {
+    let mut words = Vec::new();
+    let mut warnings = Vec::new();
+
+    for (index, line) in source.lines().enumerate() {
+        let mut fields = line.split_whitespace();
+
+        let Some(entry) = fields.next() else {
+            continue;
+        };
+
+        let (mut letters, mut flags) = split_dic_entry(entry);
+
+        if !flags.chars().all(&is_flag) {
+            warnings.push(WordListWarning::UnknownFlags {
+                line: index + 1,
+                entry: entry.to_string(),
+                flags: flags.to_string(),
+            });
+
+            letters.push('/');
+            letters.extend(flags.chars());
+            flags = "";
+        }
+
+        let mut metadata = WordMetadata::default();
+
+        for name in fields.filter_map(|field| field.strip_prefix("po:")) {
+            match part_of_speech(name) {
+                Some(part_of_speech) => metadata = metadata.or(&part_of_speech),
+                None => warnings.push(WordListWarning::UnknownPartOfSpeech {
+                    line: index + 1,
+                    name: name.to_string(),
+                }),
+            }
+        }
+
+        words.push((
+            MarkedWord {
+                letters,
+                attributes: flags.chars().collect(),
+            },
+            metadata,
+        ));
+    }
+
+    (words, warnings)
+}
+
+/// The metadata implied by the value of a `po:` field.
+fn part_of_speech(name: &str) -> Option<WordMetadata> {
+    let noun = |is_proper, is_plural, is_pronoun| NounData {
+        is_proper: Some(is_proper),
+        is_plural: Some(is_plural),
+        is_possessive: None,
+        is_pronoun: Some(is_pronoun),
+    };
+
+    let verb = |is_linking| VerbData {
+        is_linking: Some(is_linking),
+        ..Default::default()
+    };
+
+    let metadata = match name {
+        "noun" => WordMetadata {
+            noun: Some(noun(false, false, false)),
+            ..Default::default()
+        },
+        "proper_noun" => WordMetadata {
+            noun: Some(noun(true, false, false)),
+            ..Default::default()
+        },
+        "plural_noun" => WordMetadata {
+            noun: Some(noun(false, true, false)),
+            ..Default::default()
+        },
+        "pronoun" => WordMetadata {
+            noun: Some(noun(false, false, true)),
+            ..Default::default()
+        },
+        "verb" => WordMetadata {
+            verb: Some(verb(false)),
+            ..Default::default()
+        },
+        "linking_verb" => WordMetadata {
+            verb: Some(verb(true)),
+            ..Default::default()
+        },
+        "adjective" => WordMetadata {
+            adjective: Some(AdjectiveData {}),
+            ..Default::default()
+        },
+        "adverb" => WordMetadata {
+            adverb: Some(AdverbData {}),
+            ..Default::default()
+        },
+        "conjunction" => WordMetadata {
+            conjunction: Some(ConjunctionData {}),
+            ..Default::default()
+        },
+        "swear" => WordMetadata {
+            swear: Some(true),
+            ..Default::default()
+        },
+        _ => return None,
+    };
+
+    Some(metadata)
+}
+
+/// Split a `.dic` entry into its word and flags at the first slash that
+/// hasn't been escaped with a backslash.
+fn split_dic_entry(entry: &str) -> (CharString, &str) {
+    let mut letters = CharString::new();
+    let mut chars = entry.char_indices().peekable();
+
+    while let Some((index, c)) = chars.next() {
+        match c {
+            '\\' if matches!(chars.peek(), Some((_, '/'))) => {
+                letters.push('/');
+                chars.next();
+            }
+            '/' => return (letters, &entry[index + 1..]),
+            _ => letters.push(c),
+        }
+    }
+
+    (letters, "")
+}
+
 #[cfg(test)]
 mod tests {
     use super::super::tests::TEST_WORD_LIST;
-    use super::parse_word_list;
+    use super::{parse_annotated_word_list, parse_word_list, split_dic_entry, WordListWarning};
 
     #[test]
     fn can_parse_test_file() {
@@ -50,4 +252,63 @@ mod tests {
         assert_eq!(list.last().unwrap().attributes.len(), 2);
         assert_eq!(list.len(), 3);
     }
+
+    #[test]
+    fn splits_escaped_dic_entries() {
+        let (letters, flags) = split_dic_entry("and\\/or/SM");
+
+        assert_eq!(letters.iter().collect::<String>(), "and/or");
+        assert_eq!(flags, "SM");
+    }
+
+    #[test]
+    fn parses_annotated_word_lists() {
+        let (list, warnings) = parse_annotated_word_list(
+            "kubelet/SM po:noun\n\nKubernetes po:proper_noun\nyeet",
+            |flag| "SM".contains(flag),
+        );
+
+        assert!(warnings.is_empty());
+        assert_eq!(list.len(), 3);
+        assert_eq!(list[0].0.attributes, vec!['S', 'M']);
+        assert!(list[0].1.is_noun());
+        assert!(list[1].1.is_proper_noun());
+        assert_eq!(list[2].1, Default::default());
+    }
+
+    #[test]
+    fn reads_unknown_flags_as_part_of_the_word() {
+        let (list, warnings) =
+            parse_annotated_word_list("and/or\nTCP\\/IP/S", |flag| "SIP".contains(flag));
+
+        let letters = |index: usize| list[index].0.letters.iter().collect::<String>();
+
+        assert_eq!(letters(0), "and/or");
+        assert!(list[0].0.attributes.is_empty());
+        assert_eq!(letters(1), "TCP/IP");
+        assert_eq!(list[1].0.attributes, vec!['S']);
+        assert_eq!(
+            warnings,
+            vec![WordListWarning::UnknownFlags {
+                line: 1,
+                entry: "and/or".to_string(),
+                flags: "or".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn reports_unknown_parts_of_speech() {
+        let (list, warnings) =
+            parse_annotated_word_list("yeet po:verb po:verbb", |flag| flag == 'S');
+
+        assert!(list[0].1.is_verb());
+        assert_eq!(
+            warnings,
+            vec![WordListWarning::UnknownPartOfSpeech {
+                line: 1,
+                name: "verbb".to_string(),
+            }]
+        );
+    }
 }
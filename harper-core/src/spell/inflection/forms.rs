@@ -0,0 +1,259 @@
+//! The irregular forms of words, and the labeling of the inflected forms in
+//! the curated dictionary.
+//!
+//! This doesn't need a [`Dictionary`](crate::Dictionary), so the build
+//! script can use it to compile the curated dictionary.
+
+use hashbrown::{HashMap, HashSet};
+use lazy_static::lazy_static;
+use serde::Deserialize;
+
+use crate::spell::hunspell::{parse_default_attribute_list, AttributeList, MarkedWord};
+use crate::{CharString, NounData, VerbData, VerbForm, WordMetadata};
+
+/// The forms of a verb that doesn't follow the usual rules.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(super) struct IrregularVerb {
+    pub(super) base: String,
+    third_person_singular: String,
+    past: String,
+    past_participle: String,
+    gerund: String,
+}
+
+impl IrregularVerb {
+    pub(super) fn form(&self, form: VerbForm) -> &str {
+        match form {
+            VerbForm::Base => &self.base,
+            VerbForm::ThirdPersonSingular => &self.third_person_singular,
+            VerbForm::Past => &self.past,
+            VerbForm::PastParticiple => &self.past_participle,
+            VerbForm::Gerund => &self.gerund,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(super) struct IrregularNoun {
+    pub(super) singular: String,
+    pub(super) plural: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(super) struct IrregularForms {
+    pub(super) verbs: Vec<IrregularVerb>,
+    pub(super) nouns: Vec<IrregularNoun>,
+}
+
+/// The irregular forms, indexed by each of their words.
+pub(super) struct IrregularIndex {
+    pub(super) forms: IrregularForms,
+    /// Maps every form of an irregular verb to its index in `forms.verbs`.
+    pub(super) verbs: HashMap<CharString, usize>,
+    /// Maps both forms of an irregular noun to its index in `forms.nouns`.
+    pub(super) nouns: HashMap<CharString, usize>,
+}
+
+fn uncached_irregular_index() -> IrregularIndex {
+    let forms: IrregularForms = serde_json::from_str(include_str!("../../../irregular_forms.json"))
+        .expect("The built-in irregular forms should always be valid.");
+
+    let mut verbs = HashMap::new();
+    for (index, verb) in forms.verbs.iter().enumerate() {
+        for form in VerbForm::ALL {
+            verbs
+                .entry(verb.form(form).chars().collect())
+                .or_insert(index);
+        }
+    }
+
+    let mut nouns = HashMap::new();
+    for (index, noun) in forms.nouns.iter().enumerate() {
+        nouns.insert(noun.singular.chars().collect(), index);
+        nouns.insert(noun.plural.chars().collect(), index);
+    }
+
+    IrregularIndex {
+        forms,
+        verbs,
+        nouns,
+    }
+}
+
+lazy_static! {
+    pub(super) static ref IRREGULAR: IrregularIndex = uncached_irregular_index();
+    pub(super) static ref ATTRIBUTES: AttributeList = parse_default_attribute_list();
+}
+
+/// Label the inflected forms in an expanded word list that the affixes
+/// can't: irregular ones, and ones that double the last letter of the verb
+/// (like “stopped”), which are listed as words of their own. Nouns with a
+/// plural form are labeled singular.
+///
+/// `marked_words` is the word list `words` was expanded from.
+///
+/// Forms that are spelled the same as the base form (like “read”) are left
+/// alone, so the base form isn't mistaken for an inflection.
+pub(crate) fn annotate_inflections(
+    words: &mut HashMap<CharString, WordMetadata>,
+    marked_words: &[MarkedWord],
+) {
+    let verb_form = |form| WordMetadata {
+        verb: Some(VerbData::with_form(form)),
+        ..Default::default()
+    };
+
+    let mut doubled = Vec::new();
+
+    for (word, metadata) in words.iter() {
+        if metadata.verb.is_none() {
+            continue;
+        }
+
+        for form in VerbForm::ALL {
+            let is_doubled = ATTRIBUTES
+                .uninflect(word, adds_verb_form(form))
+                .iter()
+                .filter_map(|stem| undouble_last(stem))
+                .any(|base| words.get(base).is_some_and(|m| m.verb.is_some()));
+
+            if is_doubled {
+                doubled.push((word.clone(), form));
+            }
+        }
+    }
+
+    for (word, form) in doubled {
+        if let Some(metadata) = words.get_mut(&word) {
+            metadata.append(&verb_form(form));
+        }
+    }
+
+    // Words like “saw” and “found” are verbs in their own right, marked with
+    // the suffixes of their regular forms (“sawed”, “founding”), so they keep
+    // their base form.
+    let regular_verbs: HashSet<&CharString> = marked_words
+        .iter()
+        .filter(|word| IRREGULAR.verbs.contains_key(&word.letters))
+        .filter(|word| {
+            word.attributes.iter().any(|attribute| {
+                ATTRIBUTES.is_suffix_adding(*attribute, |m| {
+                    adds_verb_form(VerbForm::Past)(m) || adds_verb_form(VerbForm::Gerund)(m)
+                })
+            })
+        })
+        .map(|word| &word.letters)
+        .collect();
+
+    for verb in &IRREGULAR.forms.verbs {
+        for form in VerbForm::ALL {
+            let word = verb.form(form);
+
+            if form == VerbForm::Base || word == verb.base {
+                continue;
+            }
+
+            let word: CharString = word.chars().collect();
+
+            if regular_verbs.contains(&word) {
+                continue;
+            }
+
+            if let Some(metadata) = words.get_mut(&word) {
+                metadata.append(&verb_form(form));
+            }
+        }
+    }
+
+    let noun_number = |is_plural| WordMetadata {
+        noun: Some(NounData {
+            is_proper: None,
+            is_plural: Some(is_plural),
+            is_possessive: None,
+            is_pronoun: None,
+        }),
+        ..Default::default()
+    };
+
+    for noun in &IRREGULAR.forms.nouns {
+        if let Some(metadata) = words.get_mut(&noun.plural.chars().collect::<CharString>()) {
+            metadata.append(&noun_number(true));
+        }
+    }
+
+    // Nouns that have a plural form of their own, like “list” or “child”, are
+    // singular. Ones that don't, like “sheep” or “data”, could be either.
+    let mut singular = Vec::new();
+
+    for (word, metadata) in words.iter() {
+        if !metadata.noun.is_some_and(|noun| noun.is_plural.is_none()) {
+            continue;
+        }
+
+        let irregular_plural = IRREGULAR
+            .nouns
+            .get(word)
+            .map(|&index| &IRREGULAR.forms.nouns[index])
+            .filter(|noun| noun.singular.chars().eq(word.iter().copied()))
+            .map(|noun| noun.plural.chars().collect::<CharString>());
+
+        let has_plural = ATTRIBUTES
+            .inflect(word, adds_plural)
+            .into_iter()
+            .chain(irregular_plural)
+            .any(|plural| {
+                plural != *word && words.get(&plural).is_some_and(|m| m.is_plural_noun())
+            });
+
+        if has_plural {
+            singular.push(word.clone());
+        }
+    }
+
+    for word in singular {
+        if let Some(metadata) = words.get_mut(&word) {
+            metadata.append(&noun_number(false));
+        }
+    }
+}
+
+/// Whether an affix adds the given form of a verb.
+pub(super) fn adds_verb_form(form: VerbForm) -> impl Fn(&WordMetadata) -> bool {
+    move |metadata| form != VerbForm::Base && metadata.verb.is_some_and(|verb| verb.is_form(form))
+}
+
+pub(super) fn adds_any_verb_form(metadata: &WordMetadata) -> bool {
+    VerbForm::ALL
+        .into_iter()
+        .any(|form| adds_verb_form(form)(metadata))
+}
+
+pub(super) fn adds_plural(metadata: &WordMetadata) -> bool {
+    metadata
+        .noun
+        .is_some_and(|noun| noun.is_plural == Some(true))
+}
+
+/// `word` with its last letter doubled, as in “run” and “running”.
+pub(super) fn double_last(word: &[char]) -> Option<CharString> {
+    let last = *word.last()?;
+
+    if !last.is_alphabetic() || "aeiouwxy".contains(last) {
+        return None;
+    }
+
+    let mut doubled = CharString::from(word);
+    doubled.push(last);
+    Some(doubled)
+}
+
+/// `word` with its doubled last letter undone.
+pub(super) fn undouble_last(word: &[char]) -> Option<&[char]> {
+    match word {
+        [.., a, b] if a == b => Some(&word[..word.len() - 1]),
+        _ => None,
+    }
+}
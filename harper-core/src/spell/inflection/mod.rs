@@ -0,0 +1,311 @@
+//! The relationships between the inflected forms of words, like “go” and
+//! “went” or “child” and “children”.
+//!
+//! Regular forms are found with the suffixes in `affixes.json` that add
+//! inflection metadata (like “-s” and “-ed”), and irregular ones are listed
+//! in `irregular_forms.json`.
+
+mod forms;
+
+use super::Dictionary;
+use crate::{CharString, CharStringExt, VerbForm, WordMetadata};
+pub(crate) use forms::annotate_inflections;
+use forms::{
+    adds_any_verb_form, adds_plural, adds_verb_form, double_last, undouble_last, ATTRIBUTES,
+    IRREGULAR,
+};
+
+/// The first of `candidates` that is in the dictionary.
+fn first_known(
+    dictionary: &(impl Dictionary + ?Sized),
+    candidates: impl IntoIterator<Item = CharString>,
+) -> Option<CharString> {
+    let mut candidates: Vec<_> = candidates.into_iter().collect();
+    candidates.sort();
+    candidates.dedup();
+
+    candidates
+        .into_iter()
+        .find(|candidate| dictionary.contains_word(candidate))
+}
+
+/// The stems the affixes that match `adds` could have made `word` from, as
+/// long as they are in the dictionary and match `is_stem`.
+fn find_stem(
+    dictionary: &(impl Dictionary + ?Sized),
+    word: &[char],
+    adds: impl Fn(&WordMetadata) -> bool,
+    is_stem: impl Fn(&WordMetadata) -> bool,
+) -> Option<CharString> {
+    let stems = ATTRIBUTES.uninflect(word, adds);
+
+    let undoubled = stems
+        .iter()
+        .filter_map(|stem| undouble_last(stem).map(CharString::from));
+
+    let mut candidates: Vec<_> = stems.iter().cloned().chain(undoubled).collect();
+    candidates.sort();
+    candidates.dedup();
+
+    candidates
+        .into_iter()
+        .find(|stem| is_stem(&dictionary.get_word_metadata(stem)))
+}
+
+/// See [`Dictionary::get_verb_form`].
+pub(super) fn verb_form(
+    dictionary: &(impl Dictionary + ?Sized),
+    verb: &[char],
+    form: VerbForm,
+) -> Option<CharString> {
+    let base = verb_base(dictionary, verb)?;
+
+    if form == VerbForm::Base {
+        return Some(base);
+    }
+
+    if let Some(&index) = IRREGULAR.verbs.get(&base.to_lower()) {
+        let irregular = &IRREGULAR.forms.verbs[index];
+
+        if irregular.base.chars().eq(base.to_lower()) {
+            let inflected: CharString = irregular.form(form).chars().collect();
+            return dictionary.contains_word(&inflected).then_some(inflected);
+        }
+    }
+
+    first_known(dictionary, ATTRIBUTES.inflect(&base, adds_verb_form(form))).or_else(|| {
+        first_known(
+            dictionary,
+            ATTRIBUTES.inflect(&double_last(&base)?, adds_verb_form(form)),
+        )
+    })
+}
+
+/// The base form of a verb, in any form.
+fn verb_base(dictionary: &(impl Dictionary + ?Sized), verb: &[char]) -> Option<CharString> {
+    let metadata = dictionary.get_word_metadata(verb);
+
+    // Checked first, so verbs like “saw” aren't mistaken for forms of
+    // irregular ones they share a spelling with.
+    if metadata
+        .verb
+        .is_some_and(|verb| verb.is_form(VerbForm::Base))
+    {
+        return Some(CharString::from(verb));
+    }
+
+    if let Some(&index) = IRREGULAR.verbs.get(&verb.to_lower()) {
+        let base: CharString = IRREGULAR.forms.verbs[index].base.chars().collect();
+        return dictionary.contains_word(&base).then_some(base);
+    }
+
+    find_stem(dictionary, verb, adds_any_verb_form, |m| m.verb.is_some())
+}
+
+/// See [`Dictionary::get_plural`].
+pub(super) fn plural(dictionary: &(impl Dictionary + ?Sized), noun: &[char]) -> Option<CharString> {
+    let singular = singular(dictionary, noun)?;
+
+    if let Some(&index) = IRREGULAR.nouns.get(&singular.to_lower()) {
+        let plural: CharString = IRREGULAR.forms.nouns[index].plural.chars().collect();
+        return dictionary.contains_word(&plural).then_some(plural);
+    }
+
+    first_known(dictionary, ATTRIBUTES.inflect(&singular, adds_plural))
+}
+
+/// See [`Dictionary::get_singular`].
+pub(super) fn singular(
+    dictionary: &(impl Dictionary + ?Sized),
+    noun: &[char],
+) -> Option<CharString> {
+    if let Some(&index) = IRREGULAR.nouns.get(&noun.to_lower()) {
+        let singular: CharString = IRREGULAR.forms.nouns[index].singular.chars().collect();
+        return dictionary.contains_word(&singular).then_some(singular);
+    }
+
+    let metadata = dictionary.get_word_metadata(noun);
+    let noun_data = metadata.noun?;
+
+    if noun_data.is_plural != Some(true) {
+        return Some(CharString::from(noun));
+    }
+
+    find_stem(dictionary, noun, adds_plural, |m| {
+        m.noun.is_some_and(|n| n.is_plural != Some(true))
+    })
+}
+
+/// See [`Dictionary::get_lemma`].
+pub(super) fn lemma(dictionary: &(impl Dictionary + ?Sized), word: &[char]) -> Option<CharString> {
+    let metadata = dictionary.get_word_metadata(word);
+
+    let is_inflected_verb = metadata
+        .verb
+        .is_some_and(|verb| !verb.is_form(VerbForm::Base));
+    let is_plural_noun = metadata
+        .noun
+        .is_some_and(|noun| noun.is_plural == Some(true));
+
+    if is_inflected_verb {
+        if let Some(base) = verb_base(dictionary, word) {
+            return Some(base);
+        }
+    }
+
+    if is_plural_noun {
+        if let Some(singular) = singular(dictionary, word) {
+            return Some(singular);
+        }
+    }
+
+    dictionary
+        .contains_word(word)
+        .then(|| CharString::from(word))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::spell::Dictionary;
+    use crate::{FstDictionary, VerbForm};
+
+    fn verb_form(verb: &str, form: VerbForm) -> Option<String> {
+        let verb: Vec<char> = verb.chars().collect();
+
+        FstDictionary::curated()
+            .get_verb_form(&verb, form)
+            .map(|w| w.iter().collect())
+    }
+
+    fn lemma(word: &str) -> Option<String> {
+        let word: Vec<char> = word.chars().collect();
+
+        FstDictionary::curated()
+            .get_lemma(&word)
+            .map(|w| w.iter().collect())
+    }
+
+    #[test]
+    fn inflects_regular_verbs() {
+        assert_eq!(
+            verb_form("walk", VerbForm::ThirdPersonSingular).as_deref(),
+            Some("walks")
+        );
+        assert_eq!(verb_form("walk", VerbForm::Past).as_deref(), Some("walked"));
+        assert_eq!(
+            verb_form("walked", VerbForm::Gerund).as_deref(),
+            Some("walking")
+        );
+        assert_eq!(verb_form("try", VerbForm::Past).as_deref(), Some("tried"));
+    }
+
+    #[test]
+    fn inflects_irregular_verbs() {
+        assert_eq!(verb_form("go", VerbForm::Past).as_deref(), Some("went"));
+        assert_eq!(
+            verb_form("went", VerbForm::PastParticiple).as_deref(),
+            Some("gone")
+        );
+        assert_eq!(verb_form("has", VerbForm::Base).as_deref(), Some("have"));
+    }
+
+    #[test]
+    fn doubles_final_consonants() {
+        assert_eq!(
+            verb_form("stop", VerbForm::Past).as_deref(),
+            Some("stopped")
+        );
+        assert_eq!(lemma("stopped").as_deref(), Some("stop"));
+    }
+
+    #[test]
+    fn finds_lemmas() {
+        assert_eq!(lemma("walks").as_deref(), Some("walk"));
+        assert_eq!(lemma("tried").as_deref(), Some("try"));
+        assert_eq!(lemma("went").as_deref(), Some("go"));
+        assert_eq!(lemma("children").as_deref(), Some("child"));
+        assert_eq!(lemma("giants").as_deref(), Some("giant"));
+        assert_eq!(lemma("hello").as_deref(), Some("hello"));
+        assert_eq!(lemma("asdfgh"), None);
+    }
+
+    #[test]
+    fn pluralizes_nouns() {
+        let dict = FstDictionary::curated();
+        let word = |s: &str| s.chars().collect::<Vec<_>>();
+        let string = |w: Option<crate::CharString>| w.map(|w| w.iter().collect::<String>());
+
+        assert_eq!(
+            string(dict.get_plural(&word("giant"))).as_deref(),
+            Some("giants")
+        );
+        assert_eq!(
+            string(dict.get_plural(&word("box"))).as_deref(),
+            Some("boxes")
+        );
+        assert_eq!(
+            string(dict.get_plural(&word("child"))).as_deref(),
+            Some("children")
+        );
+        assert_eq!(
+            string(dict.get_singular(&word("boxes"))).as_deref(),
+            Some("box")
+        );
+        assert_eq!(
+            string(dict.get_singular(&word("mice"))).as_deref(),
+            Some("mouse")
+        );
+    }
+
+    #[test]
+    fn labels_forms_in_metadata() {
+        let dict = FstDictionary::curated();
+
+        assert!(dict
+            .get_word_metadata_str("walks")
+            .is_verb_form(VerbForm::ThirdPersonSingular));
+        assert!(dict
+            .get_word_metadata_str("walked")
+            .is_verb_form(VerbForm::Past));
+        assert!(dict
+            .get_word_metadata_str("went")
+            .is_verb_form(VerbForm::Past));
+        assert!(dict
+            .get_word_metadata_str("gone")
+            .is_verb_form(VerbForm::PastParticiple));
+        assert!(dict
+            .get_word_metadata_str("walk")
+            .is_verb_form(VerbForm::Base));
+        assert!(dict.get_word_metadata_str("children").is_plural_noun());
+    }
+
+    #[test]
+    fn keeps_verbs_that_share_irregular_spellings() {
+        let dict = FstDictionary::curated();
+
+        assert_eq!(
+            verb_form("saw", VerbForm::Gerund).as_deref(),
+            Some("sawing")
+        );
+        assert_eq!(verb_form("see", VerbForm::Past).as_deref(), Some("saw"));
+        assert_eq!(lemma("found").as_deref(), Some("found"));
+        assert_eq!(verb_form("find", VerbForm::Past).as_deref(), Some("found"));
+        assert!(dict
+            .get_word_metadata_str("saw")
+            .is_verb_form(VerbForm::Base));
+        assert!(dict
+            .get_word_metadata_str("found")
+            .is_verb_form(VerbForm::Base));
+    }
+
+    #[test]
+    fn labels_nouns_with_plurals_singular() {
+        let dict = FstDictionary::curated();
+
+        assert!(dict.get_word_metadata_str("list").is_not_plural_noun());
+        assert!(dict.get_word_metadata_str("child").is_not_plural_noun());
+        assert!(!dict.get_word_metadata_str("lists").is_not_plural_noun());
+        assert!(!dict.get_word_metadata_str("sheep").is_not_plural_noun());
+        assert!(!dict.get_word_metadata_str("data").is_not_plural_noun());
+    }
+}
@@ -1,19 +1,25 @@
 use std::borrow::Cow;
 
-use itertools::{Itertools, MinMaxResult};
-
 use crate::{CharString, CharStringExt, WordMetadata};
 
 pub use self::dictionary::Dictionary;
-pub use self::fst_dictionary::FstDictionary;
+pub use self::fst_dictionary::{CompiledDictionaryError, FstDictionary};
 pub use self::full_dictionary::FullDictionary;
+pub use self::hunspell::Error as HunspellError;
+pub use self::hunspell::WordListWarning;
 pub use self::merged_dictionary::MergedDictionary;
+pub(crate) use dialect_spellings::dialect_corrections;
+pub(crate) use ranking::{score_splits, sort_scored};
 
+mod compiled;
+mod dialect_spellings;
 mod dictionary;
 mod fst_dictionary;
 mod full_dictionary;
 mod hunspell;
+mod inflection;
 mod merged_dictionary;
+mod ranking;
 
 #[derive(PartialEq)]
 pub struct FuzzyMatchResult<'a> {
@@ -28,56 +34,114 @@ impl PartialOrd for FuzzyMatchResult<'_> {
     }
 }
 
-/// Order the suggestions to be shown to the user.
-fn order_suggestions(matches: Vec<FuzzyMatchResult>) -> Vec<&[char]> {
-    let mut found: Vec<&FuzzyMatchResult> = Vec::with_capacity(matches.len());
-    // Often the longest and the shortest words are the most helpful, so lets push
-    // them first.
-    let minmax = matches.iter().position_minmax_by_key(|fmr| fmr.word.len());
-    if let MinMaxResult::MinMax(a, b) = minmax {
-        if a == b {
-            found.push(&matches[a]);
-        } else {
-            found.push(&matches[a]);
-            found.push(&matches[b]);
-        }
+/// The words on either side of a misspelling, which can help decide between
+/// suggestions that are otherwise equally good.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SuggestionContext<'a> {
+    pub previous: Option<&'a [char]>,
+    pub next: Option<&'a [char]>,
+}
 
-        // Push the rest
-        found.extend(
-            matches
-                .iter()
-                .enumerate()
-                .filter(|(i, _)| *i != a && *i != b)
-                .map(|v| v.1),
-        );
-    } else {
-        found.extend(&matches);
+impl SuggestionContext<'_> {
+    pub fn is_empty(&self) -> bool {
+        self.previous.is_none() && self.next.is_none()
     }
+}
 
-    // Swap the lowest edit distance word with the shortest.
-    if found.len() >= 3 {
-        found.swap(0, 2);
-    }
+/// Get the closest matches in the provided [`Dictionary`], best first.
+///
+/// Candidates are ranked by how likely they are to be what the user meant to
+/// type, taking into account keyboard layout, common typos, pronunciation and
+/// how common each word is.
+pub fn suggest_correct_spelling<'a>(
+    misspelled_word: &[char],
+    result_limit: usize,
+    max_edit_dist: u8,
+    dictionary: &'a impl Dictionary,
+) -> Vec<&'a [char]> {
+    suggest_correct_spelling_in_context(
+        misspelled_word,
+        SuggestionContext::default(),
+        result_limit,
+        max_edit_dist,
+        dictionary,
+    )
+}
 
-    // Make commonality relevant
-    found.sort_by_key(|fmr| if fmr.metadata.common { 0 } else { 1 });
+/// Like [`suggest_correct_spelling`], but also considers the words around the
+/// misspelling.
+pub fn suggest_correct_spelling_in_context<'a>(
+    misspelled_word: &[char],
+    context: SuggestionContext,
+    result_limit: usize,
+    max_edit_dist: u8,
+    dictionary: &'a impl Dictionary,
+) -> Vec<&'a [char]> {
+    let mut scored = score_suggestions(misspelled_word, result_limit, max_edit_dist, dictionary);
+    apply_context(&mut scored, context, dictionary);
 
-    found.into_iter().map(|fmr| fmr.word).collect()
+    scored.into_iter().map(|(word, _)| word).collect()
 }
 
-/// Get the closest matches in the provided [`Dictionary`] and rank them
-/// Implementation is left up to the underlying dictionary.
-pub fn suggest_correct_spelling<'a>(
+/// How many more candidates than requested to fetch from the dictionary
+/// before ranking them.
+const CANDIDATE_POOL: usize = 10;
+
+/// The most candidates to fetch from the dictionary before ranking them,
+/// unless more results than this are requested.
+const MAX_CANDIDATE_POOL: usize = 100;
+
+/// Get the closest matches in the provided [`Dictionary`] along with their
+/// scores, best (lowest) first.
+pub(crate) fn score_suggestions<'a>(
     misspelled_word: &[char],
     result_limit: usize,
     max_edit_dist: u8,
     dictionary: &'a impl Dictionary,
-) -> Vec<&'a [char]> {
-    let matches: Vec<FuzzyMatchResult> = dictionary
-        .fuzzy_match(misspelled_word, max_edit_dist, result_limit)
+) -> Vec<(&'a [char], f32)> {
+    // The dictionary cuts its matches down by edit distance alone, which can
+    // drop the best candidate, so score a larger pool.
+    let pool = result_limit
+        .saturating_mul(CANDIDATE_POOL)
+        .min(MAX_CANDIDATE_POOL)
+        .max(result_limit);
+    let mut candidates = dictionary.fuzzy_match(misspelled_word, max_edit_dist, pool);
+
+    // Swapped letters are among the most common typos, but count as two edits.
+    for candidate in ranking::transpositions(misspelled_word, dictionary) {
+        if !candidates.iter().any(|c| c.word == candidate.word) {
+            candidates.push(candidate);
+        }
+    }
+
+    let mut scored: Vec<_> = candidates
         .into_iter()
+        .map(|candidate| (candidate.word, ranking::score(misspelled_word, &candidate)))
         .collect();
-    order_suggestions(matches)
+
+    ranking::sort_scored(&mut scored);
+    scored.dedup_by(|a, b| a.0 == b.0);
+    scored.truncate(result_limit);
+
+    scored
+}
+
+/// Adjust the scores of suggestions from [`score_suggestions`] for the words
+/// around the misspelling, then sort them again.
+pub(crate) fn apply_context<W: AsRef<[char]>>(
+    suggestions: &mut [(W, f32)],
+    context: SuggestionContext,
+    dictionary: &impl Dictionary,
+) {
+    if context.is_empty() {
+        return;
+    }
+
+    for (word, score) in suggestions.iter_mut() {
+        *score += ranking::context_score(word.as_ref(), context, dictionary);
+    }
+
+    ranking::sort_scored(suggestions);
 }
 
 /// Convenience function over [`suggest_correct_spelling`] that does conversions
@@ -163,10 +227,11 @@ mod tests {
     use itertools::Itertools;
 
     use crate::spell::FuzzyMatchResult;
+    use crate::WordMetadata;
 
     use super::{
-        edit_distance, order_suggestions, seq_to_normalized, suggest_correct_spelling_str,
-        Dictionary, FstDictionary, FullDictionary,
+        apply_context, edit_distance, ranking, seq_to_normalized, suggest_correct_spelling_str,
+        FstDictionary, FullDictionary, SuggestionContext,
     };
 
     const RESULT_LIMIT: usize = 60;
@@ -241,51 +306,43 @@ mod tests {
     /// Ensures that the suggestions are ordered taking into account commonality
     #[test]
     fn common_words_first() {
+        let misspelled: Vec<_> = "wrod".chars().collect();
+        let word: Vec<_> = "word".chars().collect();
+
+        let common = FuzzyMatchResult {
+            word: &word,
+            edit_distance: 2,
+            metadata: WordMetadata {
+                common: true,
+                ..Default::default()
+            },
+        };
+        let uncommon = FuzzyMatchResult {
+            metadata: WordMetadata::default(),
+            ..common
+        };
+
+        assert!(ranking::score(&misspelled, &common) < ranking::score(&misspelled, &uncommon));
+    }
+
+    #[test]
+    fn articles_affect_order() {
         let dict = FstDictionary::curated();
-        // Select three common words
-        let common_words = dict
-            .words_iter()
-            .filter_map(|word| {
-                let metadata = dict.get_word_metadata(word);
-                if metadata.common {
-                    Some(FuzzyMatchResult {
-                        word,
-                        edit_distance: 0,
-                        metadata,
-                    })
-                } else {
-                    None
-                }
-            })
-            .take(3);
-        // Select three uncommon words
-        let uncommon_words = dict
-            .words_iter()
-            .filter_map(|word| {
-                let metadata = dict.get_word_metadata(word);
-                if metadata.common {
-                    None
-                } else {
-                    Some(FuzzyMatchResult {
-                        word,
-                        edit_distance: 0,
-                        metadata,
-                    })
-                }
-            })
-            .take(3);
-        // Feed the common and uncommon words into the ordering function, starting with uncommon
-        // words
-        let words = uncommon_words.merge(common_words).collect();
-        let suggestions = order_suggestions(words);
-
-        // Asserts that the ordering prioritizes common words
-        let common_first = suggestions
-            .into_iter()
-            .take(3)
-            .all(|word| dict.get_word_metadata(word).common);
-
-        assert!(common_first);
+        let article: Vec<_> = "a".chars().collect();
+        let apple: Vec<_> = "apple".chars().collect();
+        let maple: Vec<_> = "maple".chars().collect();
+
+        let mut scored = vec![(apple.as_slice(), 1.0), (maple.as_slice(), 1.0)];
+
+        apply_context(&mut scored, SuggestionContext::default(), &dict);
+        assert_eq!(scored[0].0, apple);
+
+        let context = SuggestionContext {
+            previous: Some(&article),
+            next: None,
+        };
+        apply_context(&mut scored, context, &dict);
+        assert_eq!(scored[0].0, maple);
     }
 
     #[test]
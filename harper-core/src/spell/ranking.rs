@@ -0,0 +1,368 @@
+//! Scoring of spelling suggestions.
+//!
+//! Each candidate returned by [`Dictionary::fuzzy_match`] is given a score,
+//! where lower is better. The score starts from an edit distance that knows
+//! about common kinds of typos (adjacent keys, swapped letters, doubled
+//! letters and missing apostrophes), then is adjusted for how the words
+//! sound, how common the candidate is and the words around the misspelling.
+
+use super::{Dictionary, FuzzyMatchResult, SuggestionContext};
+use crate::pos_tagger::{is_determiner, is_subject_pronoun};
+use crate::{CharString, CharStringExt};
+
+/// The cost of inserting or deleting a letter.
+const INDEL_COST: f32 = 1.0;
+/// The cost of inserting or deleting a letter next to a copy of itself.
+const DOUBLED_LETTER_COST: f32 = 0.5;
+/// The cost of inserting or deleting an apostrophe.
+const APOSTROPHE_COST: f32 = 0.3;
+/// The cost of substituting a letter for one with no relation to it.
+const SUBSTITUTION_COST: f32 = 1.0;
+/// The cost of substituting a letter for one next to it on the keyboard.
+const ADJACENT_KEY_COST: f32 = 0.6;
+/// The cost of substituting a vowel for another vowel.
+const VOWEL_COST: f32 = 0.7;
+/// The cost of swapping two neighboring letters.
+const TRANSPOSITION_COST: f32 = 0.6;
+
+const PHONETIC_MATCH_BONUS: f32 = 0.4;
+const COMMON_WORD_BONUS: f32 = 0.3;
+const FIRST_LETTER_PENALTY: f32 = 0.3;
+const CAPITALIZATION_PENALTY: f32 = 0.3;
+const CONTEXT_WEIGHT: f32 = 0.25;
+/// The cost of a missing space, when both sides are dictionary words.
+const SPLIT_COST: f32 = 0.3;
+/// Splitting off an uncommon word is less likely to be right.
+const UNCOMMON_SPLIT_PENALTY: f32 = 0.15;
+
+/// The rows of a QWERTY keyboard, each offset a little further to the right
+/// than the last.
+const KEYBOARD_ROWS: [&str; 3] = ["qwertyuiop", "asdfghjkl", "zxcvbnm"];
+
+/// Score a candidate for a misspelled word, without looking at the words
+/// around it. Lower is better.
+pub(super) fn score(misspelled: &[char], candidate: &FuzzyMatchResult) -> f32 {
+    let misspelled_lower = misspelled.to_lower();
+    let candidate_lower = candidate.word.to_lower();
+
+    let mut score = weighted_distance(&misspelled_lower, &candidate_lower);
+
+    if phonetic_key(&misspelled_lower) == phonetic_key(&candidate_lower) {
+        score -= PHONETIC_MATCH_BONUS;
+    }
+
+    if candidate.metadata.common {
+        score -= COMMON_WORD_BONUS;
+    }
+
+    if misspelled_lower.first() != candidate_lower.first() {
+        score += FIRST_LETTER_PENALTY;
+    }
+
+    // Suggesting a proper noun for a word written in lowercase is rarely
+    // what the user wants.
+    let is_capitalized = |word: &[char]| word.first().is_some_and(|c| c.is_uppercase());
+    if is_capitalized(candidate.word) && !is_capitalized(misspelled) {
+        score += CAPITALIZATION_PENALTY;
+    }
+
+    score
+}
+
+/// Dictionary words that can be made by swapping two neighboring letters of
+/// the misspelled word.
+pub(super) fn transpositions<'a>(
+    misspelled: &[char],
+    dictionary: &'a impl Dictionary,
+) -> Vec<FuzzyMatchResult<'a>> {
+    let mut swapped = misspelled.to_vec();
+
+    (1..misspelled.len())
+        .filter(|&i| misspelled[i] != misspelled[i - 1])
+        .flat_map(|i| {
+            swapped.swap(i - 1, i);
+            let found = dictionary.fuzzy_match(&swapped, 0, 1);
+            swapped.swap(i - 1, i);
+            found
+        })
+        .collect()
+}
+
+/// Ways of splitting a run-together word, like “alot”, into two dictionary
+/// words separated by a space, along with their scores.
+pub(crate) fn score_splits(word: &[char], dictionary: &impl Dictionary) -> Vec<(CharString, f32)> {
+    // Single letters are only allowed when they are words people actually
+    // run into their neighbors.
+    let is_part = |part: &[char]| match part {
+        [c] => matches!(c, 'a' | 'A' | 'I'),
+        _ => dictionary.contains_word(part),
+    };
+
+    (1..word.len())
+        .filter_map(|i| {
+            let (left, right) = word.split_at(i);
+
+            if !is_part(left) || !is_part(right) {
+                return None;
+            }
+
+            // A split keeps every letter, so it always sounds the same.
+            let mut score = SPLIT_COST - PHONETIC_MATCH_BONUS;
+
+            for part in [left, right] {
+                if part.len() > 1 && !dictionary.get_word_metadata(part).common {
+                    score += UNCOMMON_SPLIT_PENALTY;
+                }
+            }
+
+            let mut split = CharString::from(left);
+            split.push(' ');
+            split.extend_from_slice(right);
+
+            Some((split, score))
+        })
+        .collect()
+}
+
+/// How much better (negative) or worse (positive) a candidate fits between
+/// the words around the misspelling.
+pub(super) fn context_score(
+    candidate: &[char],
+    context: SuggestionContext,
+    dictionary: &impl Dictionary,
+) -> f32 {
+    let metadata = dictionary.get_word_metadata(candidate);
+    let mut score = 0.0;
+
+    if let Some(previous) = context.previous {
+        let previous = previous.to_lower();
+
+        if is_determiner(&previous) {
+            if metadata.is_noun() || metadata.is_adjective() {
+                score -= CONTEXT_WEIGHT;
+            } else if metadata.is_verb() || metadata.is_adverb() {
+                score += CONTEXT_WEIGHT;
+            }
+
+            let starts_with_vowel = candidate
+                .first()
+                .is_some_and(|c| is_vowel(c.to_ascii_lowercase()));
+
+            match (previous.as_slice(), starts_with_vowel) {
+                (['a', 'n'], true) | (['a'], false) => score -= CONTEXT_WEIGHT,
+                (['a', 'n'], false) | (['a'], true) => score += CONTEXT_WEIGHT,
+                _ => (),
+            }
+        } else if is_subject_pronoun(&previous) && metadata.is_verb() {
+            score -= CONTEXT_WEIGHT;
+        }
+    }
+
+    if let Some(next) = context.next {
+        if is_determiner(&next.to_lower()) && metadata.is_verb() {
+            score -= CONTEXT_WEIGHT;
+        }
+    }
+
+    score
+}
+
+fn is_vowel(c: char) -> bool {
+    matches!(c, 'a' | 'e' | 'i' | 'o' | 'u')
+}
+
+/// The position of a key on a QWERTY keyboard, in units of key widths.
+fn key_position(c: char) -> Option<(f32, f32)> {
+    KEYBOARD_ROWS.iter().enumerate().find_map(|(row, keys)| {
+        keys.chars()
+            .position(|k| k == c)
+            .map(|col| (row as f32, col as f32 + row as f32 * 0.5))
+    })
+}
+
+fn keys_adjacent(a: char, b: char) -> bool {
+    let (Some((row_a, col_a)), Some((row_b, col_b))) = (key_position(a), key_position(b)) else {
+        return false;
+    };
+
+    (row_a - row_b).abs() <= 1.0 && (col_a - col_b).abs() <= 1.0
+}
+
+fn substitution_cost(a: char, b: char) -> f32 {
+    if a == b {
+        0.0
+    } else if keys_adjacent(a, b) {
+        ADJACENT_KEY_COST
+    } else if is_vowel(a) && is_vowel(b) {
+        VOWEL_COST
+    } else {
+        SUBSTITUTION_COST
+    }
+}
+
+/// The cost of inserting or deleting `word[index]`.
+fn indel_cost(word: &[char], index: usize) -> f32 {
+    let c = word[index];
+
+    if c == '\'' {
+        APOSTROPHE_COST
+    } else if (index > 0 && word[index - 1] == c) || word.get(index + 1) == Some(&c) {
+        DOUBLED_LETTER_COST
+    } else {
+        INDEL_COST
+    }
+}
+
+/// A weighted optimal string alignment distance, where the typos people make
+/// most often are cheaper than other edits.
+fn weighted_distance(source: &[char], target: &[char]) -> f32 {
+    let width = target.len() + 1;
+    let mut table = vec![0.0; (source.len() + 1) * width];
+
+    for i in 1..=source.len() {
+        table[i * width] = table[(i - 1) * width] + indel_cost(source, i - 1);
+    }
+
+    for j in 1..=target.len() {
+        table[j] = table[j - 1] + indel_cost(target, j - 1);
+    }
+
+    for i in 1..=source.len() {
+        for j in 1..=target.len() {
+            let mut best = (table[(i - 1) * width + j] + indel_cost(source, i - 1))
+                .min(table[i * width + j - 1] + indel_cost(target, j - 1))
+                .min(
+                    table[(i - 1) * width + j - 1]
+                        + substitution_cost(source[i - 1], target[j - 1]),
+                );
+
+            if i > 1
+                && j > 1
+                && source[i - 1] == target[j - 2]
+                && source[i - 2] == target[j - 1]
+                && source[i - 1] != source[i - 2]
+            {
+                best = best.min(table[(i - 2) * width + j - 2] + TRANSPOSITION_COST);
+            }
+
+            table[i * width + j] = best;
+        }
+    }
+
+    table[source.len() * width + target.len()]
+}
+
+/// A rough key for how a lowercase word sounds, in the spirit of Soundex.
+/// Words that are spelled differently but sound alike, like “recieve” and
+/// “receive”, share a key.
+fn phonetic_key(word: &[char]) -> CharString {
+    let mut key = CharString::new();
+
+    for (index, &c) in word.iter().enumerate() {
+        let next = word.get(index + 1).copied();
+
+        let code = match c {
+            'a' | 'e' | 'i' | 'o' | 'u' | 'y' | 'h' | 'w' if index > 0 => continue,
+            'p' if next == Some('h') => 'f',
+            'c' if matches!(next, Some('e' | 'i' | 'y')) => 's',
+            'c' | 'q' | 'k' => 'k',
+            'z' => 's',
+            '\'' | '-' => continue,
+            _ => c,
+        };
+
+        if key.last() != Some(&code) {
+            key.push(code);
+        }
+    }
+
+    key
+}
+
+/// Candidates are sorted by score, then alphabetically so the order doesn't
+/// depend on the dictionary.
+pub(crate) fn sort_scored<W: AsRef<[char]>>(suggestions: &mut [(W, f32)]) {
+    suggestions.sort_by(|(a_word, a_score), (b_word, b_score)| {
+        a_score
+            .total_cmp(b_score)
+            .then_with(|| a_word.as_ref().cmp(b_word.as_ref()))
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{context_score, keys_adjacent, phonetic_key, score_splits, weighted_distance};
+    use crate::spell::SuggestionContext;
+    use crate::{CharStringExt, FstDictionary};
+
+    fn distance(a: &str, b: &str) -> f32 {
+        let a: Vec<_> = a.chars().collect();
+        let b: Vec<_> = b.chars().collect();
+        weighted_distance(&a, &b)
+    }
+
+    #[test]
+    fn adjacent_keys() {
+        assert!(keys_adjacent('s', 'a'));
+        assert!(keys_adjacent('s', 'e'));
+        assert!(keys_adjacent('g', 'b'));
+        assert!(!keys_adjacent('a', 'p'));
+        assert!(!keys_adjacent('q', 'z'));
+    }
+
+    #[test]
+    fn common_typos_are_cheap() {
+        assert!(distance("teh", "the") < distance("teh", "tea"));
+        assert!(distance("hwllo", "hello") < distance("hzllo", "hello"));
+        assert!(distance("im", "i'm") < distance("im", "in"));
+        assert!(distance("adress", "address") < distance("adress", "egress"));
+        assert_eq!(distance("same", "same"), 0.0);
+    }
+
+    #[test]
+    fn similar_sounds_share_keys() {
+        let key = |s: &str| phonetic_key(&s.chars().collect::<Vec<_>>()).to_string();
+
+        assert_eq!(key("recieve"), key("receive"));
+        assert_eq!(key("fone"), key("phone"));
+        assert_ne!(key("cat"), key("hat"));
+    }
+
+    #[test]
+    fn splits_into_dictionary_words() {
+        let dict = FstDictionary::curated();
+        let splits = |s: &str| {
+            score_splits(&s.chars().collect::<Vec<_>>(), &dict)
+                .into_iter()
+                .map(|(split, _)| split.to_string())
+                .collect::<Vec<_>>()
+        };
+
+        assert!(splits("alot").contains(&"a lot".to_string()));
+        assert!(splits("thecat").contains(&"the cat".to_string()));
+        assert!(!splits("hellos").iter().any(|s| s.ends_with(" s")));
+    }
+
+    #[test]
+    fn only_subject_pronouns_favor_verbs() {
+        let dict = FstDictionary::curated();
+        let score_after = |previous: &str, candidate: &str| {
+            let previous: Vec<_> = previous.chars().collect();
+            let candidate: Vec<_> = candidate.chars().collect();
+
+            context_score(
+                &candidate,
+                SuggestionContext {
+                    previous: Some(&previous),
+                    next: None,
+                },
+                dict.as_ref(),
+            )
+        };
+
+        assert!(score_after("they", "walk") < 0.0);
+        // “it” and “them” are often objects, so say nothing about what follows.
+        assert_eq!(score_after("it", "allot"), 0.0);
+        assert_eq!(score_after("them", "walk"), 0.0);
+    }
+}
@@ -6,7 +6,6 @@ use serde::{Deserialize, Serialize};
 
 use crate::punctuation::Punctuation;
 use crate::Span;
-use crate::{ConjunctionData, NounData};
 use crate::{Quote, WordMetadata};
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
@@ -75,26 +74,19 @@ impl TokenKind {
     }
 
     pub fn is_pronoun(&self) -> bool {
-        matches!(
-            self,
-            TokenKind::Word(WordMetadata {
-                noun: Some(NounData {
-                    is_pronoun: Some(true),
-                    ..
-                }),
-                ..
-            })
-        )
+        let TokenKind::Word(metadata) = self else {
+            return false;
+        };
+
+        metadata.is_pronoun_noun()
     }
 
     pub fn is_conjunction(&self) -> bool {
-        matches!(
-            self,
-            TokenKind::Word(WordMetadata {
-                conjunction: Some(ConjunctionData {}),
-                ..
-            })
-        )
+        let TokenKind::Word(metadata) = self else {
+            return false;
+        };
+
+        metadata.is_conjunction()
     }
 
     fn is_chunk_terminator(&self) -> bool {
@@ -131,23 +123,19 @@ impl TokenKind {
     }
 
     pub fn is_adjective(&self) -> bool {
-        matches!(
-            self,
-            TokenKind::Word(WordMetadata {
-                adjective: Some(_),
-                ..
-            })
-        )
+        let TokenKind::Word(metadata) = self else {
+            return false;
+        };
+
+        metadata.is_adjective()
     }
 
     pub fn is_adverb(&self) -> bool {
-        matches!(
-            self,
-            TokenKind::Word(WordMetadata {
-                adverb: Some(_),
-                ..
-            })
-        )
+        let TokenKind::Word(metadata) = self else {
+            return false;
+        };
+
+        metadata.is_adverb()
     }
 
     pub fn is_swear(&self) -> bool {
@@ -13,6 +13,26 @@ pub struct WordMetadata {
     /// Whether the word is considered especially common.
     #[serde(default = "default_common")]
     pub common: bool,
+    /// The part of speech the word was resolved to in its sentence.
+    ///
+    /// Words straight from a dictionary are untagged. The tagger run by
+    /// [`Document`](crate::Document) sets this, after which the category
+    /// queries (like [`Self::is_noun`]) only report the chosen tag.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pos_tag: Option<PartOfSpeech>,
+}
+
+/// A part of speech a word can be tagged with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, PartialOrd, Is, Hash)]
+pub enum PartOfSpeech {
+    Noun,
+    Verb,
+    Adjective,
+    Adverb,
+    Conjunction,
+    /// Words like “the”, “a” or “my”, which the dictionary has no category
+    /// for.
+    Determiner,
 }
 
 /// Needed for `serde`
@@ -23,26 +43,34 @@ fn default_common() -> bool {
 macro_rules! generate_metadata_queries {
     ($($category:ident has $($sub:ident),*).*) => {
         paste! {
+            /// Checks whether the dictionary lists the word under more than
+            /// one category, regardless of how it was tagged.
             pub fn is_likely_homograph(&self) -> bool {
-                if [$($(self.[< is_ $sub _ $category >](),)*)*].iter().map(|b| *b as u8).sum::<u8>() > 1 {
+                if [$($(matches!(
+                    self.$category,
+                    Some([< $category:camel Data >]{ [< is_ $sub >]: Some(true), .. })
+                ),)*)*].iter().map(|b| *b as u8).sum::<u8>() > 1 {
                     return true;
                 }
 
                 [$(
-                    self.[< is_ $category >](),
+                    self.$category.is_some(),
                 )*].iter().map(|b| *b as u8).sum::<u8>() > 1
             }
 
             $(
                 #[doc = concat!("Checks if the word is definitely a ", stringify!($category), ".")]
                 pub fn [< is_ $category >](&self) -> bool {
-                    self.$category.is_some()
+                    match self.pos_tag {
+                        Some(tag) => tag == PartOfSpeech::[< $category:camel >],
+                        None => self.$category.is_some(),
+                    }
                 }
 
                 $(
                     #[doc = concat!("Checks if the word is definitely a ", stringify!($category), " and more specifically is labeled as (a) ", stringify!($sub), ".")]
                     pub fn [< is_ $sub _ $category >](&self) -> bool {
-                        matches!(
+                        self.[< is_ $category >]() && matches!(
                             self.$category,
                             Some([< $category:camel Data >]{
                                 [< is_ $sub >]: Some(true),
@@ -54,7 +82,7 @@ macro_rules! generate_metadata_queries {
 
                     #[doc = concat!("Checks if the word is definitely a ", stringify!($category), " and more specifically is labeled as __not__ (a) ", stringify!($sub), ".")]
                     pub fn [< is_not_ $sub _ $category >](&self) -> bool {
-                        matches!(
+                        self.[< is_ $category >]() && matches!(
                             self.$category,
                             Some([< $category:camel Data >]{
                                 [< is_ $sub >]: Some(false),
@@ -90,6 +118,7 @@ impl WordMetadata {
             conjunction: merge!(self.conjunction, other.conjunction),
             swear: self.swear.or(other.swear),
             common: self.common || other.common,
+            pos_tag: self.pos_tag.or(other.pos_tag),
         }
     }
 
@@ -101,6 +130,40 @@ impl WordMetadata {
         adverb has
     );
 
+    /// Checks whether the word was tagged as a determiner.
+    pub fn is_determiner(&self) -> bool {
+        self.pos_tag == Some(PartOfSpeech::Determiner)
+    }
+
+    /// Checks whether the word is definitely a verb, in the given form.
+    pub fn is_verb_form(&self, form: VerbForm) -> bool {
+        self.is_verb() && self.verb.is_some_and(|verb| verb.is_form(form))
+    }
+
+    /// The metadata an affix adds to a word derived from `parent`.
+    ///
+    /// Affixes like “-s” inflect more than one part of speech, so only the
+    /// parts of speech the parent has are kept: “cats” isn't made a verb, and
+    /// “runs” is only made a noun if “run” is one. If nothing is known about
+    /// the parent, everything is kept.
+    pub(crate) fn inflecting(&self, parent: &Self) -> Self {
+        let has_category = parent.noun.is_some()
+            || parent.verb.is_some()
+            || parent.adjective.is_some()
+            || parent.adverb.is_some()
+            || parent.conjunction.is_some();
+
+        if !has_category {
+            return *self;
+        }
+
+        Self {
+            noun: self.noun.filter(|_| parent.noun.is_some()),
+            verb: self.verb.filter(|_| parent.verb.is_some()),
+            ..*self
+        }
+    }
+
     /// Checks whether a word is _definitely_ a swear.
     pub fn is_swear(&self) -> bool {
         matches!(self.swear, Some(true))
@@ -120,10 +183,43 @@ pub enum Tense {
     Future,
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, PartialOrd, Eq, Hash)]
+/// The inflected forms of a verb.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, PartialOrd, Is, Hash)]
+pub enum VerbForm {
+    /// “walk”, “go”
+    Base,
+    /// “walks”, “goes”
+    ThirdPersonSingular,
+    /// “walked”, “went”
+    Past,
+    /// “walked”, “gone”
+    PastParticiple,
+    /// “walking”, “going”
+    Gerund,
+}
+
+impl VerbForm {
+    pub const ALL: [Self; 5] = [
+        Self::Base,
+        Self::ThirdPersonSingular,
+        Self::Past,
+        Self::PastParticiple,
+        Self::Gerund,
+    ];
+}
+
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq, PartialOrd, Eq, Hash)]
 pub struct VerbData {
     pub is_linking: Option<bool>,
     pub tense: Option<Tense>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_third_person_singular: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_past: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_past_participle: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_gerund: Option<bool>,
 }
 
 impl VerbData {
@@ -132,6 +228,46 @@ impl VerbData {
         Self {
             is_linking: self.is_linking.or(other.is_linking),
             tense: self.tense.or(other.tense),
+            is_third_person_singular: self
+                .is_third_person_singular
+                .or(other.is_third_person_singular),
+            is_past: self.is_past.or(other.is_past),
+            is_past_participle: self.is_past_participle.or(other.is_past_participle),
+            is_gerund: self.is_gerund.or(other.is_gerund),
+        }
+    }
+
+    /// Checks whether the verb is labeled as being in `form`.
+    ///
+    /// A verb that isn't labeled as any of the inflected forms is assumed to
+    /// be in its base form.
+    pub fn is_form(&self, form: VerbForm) -> bool {
+        let inflected = [
+            self.is_third_person_singular,
+            self.is_past,
+            self.is_past_participle,
+            self.is_gerund,
+        ];
+
+        match form {
+            VerbForm::Base => !inflected.contains(&Some(true)),
+            VerbForm::ThirdPersonSingular => self.is_third_person_singular == Some(true),
+            VerbForm::Past => self.is_past == Some(true),
+            VerbForm::PastParticiple => self.is_past_participle == Some(true),
+            VerbForm::Gerund => self.is_gerund == Some(true),
+        }
+    }
+
+    /// A verb labeled as being in only `form`.
+    pub fn with_form(form: VerbForm) -> Self {
+        let is = |f: VerbForm| (form == f).then_some(true);
+
+        Self {
+            is_third_person_singular: is(VerbForm::ThirdPersonSingular),
+            is_past: is(VerbForm::Past),
+            is_past_participle: is(VerbForm::PastParticiple),
+            is_gerund: is(VerbForm::Gerund),
+            ..Default::default()
         }
     }
 }
@@ -0,0 +1,67 @@
+use harper_core::{suggest_correct_spelling_str, FstDictionary};
+
+static TYPOS: &str = include_str!("./typos.txt");
+
+/// Suggestions for each typo in `typos.txt`, backing off to larger edit
+/// distances the same way the spell checker does.
+fn suggestions_for_typos() -> Vec<(&'static str, &'static str, Vec<String>)> {
+    let dictionary = FstDictionary::curated();
+
+    TYPOS
+        .lines()
+        .filter(|line| !line.starts_with('#'))
+        .filter_map(|line| line.split_once(' '))
+        .map(|(typo, expected)| {
+            let mut suggestions = Vec::new();
+            let mut dist = 2;
+
+            while suggestions.is_empty() && dist < 5 {
+                suggestions = suggest_correct_spelling_str(typo, 100, dist, &dictionary);
+                dist += 1;
+            }
+
+            (typo, expected, suggestions)
+        })
+        .collect()
+}
+
+/// The fraction of typos whose intended word is within the first `n`
+/// suggestions, along with a description of each one that isn't.
+fn accuracy_within(n: usize) -> (f32, Vec<String>) {
+    let results = suggestions_for_typos();
+
+    let misses: Vec<String> = results
+        .iter()
+        .filter(|(_, expected, suggestions)| !suggestions.iter().take(n).any(|s| s == expected))
+        .map(|(typo, expected, suggestions)| {
+            format!(
+                "“{typo}” → expected “{expected}”, got {:?}",
+                &suggestions[..suggestions.len().min(n)]
+            )
+        })
+        .collect();
+
+    let accuracy = 1.0 - misses.len() as f32 / results.len() as f32;
+
+    (accuracy, misses)
+}
+
+#[test]
+fn best_suggestion_is_usually_right() {
+    let (accuracy, misses) = accuracy_within(1);
+    assert!(
+        accuracy >= 0.85,
+        "accuracy was {accuracy}, missing:\n{}",
+        misses.join("\n")
+    );
+}
+
+#[test]
+fn right_suggestion_is_almost_always_shown() {
+    let (accuracy, misses) = accuracy_within(3);
+    assert!(
+        accuracy >= 0.95,
+        "accuracy was {accuracy}, missing:\n{}",
+        misses.join("\n")
+    );
+}
@@ -1,14 +1,15 @@
 use std::collections::HashMap;
-use std::path::{Component, PathBuf};
+use std::path::{Component, Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 
 use anyhow::anyhow;
 use harper_comments::CommentParser;
-use harper_core::linting::{LintGroup, Linter};
+use harper_core::linting::{LintGroup, ReplacementRules};
 use harper_core::parsers::{CollapseIdentifiers, IsolateEnglish, Markdown, Parser, PlainEnglish};
 use harper_core::{
-    Dictionary, Document, FstDictionary, FullDictionary, MergedDictionary, Token, TokenKind,
-    WordMetadata,
+    Dialect, Document, FstDictionary, FullDictionary, MergedDictionary, ProjectConfig, Token,
+    TokenKind, PROJECT_CONFIG_FILENAMES,
 };
 use harper_html::HtmlParser;
 use serde_json::Value;
@@ -18,18 +19,21 @@ use tower_lsp::lsp_types::notification::PublishDiagnostics;
 use tower_lsp::lsp_types::{
     CodeActionOrCommand, CodeActionParams, CodeActionProviderCapability, CodeActionResponse,
     Command, ConfigurationItem, Diagnostic, DidChangeConfigurationParams,
-    DidChangeTextDocumentParams, DidCloseTextDocumentParams, DidOpenTextDocumentParams,
-    DidSaveTextDocumentParams, ExecuteCommandOptions, ExecuteCommandParams, InitializeParams,
-    InitializeResult, InitializedParams, MessageType, PublishDiagnosticsParams, Range,
-    ServerCapabilities, TextDocumentSyncCapability, TextDocumentSyncKind, TextDocumentSyncOptions,
-    TextDocumentSyncSaveOptions, Url,
+    DidChangeTextDocumentParams, DidChangeWatchedFilesParams,
+    DidChangeWatchedFilesRegistrationOptions, DidChangeWorkspaceFoldersParams,
+    DidCloseTextDocumentParams, DidOpenTextDocumentParams, DidSaveTextDocumentParams,
+    ExecuteCommandOptions, ExecuteCommandParams, FileSystemWatcher, GlobPattern, InitializeParams,
+    InitializeResult, InitializedParams, MessageType, OneOf, PublishDiagnosticsParams, Range,
+    Registration, ServerCapabilities, TextDocumentContentChangeEvent, TextDocumentSyncCapability,
+    TextDocumentSyncKind, TextDocumentSyncOptions, TextDocumentSyncSaveOptions, Unregistration,
+    Url, WorkspaceFoldersServerCapabilities, WorkspaceServerCapabilities,
 };
 use tower_lsp::{Client, LanguageServer};
-use tracing::{error, info};
+use tracing::{error, info, warn};
 
 use crate::config::Config;
 use crate::diagnostics::{lint_to_code_actions, lints_to_diagnostics};
-use crate::dictionary_io::{load_dict, save_dict};
+use crate::dictionary_io::{append_to_dict, load_dict};
 use crate::document_state::DocumentState;
 use crate::git_commit_parser::GitCommitParser;
 use crate::pos_conv::range_to_span;
@@ -38,6 +42,27 @@ pub struct Backend {
     client: Client,
     config: RwLock<Config>,
     doc_state: Mutex<HashMap<Url, DocumentState>>,
+    /// Hunspell and compiled dictionaries, keyed by the path of their `.dic`
+    /// or `.fst` file, or `None` if they couldn't be loaded.
+    /// They are slow to load, so they are only loaded again when one of
+    /// their files changes.
+    external_dictionaries: Mutex<HashMap<PathBuf, Option<Arc<FstDictionary>>>>,
+    /// The project configuration that applies to each directory, or `None`
+    /// for directories without one.
+    /// Cleared whenever a configuration file changes.
+    project_configs: Mutex<HashMap<PathBuf, Option<Arc<ProjectConfig>>>>,
+    /// Project dictionaries, keyed by path.
+    /// Entries are removed when the file they were read from changes.
+    project_dictionaries: Mutex<HashMap<PathBuf, Option<Arc<FullDictionary>>>>,
+    /// Replacement rules, keyed by the path of the file they were read from.
+    /// Entries are removed when that file changes.
+    replacement_rules: Mutex<HashMap<PathBuf, Option<Arc<ReplacementRules>>>>,
+    /// The glob patterns the client is currently watching on our behalf.
+    watched_globs: Mutex<Vec<String>>,
+    /// The root of each workspace folder open in the editor.
+    workspace_folders: RwLock<Vec<PathBuf>>,
+    /// Whether the client can watch files on our behalf.
+    can_watch_files: AtomicBool,
 }
 
 impl Backend {
@@ -46,6 +71,13 @@ impl Backend {
             client,
             doc_state: Mutex::new(HashMap::new()),
             config: RwLock::new(config),
+            external_dictionaries: Mutex::new(HashMap::new()),
+            project_configs: Mutex::new(HashMap::new()),
+            project_dictionaries: Mutex::new(HashMap::new()),
+            replacement_rules: Mutex::new(HashMap::new()),
+            watched_globs: Mutex::new(Vec::new()),
+            workspace_folders: RwLock::new(Vec::new()),
+            can_watch_files: AtomicBool::new(false),
         }
     }
 
@@ -80,16 +112,77 @@ impl Backend {
         }
     }
 
-    async fn save_file_dictionary(&self, url: &Url, dict: impl Dictionary) -> anyhow::Result<()> {
-        Ok(save_dict(
+    async fn add_to_file_dictionary(&self, url: &Url, word: &str) -> anyhow::Result<()> {
+        Ok(append_to_dict(
             self.get_file_dict_path(url)
                 .await
                 .ok_or(anyhow!("Could not compute dictionary path."))?,
-            dict,
+            word,
         )
         .await?)
     }
 
+    /// Get the location of the dictionary for the workspace containing a file,
+    /// if it is in one.
+    async fn get_workspace_dict_path(&self, url: &Url) -> Option<PathBuf> {
+        let path = url.to_file_path().ok()?;
+        let workspace_folders = self.workspace_folders.read().await;
+
+        workspace_dict_path(
+            &workspace_folders,
+            &self.config.read().await.workspace_dict_path,
+            &path,
+        )
+    }
+
+    async fn load_workspace_dictionary(&self, url: &Url) -> Option<FullDictionary> {
+        let path = self.get_workspace_dict_path(url).await?;
+
+        match load_dict(&path).await {
+            Ok(dict) => Some(dict),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => None,
+            Err(err) => {
+                warn!(
+                    "Unable to load workspace dictionary at {}: {}",
+                    path.display(),
+                    err
+                );
+                None
+            }
+        }
+    }
+
+    async fn add_to_workspace_dictionary(&self, url: &Url, word: &str) -> anyhow::Result<()> {
+        Ok(append_to_dict(
+            self.get_workspace_dict_path(url)
+                .await
+                .ok_or(anyhow!("{} is not in a workspace.", url))?,
+            word,
+        )
+        .await?)
+    }
+
+    /// Lint every open document again, for when something they all depend on
+    /// (like a dictionary) has changed.
+    async fn refresh_documents(&self) {
+        let documents: Vec<(Url, String)> = {
+            let doc_lock = self.doc_state.lock().await;
+
+            doc_lock
+                .iter()
+                .map(|(url, doc_state)| (url.clone(), doc_state.document.get_full_string()))
+                .collect()
+        };
+
+        for (url, text) in documents {
+            if let Err(err) = self.update_document(&url, &text, None).await {
+                error!("Unable to update {}: {}", url, err);
+            }
+
+            self.publish_diagnostics(&url).await;
+        }
+    }
+
     async fn load_user_dictionary(&self) -> FullDictionary {
         let config = self.config.read().await;
 
@@ -99,24 +192,303 @@ impl Backend {
         }
     }
 
-    async fn save_user_dictionary(&self, dict: impl Dictionary) -> anyhow::Result<()> {
+    async fn add_to_user_dictionary(&self, word: &str) -> anyhow::Result<()> {
         let config = self.config.read().await;
 
-        Ok(save_dict(&config.user_dict_path, dict).await?)
+        Ok(append_to_dict(&config.user_dict_path, word).await?)
     }
 
-    async fn generate_global_dictionary(&self) -> anyhow::Result<MergedDictionary> {
+    async fn generate_global_dictionary(
+        &self,
+        dialect: Dialect,
+    ) -> anyhow::Result<MergedDictionary> {
         let mut dict = MergedDictionary::new();
-        dict.add_dictionary(FstDictionary::curated());
+        dict.add_dictionary(FstDictionary::curated_dialect(dialect));
         let user_dict = self.load_user_dictionary().await;
         dict.add_dictionary(Arc::new(user_dict));
         Ok(dict)
     }
 
-    async fn generate_file_dictionary(&self, url: &Url) -> anyhow::Result<MergedDictionary> {
-        let (global_dictionary, file_dictionary) = tokio::join!(
-            self.generate_global_dictionary(),
-            self.load_file_dictionary(url)
+    /// Search for a `harper.toml` or `.harper.json` that applies to a file.
+    async fn load_project_config(&self, url: &Url) -> Option<Arc<ProjectConfig>> {
+        let path = url.to_file_path().ok()?;
+        let dir = path.parent()?.to_path_buf();
+
+        let project_config = {
+            let mut cache = self.project_configs.lock().await;
+
+            if let Some(project_config) = cache.get(&dir) {
+                return project_config.clone();
+            }
+
+            let project_config =
+                match tokio::task::spawn_blocking(move || ProjectConfig::discover(path)).await {
+                    Ok(Ok(project_config)) => project_config.map(Arc::new),
+                    Ok(Err(err)) => {
+                        warn!("Unable to load project configuration: {}", err);
+                        None
+                    }
+                    Err(err) => {
+                        error!("Unable to load project configuration: {}", err);
+                        None
+                    }
+                };
+
+            cache.insert(dir, project_config.clone());
+            project_config
+        };
+
+        // The configuration may point at files we aren't watching yet.
+        self.watch_files().await;
+
+        project_config
+    }
+
+    async fn load_project_dictionary(
+        &self,
+        project_config: Option<&ProjectConfig>,
+    ) -> Option<Arc<FullDictionary>> {
+        let path = project_config?.dictionary_path()?;
+        let mut cache = self.project_dictionaries.lock().await;
+
+        if let Some(dict) = cache.get(&path) {
+            return dict.clone();
+        }
+
+        let dict = match load_dict(&path).await {
+            Ok(dict) => Some(Arc::new(dict)),
+            Err(err) => {
+                warn!(
+                    "Unable to load project dictionary at {}: {}",
+                    path.display(),
+                    err
+                );
+                None
+            }
+        };
+
+        cache.insert(path, dict.clone());
+        dict
+    }
+
+    /// The glob patterns of every file whose changes should be picked up
+    /// without the editor telling us about them, like when pulling from
+    /// version control.
+    async fn watched_globs(&self) -> Vec<String> {
+        let (workspace_dict_path, user_rules_path) = {
+            let config = self.config.read().await;
+            (
+                config.workspace_dict_path.clone(),
+                config.replacement_rules_path.clone(),
+            )
+        };
+
+        let mut globs = vec![format!("**/{}", workspace_dict_path.to_string_lossy())];
+        globs.extend(PROJECT_CONFIG_FILENAMES.map(|filename| format!("**/{filename}")));
+
+        let mut paths: Vec<PathBuf> = user_rules_path.into_iter().collect();
+
+        for project_config in self.project_configs.lock().await.values().flatten() {
+            paths.extend(project_config.dictionary_path());
+            paths.extend(project_config.replacement_rules_path());
+
+            for (a, b) in project_config
+                .hunspell_dictionary_paths()
+                .into_iter()
+                .chain(project_config.compiled_dictionary_paths())
+            {
+                paths.extend([a, b]);
+            }
+        }
+
+        for path in paths {
+            if let Some(filename) = path.file_name() {
+                globs.push(format!("**/{}", filename.to_string_lossy()));
+            }
+        }
+
+        globs.sort();
+        globs.dedup();
+        globs
+    }
+
+    /// Ask the client to watch [`Self::watched_globs`], if they have changed
+    /// since we last asked.
+    async fn watch_files(&self) {
+        if !self.can_watch_files.load(Ordering::Relaxed) {
+            return;
+        }
+
+        let globs = self.watched_globs().await;
+        let mut watched_globs = self.watched_globs.lock().await;
+
+        if *watched_globs == globs {
+            return;
+        }
+
+        const ID: &str = "harper-watched-files";
+        const METHOD: &str = "workspace/didChangeWatchedFiles";
+
+        if !watched_globs.is_empty() {
+            let unregistration = Unregistration {
+                id: ID.to_owned(),
+                method: METHOD.to_owned(),
+            };
+
+            if let Err(err) = self
+                .client
+                .unregister_capability(vec![unregistration])
+                .await
+            {
+                warn!("Unable to stop watching files: {}", err);
+            }
+        }
+
+        let options = DidChangeWatchedFilesRegistrationOptions {
+            watchers: globs
+                .iter()
+                .map(|glob| FileSystemWatcher {
+                    glob_pattern: GlobPattern::String(glob.clone()),
+                    kind: None,
+                })
+                .collect(),
+        };
+
+        let registration = Registration {
+            id: ID.to_owned(),
+            method: METHOD.to_owned(),
+            register_options: serde_json::to_value(options).ok(),
+        };
+
+        match self.client.register_capability(vec![registration]).await {
+            Ok(()) => *watched_globs = globs,
+            Err(err) => warn!("Unable to watch files: {}", err),
+        }
+    }
+
+    /// Load the Hunspell and compiled dictionaries configured by the project.
+    async fn load_external_dictionaries(
+        &self,
+        project_config: Option<&ProjectConfig>,
+    ) -> Vec<Arc<FstDictionary>> {
+        let Some(project_config) = project_config else {
+            return Vec::new();
+        };
+
+        type Loader = Box<dyn FnOnce() -> anyhow::Result<FstDictionary> + Send>;
+
+        let hunspell =
+            project_config
+                .hunspell_dictionary_paths()
+                .into_iter()
+                .map(|(aff_path, dic_path)| {
+                    let path = dic_path.clone();
+                    let loader: Loader =
+                        Box::new(move || Ok(FstDictionary::from_hunspell_files(aff_path, path)?));
+                    (dic_path, loader)
+                });
+
+        let compiled = project_config.compiled_dictionary_paths().into_iter().map(
+            |(fst_path, metadata_path)| {
+                let path = fst_path.clone();
+                let loader: Loader =
+                    Box::new(move || Ok(FstDictionary::read_compiled(path, metadata_path)?));
+                (fst_path, loader)
+            },
+        );
+
+        let mut cache = self.external_dictionaries.lock().await;
+        let mut dictionaries = Vec::new();
+
+        for (path, loader) in hunspell.chain(compiled) {
+            if let Some(dictionary) = cache.get(&path) {
+                dictionaries.extend(dictionary.clone());
+                continue;
+            }
+
+            let dictionary = match tokio::task::spawn_blocking(loader).await {
+                Ok(Ok(dictionary)) => Some(Arc::new(dictionary)),
+                Ok(Err(err)) => {
+                    warn!("Unable to load dictionary at {}: {}", path.display(), err);
+                    None
+                }
+                Err(err) => {
+                    error!("{}", err);
+                    None
+                }
+            };
+
+            dictionaries.extend(dictionary.clone());
+            cache.insert(path, dictionary);
+        }
+
+        dictionaries
+    }
+
+    /// Load the replacement rules configured in the editor, along with those
+    /// configured by the project.
+    async fn load_replacement_rules(
+        &self,
+        project_config: Option<&ProjectConfig>,
+    ) -> ReplacementRules {
+        let user_path = self.config.read().await.replacement_rules_path.clone();
+        let project_path = project_config.and_then(|config| config.replacement_rules_path());
+
+        let mut cache = self.replacement_rules.lock().await;
+        let mut rules = ReplacementRules::new();
+
+        for path in [user_path, project_path].into_iter().flatten() {
+            if let Some(loaded) = cache.get(&path) {
+                if let Some(loaded) = loaded {
+                    rules.extend(ReplacementRules::clone(loaded));
+                }
+                continue;
+            }
+
+            let location = path.display().to_string();
+            let to_load = path.clone();
+
+            let loaded =
+                match tokio::task::spawn_blocking(move || ReplacementRules::load(to_load)).await {
+                    Ok(Ok(loaded)) => Some(Arc::new(loaded)),
+                    Ok(Err(err)) => {
+                        warn!("Unable to load replacement rules at {}: {}", location, err);
+                        None
+                    }
+                    Err(err) => {
+                        error!("Unable to load replacement rules at {}: {}", location, err);
+                        None
+                    }
+                };
+
+            if let Some(loaded) = &loaded {
+                rules.extend(ReplacementRules::clone(loaded));
+            }
+
+            cache.insert(path, loaded);
+        }
+
+        rules
+    }
+
+    async fn generate_file_dictionary(
+        &self,
+        url: &Url,
+        project_config: Option<&ProjectConfig>,
+        dialect: Dialect,
+    ) -> anyhow::Result<MergedDictionary> {
+        let (
+            global_dictionary,
+            file_dictionary,
+            workspace_dictionary,
+            project_dictionary,
+            external_dictionaries,
+        ) = tokio::join!(
+            self.generate_global_dictionary(dialect),
+            self.load_file_dictionary(url),
+            self.load_workspace_dictionary(url),
+            self.load_project_dictionary(project_config),
+            self.load_external_dictionaries(project_config)
         );
 
         let Some(file_dictionary) = file_dictionary else {
@@ -126,6 +498,18 @@ impl Backend {
         let mut global_dictionary = global_dictionary?;
         global_dictionary.add_dictionary(Arc::new(file_dictionary));
 
+        if let Some(workspace_dictionary) = workspace_dictionary {
+            global_dictionary.add_dictionary(Arc::new(workspace_dictionary));
+        }
+
+        if let Some(project_dictionary) = project_dictionary {
+            global_dictionary.add_dictionary(project_dictionary);
+        }
+
+        for external_dictionary in external_dictionaries {
+            global_dictionary.add_dictionary(external_dictionary);
+        }
+
         Ok(global_dictionary)
     }
 
@@ -158,13 +542,34 @@ impl Backend {
     ) -> anyhow::Result<()> {
         self.pull_config().await;
 
+        let project_config = self.load_project_config(url).await;
+        let replacement_rules = self.load_replacement_rules(project_config.as_deref()).await;
+
         let mut doc_lock = self.doc_state.lock().await;
         let config_lock = self.config.read().await;
 
-        let dict = Arc::new(self.generate_file_dictionary(url).await?);
+        if let (Some(project_config), Ok(path)) = (&project_config, url.to_file_path()) {
+            if project_config.is_ignored(path) {
+                doc_lock.remove(url);
+                return Ok(());
+            }
+        }
+
+        // Changes to a document don't repeat its language ID.
+        let known_language_id = doc_lock.get(url).and_then(|doc| doc.language_id.clone());
+        let lint_config = config_lock.lint_config_for(
+            language_id.or(known_language_id.as_deref()),
+            project_config.as_deref(),
+        );
+        let dialect = lint_config.dialect.unwrap_or_default();
+
+        let dict = Arc::new(
+            self.generate_file_dictionary(url, project_config.as_deref(), dialect)
+                .await?,
+        );
 
         let doc_state = doc_lock.entry(url.clone()).or_insert(DocumentState {
-            linter: LintGroup::new(config_lock.lint_config, dict.clone()),
+            linter: LintGroup::new(lint_config.clone(), dict.clone()),
             language_id: language_id.map(|v| v.to_string()),
             dict: dict.clone(),
             ..Default::default()
@@ -172,7 +577,16 @@ impl Backend {
 
         if doc_state.dict != dict {
             doc_state.dict = dict.clone();
-            doc_state.linter = LintGroup::new(config_lock.lint_config, dict.clone());
+            doc_state.linter = LintGroup::new(lint_config.clone(), dict.clone());
+            doc_state.lint_cache.clear();
+        }
+
+        if doc_state.linter.config != lint_config
+            || doc_state.linter.replacement_rules != replacement_rules
+        {
+            doc_state.linter.config = lint_config.clone();
+            doc_state.linter.replacement_rules = replacement_rules.clone();
+            doc_state.lint_cache.clear();
         }
 
         let Some(language_id) = &doc_state.language_id else {
@@ -180,8 +594,12 @@ impl Backend {
             return Ok(());
         };
 
+        if doc_state.comment_parser.is_none() {
+            doc_state.comment_parser = CommentParser::new_from_language_id(language_id);
+        }
+
         let parser: Option<Box<dyn Parser>> =
-            if let Some(ts_parser) = CommentParser::new_from_language_id(language_id) {
+            if let Some(ts_parser) = doc_state.comment_parser.clone() {
                 let source: Vec<char> = text.chars().collect();
                 let source = Arc::new(source);
 
@@ -190,11 +608,15 @@ impl Backend {
 
                     if doc_state.ident_dict != new_dict {
                         doc_state.ident_dict = new_dict.clone();
-                        let mut merged = self.generate_file_dictionary(url).await?;
+                        let mut merged = self
+                            .generate_file_dictionary(url, project_config.as_deref(), dialect)
+                            .await?;
                         merged.add_dictionary(new_dict);
                         let merged = Arc::new(merged);
 
-                        doc_state.linter = LintGroup::new(config_lock.lint_config, merged.clone());
+                        doc_state.linter = LintGroup::new(lint_config, merged.clone());
+                        doc_state.linter.replacement_rules = replacement_rules;
+                        doc_state.lint_cache.clear();
                         doc_state.dict = merged.clone();
                     }
                     Some(Box::new(CollapseIdentifiers::new(
@@ -204,16 +626,8 @@ impl Backend {
                 } else {
                     Some(Box::new(ts_parser))
                 }
-            } else if language_id == "markdown" {
-                Some(Box::new(Markdown))
-            } else if language_id == "git-commit" {
-                Some(Box::new(GitCommitParser))
-            } else if language_id == "html" {
-                Some(Box::new(HtmlParser::default()))
-            } else if language_id == "mail" || language_id == "plaintext" {
-                Some(Box::new(PlainEnglish))
             } else {
-                None
+                prose_parser(language_id)
             };
 
         match parser {
@@ -226,12 +640,67 @@ impl Backend {
                 }
 
                 doc_state.document = Document::new(text, &mut parser, &doc_state.dict);
+                doc_state.project_config = project_config;
             }
         }
 
         Ok(())
     }
 
+    /// Apply changes from the client to a document in place, re-parsing only
+    /// the paragraphs they touch.
+    ///
+    /// Source code is handled by a full update instead, since the identifiers
+    /// it contains affect how it is parsed.
+    /// In that case, the new text of the document is returned.
+    async fn apply_changes(
+        &self,
+        url: &Url,
+        changes: Vec<TextDocumentContentChangeEvent>,
+    ) -> Option<String> {
+        let isolate_english = self.config.read().await.isolate_english;
+        let mut doc_lock = self.doc_state.lock().await;
+        let doc_state = doc_lock.get_mut(url)?;
+
+        let Some(mut parser) = doc_state.language_id.as_deref().and_then(prose_parser) else {
+            let mut source = doc_state.document.get_source().to_vec();
+
+            for change in changes {
+                match change.range {
+                    Some(range) => {
+                        let span = range_to_span(&source, range);
+                        source.splice(span.start..span.end, change.text.chars());
+                    }
+                    None => source = change.text.chars().collect(),
+                }
+            }
+
+            return Some(source.into_iter().collect());
+        };
+
+        if isolate_english {
+            parser = Box::new(IsolateEnglish::new(parser, doc_state.dict.clone()));
+        }
+
+        for change in changes {
+            match change.range {
+                Some(range) => {
+                    let span = range_to_span(doc_state.document.get_source(), range);
+                    let replacement: Vec<char> = change.text.chars().collect();
+
+                    doc_state
+                        .document
+                        .apply_edit(span, &replacement, &mut parser, &doc_state.dict);
+                }
+                None => {
+                    doc_state.document = Document::new(&change.text, &mut parser, &doc_state.dict)
+                }
+            }
+        }
+
+        None
+    }
+
     async fn generate_code_actions(
         &self,
         url: &Url,
@@ -242,7 +711,9 @@ impl Backend {
             return Ok(Vec::new());
         };
 
-        let mut lints = doc_state.linter.lint(&doc_state.document);
+        let mut lints = doc_state
+            .linter
+            .lint_with_cache(&mut doc_state.lint_cache, &doc_state.document);
         lints.sort_by_key(|l| l.priority);
 
         let source_chars = doc_state.document.get_full_content();
@@ -250,11 +721,19 @@ impl Backend {
         // Find lints whole span overlaps with range
         let span = range_to_span(source_chars, range).with_len(1);
 
+        let in_workspace = self.get_workspace_dict_path(url).await.is_some();
+
         let mut actions: Vec<CodeActionOrCommand> = lints
             .into_iter()
             .filter(|lint| lint.span.overlaps_with(span))
             .flat_map(|lint| {
-                lint_to_code_actions(&lint, url, source_chars, &config.code_action_config)
+                lint_to_code_actions(
+                    &lint,
+                    url,
+                    source_chars,
+                    &config.code_action_config,
+                    in_workspace,
+                )
             })
             .collect();
 
@@ -280,14 +759,22 @@ impl Backend {
             return Vec::new();
         };
 
-        let lints = doc_state.linter.lint(&doc_state.document);
+        let lints = doc_state
+            .linter
+            .lint_with_cache(&mut doc_state.lint_cache, &doc_state.document);
         let config = self.config.read().await;
 
-        lints_to_diagnostics(
-            doc_state.document.get_full_content(),
-            &lints,
-            config.diagnostic_severity,
-        )
+        let mut severity = config.severity.clone();
+        if let Some(project_config) = &doc_state.project_config {
+            severity.override_with(&project_config.severity);
+        }
+
+        lints_to_diagnostics(doc_state.document.get_full_content(), &lints, |lint| {
+            severity
+                .get(lint)
+                .map(Into::into)
+                .unwrap_or(config.diagnostic_severity)
+        })
     }
 
     async fn publish_diagnostics(&self, url: &Url) {
@@ -319,6 +806,10 @@ impl Backend {
             let mut config = self.config.write().await;
             *config = new_config;
         }
+
+        // The user dictionary, workspace dictionary or replacement rules may
+        // have moved.
+        self.watch_files().await;
     }
 
     async fn pull_config(&self) {
@@ -339,7 +830,34 @@ impl Backend {
 
 #[tower_lsp::async_trait]
 impl LanguageServer for Backend {
-    async fn initialize(&self, _: InitializeParams) -> Result<InitializeResult> {
+    async fn initialize(&self, params: InitializeParams) -> Result<InitializeResult> {
+        #[allow(deprecated)]
+        let workspace_folders = match (params.workspace_folders, params.root_uri) {
+            (Some(folders), _) => folders.into_iter().map(|folder| folder.uri).collect(),
+            (None, Some(root_uri)) => vec![root_uri],
+            (None, None) => Vec::new(),
+        };
+
+        {
+            let mut folders = self.workspace_folders.write().await;
+
+            for path in workspace_folders
+                .iter()
+                .filter_map(|uri| uri.to_file_path().ok())
+            {
+                add_workspace_folder(&mut folders, path);
+            }
+        }
+
+        let can_watch_files = params
+            .capabilities
+            .workspace
+            .and_then(|workspace| workspace.did_change_watched_files)
+            .and_then(|watched_files| watched_files.dynamic_registration)
+            .unwrap_or(false);
+        self.can_watch_files
+            .store(can_watch_files, Ordering::Relaxed);
+
         Ok(InitializeResult {
             server_info: None,
             capabilities: ServerCapabilities {
@@ -348,14 +866,22 @@ impl LanguageServer for Backend {
                     commands: vec![
                         "HarperAddToUserDict".to_owned(),
                         "HarperAddToFileDict".to_owned(),
+                        "HarperAddToWorkspaceDict".to_owned(),
                         "HarperOpen".to_owned(),
                     ],
                     ..Default::default()
                 }),
+                workspace: Some(WorkspaceServerCapabilities {
+                    workspace_folders: Some(WorkspaceFoldersServerCapabilities {
+                        supported: Some(true),
+                        change_notifications: Some(OneOf::Left(true)),
+                    }),
+                    file_operations: None,
+                }),
                 text_document_sync: Some(TextDocumentSyncCapability::Options(
                     TextDocumentSyncOptions {
                         open_close: Some(true),
-                        change: Some(TextDocumentSyncKind::FULL),
+                        change: Some(TextDocumentSyncKind::INCREMENTAL),
                         will_save: None,
                         will_save_wait_until: None,
                         save: Some(TextDocumentSyncSaveOptions::Supported(true)),
@@ -372,6 +898,63 @@ impl LanguageServer for Backend {
             .await;
 
         self.pull_config().await;
+        self.watch_files().await;
+    }
+
+    async fn did_change_watched_files(&self, params: DidChangeWatchedFilesParams) {
+        let changed: Vec<PathBuf> = params
+            .changes
+            .iter()
+            .filter_map(|change| change.uri.to_file_path().ok())
+            .collect();
+
+        // A new or removed configuration file can change which one applies
+        // to any directory.
+        if changed.iter().any(|path| {
+            path.file_name()
+                .is_some_and(|filename| PROJECT_CONFIG_FILENAMES.iter().any(|f| filename == *f))
+        }) {
+            self.project_configs.lock().await.clear();
+        }
+
+        {
+            let (mut project_dictionaries, mut replacement_rules, mut external_dictionaries) = tokio::join!(
+                self.project_dictionaries.lock(),
+                self.replacement_rules.lock(),
+                self.external_dictionaries.lock()
+            );
+
+            for path in &changed {
+                project_dictionaries.remove(path);
+                replacement_rules.remove(path);
+                // Either of a dictionary's two files may have changed.
+                external_dictionaries
+                    .retain(|key, _| key.with_extension("") != path.with_extension(""));
+            }
+        }
+
+        self.refresh_documents().await;
+        self.watch_files().await;
+    }
+
+    async fn did_change_workspace_folders(&self, params: DidChangeWorkspaceFoldersParams) {
+        {
+            let mut workspace_folders = self.workspace_folders.write().await;
+
+            for removed in params.event.removed {
+                if let Ok(path) = removed.uri.to_file_path() {
+                    workspace_folders.retain(|folder| *folder != path);
+                }
+            }
+
+            for added in params.event.added {
+                if let Ok(path) = added.uri.to_file_path() {
+                    add_workspace_folder(&mut workspace_folders, path);
+                }
+            }
+        }
+
+        self.refresh_documents().await;
     }
 
     async fn did_save(&self, params: DidSaveTextDocumentParams) {
@@ -395,14 +978,13 @@ impl LanguageServer for Backend {
     }
 
     async fn did_change(&self, params: DidChangeTextDocumentParams) {
-        let Some(last) = params.content_changes.last() else {
-            return;
-        };
+        let url = &params.text_document.uri;
 
-        self.update_document(&params.text_document.uri, &last.text, None)
-            .await
-            .unwrap();
-        self.publish_diagnostics(&params.text_document.uri).await;
+        if let Some(text) = self.apply_changes(url, params.content_changes).await {
+            self.update_document(url, &text, None).await.unwrap();
+        }
+
+        self.publish_diagnostics(url).await;
     }
 
     async fn did_close(&self, _params: DidCloseTextDocumentParams) {}
@@ -421,36 +1003,41 @@ impl LanguageServer for Backend {
 
         match params.command.as_str() {
             "HarperAddToUserDict" => {
-                let word = &first.chars().collect::<Vec<_>>();
-
                 let Some(second) = string_args.next() else {
                     return Ok(None);
                 };
 
                 let file_url = second.parse().unwrap();
 
-                let mut dict = self.load_user_dictionary().await;
-                dict.append_word(word, WordMetadata::default());
-                let _ = self.save_user_dictionary(dict).await;
+                if let Err(err) = self.add_to_user_dictionary(&first).await {
+                    error!("Unable to add to user dictionary: {}", err);
+                }
                 let _ = self.update_document_from_file(&file_url, None).await;
                 self.publish_diagnostics(&file_url).await;
             }
             "HarperAddToFileDict" => {
-                let word = &first.chars().collect::<Vec<_>>();
-
                 let Some(second) = string_args.next() else {
                     return Ok(None);
                 };
 
                 let file_url = second.parse().unwrap();
 
-                let Some(mut dict) = self.load_file_dictionary(&file_url).await else {
-                    error!("Unable resolve dictionary path: {file_url}");
+                if let Err(err) = self.add_to_file_dictionary(&file_url, &first).await {
+                    error!("Unable to add to file dictionary: {}", err);
+                }
+                let _ = self.update_document_from_file(&file_url, None).await;
+                self.publish_diagnostics(&file_url).await;
+            }
+            "HarperAddToWorkspaceDict" => {
+                let Some(second) = string_args.next() else {
                     return Ok(None);
                 };
-                dict.append_word(word, WordMetadata::default());
 
-                let _ = self.save_file_dictionary(&file_url, dict).await;
+                let file_url = second.parse().unwrap();
+
+                if let Err(err) = self.add_to_workspace_dictionary(&file_url, &first).await {
+                    error!("Unable to add to workspace dictionary: {}", err);
+                }
                 let _ = self.update_document_from_file(&file_url, None).await;
                 self.publish_diagnostics(&file_url).await;
             }
@@ -483,7 +1070,10 @@ impl LanguageServer for Backend {
             let config_lock = self.config.read().await;
 
             for doc in doc_lock.values_mut() {
-                doc.linter = LintGroup::new(config_lock.lint_config, doc.dict.clone());
+                let lint_config = config_lock
+                    .lint_config_for(doc.language_id.as_deref(), doc.project_config.as_deref());
+                doc.linter = LintGroup::new(lint_config, doc.dict.clone());
+                doc.lint_cache.clear();
             }
 
             doc_lock.keys().cloned().collect()
@@ -522,3 +1112,110 @@ impl LanguageServer for Backend {
         Ok(())
     }
 }
+
+/// Get the parser for a language that isn't source code.
+fn prose_parser(language_id: &str) -> Option<Box<dyn Parser>> {
+    match language_id {
+        "markdown" => Some(Box::new(Markdown)),
+        "git-commit" => Some(Box::new(GitCommitParser)),
+        "html" => Some(Box::new(HtmlParser::default())),
+        "mail" | "plaintext" => Some(Box::new(PlainEnglish)),
+        _ => None,
+    }
+}
+
+/// Add a workspace folder, unless it is already open.
+fn add_workspace_folder(workspace_folders: &mut Vec<PathBuf>, path: PathBuf) {
+    if !workspace_folders.contains(&path) {
+        workspace_folders.push(path);
+    }
+}
+
+/// The location of the dictionary for the workspace folder containing `file`,
+/// if it is in one.
+fn workspace_dict_path(
+    workspace_folders: &[PathBuf],
+    workspace_dict_path: &Path,
+    file: &Path,
+) -> Option<PathBuf> {
+    // Workspace folders can be nested, in which case the innermost one wins.
+    let root = workspace_folders
+        .iter()
+        .filter(|root| file.starts_with(root))
+        .max_by_key(|root| root.components().count())?;
+
+    Some(root.join(workspace_dict_path))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use super::{add_workspace_folder, workspace_dict_path};
+
+    #[test]
+    fn finds_workspace_dictionary() {
+        let folders = [PathBuf::from("/home/user/project")];
+
+        assert_eq!(
+            workspace_dict_path(
+                &folders,
+                Path::new(".harper/dictionary.txt"),
+                Path::new("/home/user/project/src/main.rs")
+            ),
+            Some(PathBuf::from("/home/user/project/.harper/dictionary.txt"))
+        );
+    }
+
+    #[test]
+    fn files_outside_workspaces_have_no_workspace_dictionary() {
+        let folders = [PathBuf::from("/home/user/project")];
+
+        assert_eq!(
+            workspace_dict_path(
+                &folders,
+                Path::new(".harper/dictionary.txt"),
+                Path::new("/home/user/project-two/README.md")
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn innermost_workspace_folder_wins() {
+        let folders = [
+            PathBuf::from("/home/user/monorepo/packages/app"),
+            PathBuf::from("/home/user/monorepo"),
+        ];
+        let dict_path = Path::new(".harper/dictionary.txt");
+
+        assert_eq!(
+            workspace_dict_path(
+                &folders,
+                dict_path,
+                Path::new("/home/user/monorepo/packages/app/index.ts")
+            ),
+            Some(PathBuf::from(
+                "/home/user/monorepo/packages/app/.harper/dictionary.txt"
+            ))
+        );
+        assert_eq!(
+            workspace_dict_path(
+                &folders,
+                dict_path,
+                Path::new("/home/user/monorepo/packages/lib/index.ts")
+            ),
+            Some(PathBuf::from("/home/user/monorepo/.harper/dictionary.txt"))
+        );
+    }
+
+    #[test]
+    fn workspace_folders_are_not_added_twice() {
+        let mut folders = Vec::new();
+
+        add_workspace_folder(&mut folders, PathBuf::from("/home/user/project"));
+        add_workspace_folder(&mut folders, PathBuf::from("/home/user/project"));
+
+        assert_eq!(folders, [PathBuf::from("/home/user/project")]);
+    }
+}
@@ -1,7 +1,9 @@
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 
 use dirs::{config_dir, data_local_dir};
 use harper_core::linting::LintGroupConfig;
+use harper_core::{ProjectConfig, Severity, SeverityOverrides};
 use resolve_path::PathResolveExt;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -29,6 +31,17 @@ impl DiagnosticSeverity {
     }
 }
 
+impl From<Severity> for DiagnosticSeverity {
+    fn from(value: Severity) -> Self {
+        match value {
+            Severity::Error => DiagnosticSeverity::Error,
+            Severity::Warning => DiagnosticSeverity::Warning,
+            Severity::Information => DiagnosticSeverity::Information,
+            Severity::Hint => DiagnosticSeverity::Hint,
+        }
+    }
+}
+
 /// Configuration for how code actions are displayed.
 /// Originally motivated by [#89](https://github.com/automattic/harper/issues/89).
 #[derive(Debug, Clone, Default)]
@@ -66,8 +79,19 @@ impl CodeActionConfig {
 pub struct Config {
     pub user_dict_path: PathBuf,
     pub file_dict_path: PathBuf,
+    /// The location of the dictionary shared by everyone working in a
+    /// workspace, relative to the root of the workspace.
+    pub workspace_dict_path: PathBuf,
+    /// A file of user-defined replacement rules, applied to every document.
+    pub replacement_rules_path: Option<PathBuf>,
     pub lint_config: LintGroupConfig,
+    /// Takes precedence over [`Self::lint_config`] for documents with a
+    /// particular language ID, like `markdown`.
+    pub language_lint_configs: BTreeMap<String, LintGroupConfig>,
     pub diagnostic_severity: DiagnosticSeverity,
+    /// Takes precedence over [`Self::diagnostic_severity`] for specific rules
+    /// and kinds of lint.
+    pub severity: SeverityOverrides,
     pub code_action_config: CodeActionConfig,
     pub isolate_english: bool,
 }
@@ -102,14 +126,44 @@ impl Config {
             }
         }
 
+        if let Some(v) = value.get("workspaceDictPath") {
+            if let Value::String(path) = v {
+                base.workspace_dict_path = PathBuf::from(path);
+            } else {
+                return Err(anyhow::format_err!("workspaceDictPath must be a string."));
+            }
+        }
+
+        if let Some(v) = value.get("replacementRulesPath") {
+            if let Value::String(path) = v {
+                base.replacement_rules_path = Some(path.try_resolve()?.to_path_buf());
+            } else {
+                return Err(anyhow::format_err!(
+                    "replacementRulesPath must be a string."
+                ));
+            }
+        }
+
         if let Some(v) = value.get("linters") {
             base.lint_config = serde_json::from_value(v.clone())?;
         }
 
+        if let Some(v) = value.get("languageLinters") {
+            base.language_lint_configs = serde_json::from_value(v.clone())?;
+        }
+
+        if let Some(v) = value.get("dialect") {
+            base.lint_config.dialect = Some(serde_json::from_value(v.clone())?);
+        }
+
         if let Some(v) = value.get("diagnosticSeverity") {
             base.diagnostic_severity = serde_json::from_value(v.clone())?;
         }
 
+        if let Some(v) = value.get("severity") {
+            base.severity = serde_json::from_value(v.clone())?;
+        }
+
         if let Some(v) = value.get("codeActions") {
             base.code_action_config = CodeActionConfig::from_lsp_config(v.clone())?;
         }
@@ -126,6 +180,38 @@ impl Config {
 
         Ok(base)
     }
+
+    /// The linters to use for a document with the given language ID, in the
+    /// given project.
+    ///
+    /// From lowest to highest precedence, these are the editor's linters, the
+    /// project's linters, the editor's linters for the language and the
+    /// project's linters for the language.
+    pub fn lint_config_for(
+        &self,
+        language_id: Option<&str>,
+        project_config: Option<&ProjectConfig>,
+    ) -> LintGroupConfig {
+        let mut lint_config = self.lint_config.clone();
+
+        if let Some(project_config) = project_config {
+            lint_config.override_with(&project_config.linters);
+        }
+
+        if let Some(language_config) = language_id.and_then(|id| self.language_lint_configs.get(id))
+        {
+            lint_config.override_with(language_config);
+        }
+
+        if let Some(language_config) = language_id
+            .zip(project_config)
+            .and_then(|(id, project_config)| project_config.language_linters.get(id))
+        {
+            lint_config.override_with(language_config);
+        }
+
+        lint_config
+    }
 }
 
 impl Default for Config {
@@ -135,10 +221,51 @@ impl Default for Config {
             file_dict_path: data_local_dir()
                 .unwrap()
                 .join("harper-ls/file_dictionaries/"),
+            workspace_dict_path: PathBuf::from(".harper/dictionary.txt"),
+            replacement_rules_path: None,
             lint_config: LintGroupConfig::default(),
+            language_lint_configs: BTreeMap::new(),
             diagnostic_severity: DiagnosticSeverity::Hint,
+            severity: SeverityOverrides::default(),
             code_action_config: CodeActionConfig::default(),
             isolate_english: false,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use harper_core::ProjectConfig;
+    use serde_json::json;
+
+    use super::Config;
+
+    #[test]
+    fn language_linters_apply_on_top_of_project_linters() {
+        let config = Config::from_lsp_config(json!({
+            "harper-ls": {
+                "linters": { "passive_voice": true, "long_sentences": true },
+                "languageLinters": { "markdown": { "long_sentences": false } }
+            }
+        }))
+        .unwrap();
+
+        let project_config = ProjectConfig::from_toml_str(
+            "[linters]\npassive_voice = false\nlong_sentences = true\n\n[language_linters.markdown]\npassive_voice = true",
+            "/project",
+        )
+        .unwrap();
+
+        let markdown = config.lint_config_for(Some("markdown"), Some(&project_config));
+        assert_eq!(markdown.passive_voice, Some(true));
+        assert_eq!(markdown.long_sentences, Some(false));
+
+        let rust = config.lint_config_for(Some("rust"), Some(&project_config));
+        assert_eq!(rust.passive_voice, Some(false));
+        assert_eq!(rust.long_sentences, Some(true));
+
+        let outside_project = config.lint_config_for(Some("markdown"), None);
+        assert_eq!(outside_project.passive_voice, Some(true));
+        assert_eq!(outside_project.long_sentences, Some(false));
+    }
+}
@@ -2,8 +2,8 @@ use std::collections::HashMap;
 
 use harper_core::linting::{Lint, Suggestion};
 use tower_lsp::lsp_types::{
-    CodeAction, CodeActionKind, CodeActionOrCommand, Command, Diagnostic, TextEdit, Url,
-    WorkspaceEdit,
+    CodeAction, CodeActionKind, CodeActionOrCommand, Command, Diagnostic, NumberOrString, TextEdit,
+    Url, WorkspaceEdit,
 };
 
 use crate::config::{CodeActionConfig, DiagnosticSeverity};
@@ -12,19 +12,22 @@ use crate::pos_conv::span_to_range;
 pub fn lints_to_diagnostics(
     source: &[char],
     lints: &[Lint],
-    severity: DiagnosticSeverity,
+    severity: impl Fn(&Lint) -> DiagnosticSeverity,
 ) -> Vec<Diagnostic> {
     lints
         .iter()
-        .map(|lint| lint_to_diagnostic(lint, source, severity))
+        .map(|lint| lint_to_diagnostic(lint, source, severity(lint)))
         .collect()
 }
 
+/// `in_workspace` decides whether words can be added to the workspace
+/// dictionary.
 pub fn lint_to_code_actions<'a>(
     lint: &'a Lint,
     url: &'a Url,
     source: &'a [char],
     config: &CodeActionConfig,
+    in_workspace: bool,
 ) -> Vec<CodeActionOrCommand> {
     let mut results = Vec::new();
 
@@ -72,6 +75,14 @@ pub fn lint_to_code_actions<'a>(
             Some(vec![orig.clone().into(), url.to_string().into()]),
         )));
 
+        if in_workspace {
+            results.push(CodeActionOrCommand::Command(Command::new(
+                format!("Add \"{}\" to the workspace dictionary.", orig),
+                "HarperAddToWorkspaceDict".to_string(),
+                Some(vec![orig.clone().into(), url.to_string().into()]),
+            )));
+        }
+
         results.push(CodeActionOrCommand::Command(Command::new(
             format!("Add \"{}\" to the file dictionary.", orig),
             "HarperAddToFileDict".to_string(),
@@ -92,7 +103,7 @@ fn lint_to_diagnostic(lint: &Lint, source: &[char], severity: DiagnosticSeverity
     Diagnostic {
         range,
         severity: Some(severity.to_lsp()),
-        code: None,
+        code: lint.rule.clone().map(NumberOrString::String),
         code_description: None,
         source: Some("Harper".to_string()),
         message: lint.message.clone(),
@@ -1,59 +1,117 @@
 use std::path::Path;
 
-use harper_core::{Dictionary, FullDictionary, WordMetadata};
-use tokio::fs::{self, File};
-use tokio::io::{self, AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, BufReader, BufWriter};
+use harper_core::FullDictionary;
+use tokio::fs::{self, OpenOptions};
+use tokio::io::{self, AsyncWriteExt};
+use tracing::warn;
 
-/// Save the contents of a dictionary to a file.
+/// Append a word to a dictionary file, unless it is already there.
 /// Ensures that the path to the destination exists.
-pub async fn save_dict(path: impl AsRef<Path>, dict: impl Dictionary) -> io::Result<()> {
-    if let Some(parent) = path.as_ref().parent() {
+///
+/// Slashes in the word are escaped, so they aren't read as affix flags.
+/// Existing entries are kept as written, so their affix flags and
+/// part-of-speech fields (e.g. `kubelet/SM po:noun`) are preserved.
+pub async fn append_to_dict(path: impl AsRef<Path>, word: &str) -> io::Result<()> {
+    let path = path.as_ref();
+
+    if let Some(parent) = path.parent() {
         fs::create_dir_all(parent).await?;
     }
 
-    let file = File::create(path.as_ref()).await?;
-    let mut write = BufWriter::new(file);
+    let existing = match fs::read_to_string(path).await {
+        Ok(existing) => existing,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+        Err(err) => return Err(err),
+    };
 
-    write_word_list(dict, &mut write).await?;
+    let entry = word.trim().replace('/', "\\/");
 
-    write.flush().await?;
+    if existing.lines().any(|line| line.trim() == entry) {
+        return Ok(());
+    }
 
-    Ok(())
-}
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .await?;
 
-async fn write_word_list(dict: impl Dictionary, mut w: impl AsyncWrite + Unpin) -> io::Result<()> {
-    let mut cur_str = String::new();
+    if !existing.is_empty() && !existing.ends_with('\n') {
+        file.write_all(b"\n").await?;
+    }
 
-    for word in dict.words_iter() {
-        cur_str.clear();
-        cur_str.extend(word);
+    file.write_all(entry.as_bytes()).await?;
+    file.write_all(b"\n").await?;
 
-        w.write_all(cur_str.as_bytes()).await?;
-        w.write_all(b"\n").await?;
-    }
+    file.flush().await?;
 
     Ok(())
 }
 
+/// Load a dictionary file, in the format read by
+/// [`FullDictionary::from_word_list`].
+///
+/// Problems with its entries are logged, rather than keeping the rest of the
+/// dictionary from loading.
 pub async fn load_dict(path: impl AsRef<Path>) -> io::Result<FullDictionary> {
-    let file = File::open(path.as_ref()).await?;
-    let read = BufReader::new(file);
+    let path = path.as_ref();
+    let source = fs::read_to_string(path).await?;
 
-    dict_from_word_list(read).await
+    let (dict, warnings) = FullDictionary::from_word_list(&source);
+
+    for warning in warnings {
+        warn!("{}: {}", path.display(), warning);
+    }
+
+    Ok(dict)
 }
 
-/// This function could definitely be optimized to use less memory.
-/// Right now it isn't an issue.
-async fn dict_from_word_list(mut r: impl AsyncRead + Unpin) -> io::Result<FullDictionary> {
-    let mut str = String::new();
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
 
-    r.read_to_string(&mut str).await?;
+    use harper_core::Dictionary;
 
-    let mut dict = FullDictionary::new();
-    dict.extend_words(
-        str.lines()
-            .map(|l| (l.chars().collect::<Vec<char>>(), WordMetadata::default())),
-    );
+    use super::{append_to_dict, load_dict};
 
-    Ok(dict)
+    /// A dictionary file that doesn't exist yet, in a directory of its own.
+    fn dict_path(name: &str) -> PathBuf {
+        std::env::temp_dir()
+            .join(format!("harper-ls-{}-{}", name, std::process::id()))
+            .join("dictionary.txt")
+    }
+
+    #[tokio::test]
+    async fn round_trips_words_with_slashes() {
+        let path = dict_path("slashes");
+
+        append_to_dict(&path, "TCP/IP").await.unwrap();
+        append_to_dict(&path, "TCP/IP").await.unwrap();
+        let source = tokio::fs::read_to_string(&path).await.unwrap();
+        let dict = load_dict(&path).await.unwrap();
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+
+        assert_eq!(source, "TCP\\/IP\n");
+        assert!(dict.contains_word_str("TCP/IP"));
+        assert!(!dict.contains_word_str("TCP"));
+    }
+
+    #[tokio::test]
+    async fn keeps_annotated_entries() {
+        let path = dict_path("annotated");
+        tokio::fs::create_dir_all(path.parent().unwrap())
+            .await
+            .unwrap();
+        tokio::fs::write(&path, "kubelet/SM po:noun").await.unwrap();
+
+        append_to_dict(&path, "and/or").await.unwrap();
+        let source = tokio::fs::read_to_string(&path).await.unwrap();
+        let dict = load_dict(&path).await.unwrap();
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+
+        assert_eq!(source, "kubelet/SM po:noun\nand\\/or\n");
+        assert!(dict.get_word_metadata_str("kubelet").is_noun());
+        assert!(dict.contains_word_str("kubelets"));
+        assert!(dict.contains_word_str("and/or"));
+    }
 }
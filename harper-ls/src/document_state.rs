@@ -1,5 +1,8 @@
-use harper_core::linting::LintGroup;
-use harper_core::{Document, FullDictionary, Lrc, MergedDictionary};
+use std::sync::Arc;
+
+use harper_comments::CommentParser;
+use harper_core::linting::{LintGroup, ParagraphLintCache};
+use harper_core::{Document, FullDictionary, Lrc, MergedDictionary, ProjectConfig};
 
 #[derive(Default)]
 pub struct DocumentState {
@@ -7,5 +10,12 @@ pub struct DocumentState {
     pub ident_dict: Lrc<FullDictionary>,
     pub dict: Lrc<MergedDictionary>,
     pub linter: LintGroup<Lrc<MergedDictionary>>,
+    /// The lints of each paragraph, which must be cleared whenever
+    /// [`Self::linter`] is changed.
+    pub lint_cache: ParagraphLintCache,
     pub language_id: Option<String>,
+    pub project_config: Option<Arc<ProjectConfig>>,
+    /// Kept between updates so that the source code can be parsed
+    /// incrementally.
+    pub comment_parser: Option<CommentParser>,
 }
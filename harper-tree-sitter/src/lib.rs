@@ -1,13 +1,27 @@
 use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
 
 use harper_core::{FullDictionary, Mask, Masker, Span, WordMetadata};
-use tree_sitter::{Language, Node, Tree, TreeCursor};
+use tree_sitter::{InputEdit, Language, Node, Point, Tree, TreeCursor};
 
 /// A Harper [`Masker`] that wraps a given tree-sitter language and a condition,
 /// allowing you to selectively parse only specific tree-sitter nodes.
+///
+/// The masker remembers the last tree it produced, so parsing a slightly
+/// modified version of the same text is incremental.
+/// Clones share this tree with the original.
+#[derive(Clone)]
 pub struct TreeSitterMasker {
     language: Language,
     node_condition: fn(&Node) -> bool,
+    previous: Arc<Mutex<Option<PreviousParse>>>,
+}
+
+/// The text most recently parsed by a [`TreeSitterMasker`], along with the
+/// resulting tree.
+struct PreviousParse {
+    text: String,
+    tree: Tree,
 }
 
 impl TreeSitterMasker {
@@ -15,15 +29,33 @@ impl TreeSitterMasker {
         Self {
             language,
             node_condition,
+            previous: Arc::default(),
         }
     }
 
     fn parse_root(&self, text: &str) -> Option<Tree> {
+        let mut previous = self.previous.lock().unwrap();
+
+        let old_tree = match previous.as_mut() {
+            Some(previous) if previous.text == text => return Some(previous.tree.clone()),
+            Some(previous) => {
+                previous.tree.edit(&input_edit(&previous.text, text));
+                Some(&previous.tree)
+            }
+            None => None,
+        };
+
         let mut parser = tree_sitter::Parser::new();
         parser.set_language(self.language).unwrap();
 
-        // TODO: Use incremental parsing
-        parser.parse(text, None)
+        let tree = parser.parse(text, old_tree);
+
+        *previous = tree.clone().map(|tree| PreviousParse {
+            text: text.to_string(),
+            tree,
+        });
+
+        tree
     }
 
     pub fn create_ident_dict(&self, source: &[char]) -> Option<FullDictionary> {
@@ -115,6 +147,46 @@ impl Masker for TreeSitterMasker {
     }
 }
 
+/// Describe the change from `old` to `new` as a single edit, by trimming the
+/// text the two have in common at either end.
+fn input_edit(old: &str, new: &str) -> InputEdit {
+    let prefix = old
+        .char_indices()
+        .zip(new.chars())
+        .find(|((_, a), b)| a != b)
+        .map(|((idx, _), _)| idx)
+        .unwrap_or(old.len().min(new.len()));
+
+    let suffix: usize = old[prefix..]
+        .chars()
+        .rev()
+        .zip(new[prefix..].chars().rev())
+        .take_while(|(a, b)| a == b)
+        .map(|(a, _)| a.len_utf8())
+        .sum();
+
+    let old_end = old.len() - suffix;
+    let new_end = new.len() - suffix;
+
+    InputEdit {
+        start_byte: prefix,
+        old_end_byte: old_end,
+        new_end_byte: new_end,
+        start_position: byte_to_point(old, prefix),
+        old_end_position: byte_to_point(old, old_end),
+        new_end_position: byte_to_point(new, new_end),
+    }
+}
+
+fn byte_to_point(text: &str, byte: usize) -> Point {
+    let before = &text[..byte];
+
+    match before.rfind('\n') {
+        Some(newline) => Point::new(before.matches('\n').count(), byte - newline - 1),
+        None => Point::new(0, byte),
+    }
+}
+
 /// Converts a set of byte-indexed [`Span`]s to char-index Spans, in-place.
 /// NOTE: Will sort the given slice by their [`Span::start`].
 ///
@@ -149,3 +221,30 @@ fn byte_spans_to_char_spans(byte_spans: &mut Vec<Span>, source: &str) {
         last_byte_pos = byte_span.end;
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter::Point;
+
+    use super::input_edit;
+
+    #[test]
+    fn finds_inserted_text() {
+        let edit = input_edit("fn a() {}\n// hello\n", "fn a() {}\n// hello there\n");
+
+        assert_eq!(edit.start_byte, 18);
+        assert_eq!(edit.old_end_byte, 18);
+        assert_eq!(edit.new_end_byte, 24);
+        assert_eq!(edit.start_position, Point::new(1, 8));
+        assert_eq!(edit.new_end_position, Point::new(1, 14));
+    }
+
+    #[test]
+    fn respects_char_boundaries() {
+        let edit = input_edit("// café", "// cafè");
+
+        assert_eq!(edit.start_byte, 6);
+        assert_eq!(edit.old_end_byte, 8);
+        assert_eq!(edit.new_end_byte, 8);
+    }
+}
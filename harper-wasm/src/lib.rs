@@ -6,7 +6,8 @@ use std::sync::Arc;
 use harper_core::language_detection::is_doc_likely_english;
 use harper_core::linting::{LintGroup, LintGroupConfig, Linter as _};
 use harper_core::parsers::{IsolateEnglish, Markdown, PlainEnglish};
-use harper_core::{remove_overlaps, Document, FstDictionary, FullDictionary, Lrc};
+use harper_core::readability::ReadabilityReport;
+use harper_core::{remove_overlaps, Document, FstDictionary, Lrc};
 use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::wasm_bindgen;
 use wasm_bindgen::JsValue;
@@ -51,8 +52,8 @@ pub struct Linter {
 #[wasm_bindgen]
 impl Linter {
     /// Construct a new `Linter`.
-    /// Note that this can mean constructing the curated dictionary, which is the most expensive operation
-    /// in Harper.
+    /// Note that this can mean loading the curated dictionary, which is compiled into Harper ahead of
+    /// time.
     pub fn new() -> Self {
         let dictionary = FstDictionary::curated();
 
@@ -62,6 +63,20 @@ impl Linter {
         }
     }
 
+    /// Construct a new `Linter` from a dictionary compiled with `harper-cli compile-dict`,
+    /// instead of the curated dictionary.
+    ///
+    /// Setting a different dialect will switch back to the curated dictionary for that dialect.
+    pub fn new_with_compiled_dictionary(fst: Vec<u8>, metadata: Vec<u8>) -> Result<Linter, String> {
+        let dictionary =
+            Arc::new(FstDictionary::from_compiled(fst, &metadata).map_err(|v| v.to_string())?);
+
+        Ok(Self {
+            lint_group: LintGroup::new(LintGroupConfig::default(), dictionary.clone()),
+            dictionary,
+        })
+    }
+
     /// Helper method to quickly check if a plain string is likely intended to be English
     pub fn is_likely_english(&self, text: String) -> bool {
         let document = Document::new_plain_english(&text, &self.dictionary);
@@ -84,7 +99,7 @@ impl Linter {
     }
 
     pub fn set_lint_config_from_json(&mut self, json: String) -> Result<(), String> {
-        self.lint_group.config = serde_json::from_str(&json).map_err(|v| v.to_string())?;
+        self.set_lint_config(serde_json::from_str(&json).map_err(|v| v.to_string())?);
         Ok(())
     }
 
@@ -96,18 +111,29 @@ impl Linter {
     }
 
     pub fn set_lint_config_from_object(&mut self, object: JsValue) -> Result<(), String> {
-        self.lint_group.config =
-            serde_wasm_bindgen::from_value(object).map_err(|v| v.to_string())?;
+        self.set_lint_config(serde_wasm_bindgen::from_value(object).map_err(|v| v.to_string())?);
         Ok(())
     }
 
+    /// Measure the readability of the provided text, as a whole and for each paragraph and
+    /// sentence within it.
+    pub fn get_readability_as_json(&self, text: String) -> String {
+        serde_json::to_string(&self.readability(&text)).unwrap()
+    }
+
+    pub fn get_readability_as_object(&self, text: String) -> JsValue {
+        // Important for downstream JSON serialization
+        let serializer = serde_wasm_bindgen::Serializer::json_compatible();
+
+        self.readability(&text).serialize(&serializer).unwrap()
+    }
+
     /// Perform the configured linting on the provided text.
     pub fn lint(&mut self, text: String) -> Vec<Lint> {
         let source: Vec<_> = text.chars().collect();
         let source = Lrc::new(source);
 
-        let document =
-            Document::new_from_vec(source.clone(), &mut Markdown, &FullDictionary::curated());
+        let document = Document::new_from_vec(source.clone(), &mut Markdown, &self.dictionary);
 
         let mut lints = self.lint_group.lint(&document);
 
@@ -120,6 +146,24 @@ impl Linter {
     }
 }
 
+impl Linter {
+    fn readability(&self, text: &str) -> ReadabilityReport {
+        let document = Document::new(text, &mut Markdown, &self.dictionary);
+        ReadabilityReport::new(&document)
+    }
+
+    /// Apply a new configuration, switching to the dictionary for its dialect
+    /// if that has changed.
+    fn set_lint_config(&mut self, config: LintGroupConfig) {
+        if config.dialect != self.lint_group.config.dialect {
+            self.dictionary = FstDictionary::curated_dialect(config.dialect.unwrap_or_default());
+            self.lint_group = LintGroup::new(config, self.dictionary.clone());
+        } else {
+            self.lint_group.config = config;
+        }
+    }
+}
+
 impl Default for Linter {
     fn default() -> Self {
         Self::new()
@@ -200,6 +244,12 @@ impl Lint {
         self.inner.lint_kind.to_string()
     }
 
+    /// Get the stable identifier of the rule that produced the lint (e.g.
+    /// `SpellCheck`).
+    pub fn rule(&self) -> Option<String> {
+        self.inner.rule.clone()
+    }
+
     pub fn suggestion_count(&self) -> usize {
         self.inner.suggestions.len()
     }
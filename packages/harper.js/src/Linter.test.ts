@@ -94,6 +94,19 @@ for (const [linterName, Linter] of Object.entries(linters)) {
 			expect(lintConfig[key]).toBe(true);
 		}
 	});
+
+	test(`${linterName} measures readability for each paragraph`, async () => {
+		const linter = new Linter();
+
+		const readability = await linter.getReadability(
+			'The cat sat on the mat.\n\nThe bug was fixed. We shipped it.'
+		);
+
+		expect(readability.scores.words).toBe(13);
+		expect(readability.scores.passive_sentences).toBe(1);
+		expect(readability.paragraphs.length).toBe(2);
+		expect(readability.paragraphs[1].sentences.length).toBe(2);
+	});
 }
 
 test('Linters have the same config format', async () => {
@@ -1,5 +1,5 @@
 import type { Lint, Span, Suggestion } from 'wasm';
-import { LintConfig } from './main';
+import { LintConfig, Readability } from './main';
 
 /** A interface for an object that can perform linting actions. */
 export default interface Linter {
@@ -18,6 +18,12 @@ export default interface Linter {
 	 * The algorithm can be described as "proof of concept" and as such does not work terribly well.*/
 	isolateEnglish(text: string): Promise<string>;
 
+	/** Measure the readability of the provided text, as a whole and for each paragraph and sentence within it. */
+	getReadability(text: string): Promise<Readability>;
+
+	/** Measure the readability of the provided text, returning the result as JSON. */
+	getReadabilityAsJSON(text: string): Promise<string>;
+
 	/** Get the linter's current configuration. */
 	getLintConfig(): Promise<LintConfig>;
 
@@ -1,7 +1,7 @@
 import type { Lint, Span, Suggestion, Linter as WasmLinter } from 'wasm';
 import Linter from './Linter';
 import loadWasm from './loadWasm';
-import { LintConfig } from './main';
+import { LintConfig, Readability } from './main';
 
 /** A Linter that runs in the current JavaScript context (meaning it is allowed to block the event loop). */
 export default class LocalLinter implements Linter {
@@ -46,6 +46,18 @@ export default class LocalLinter implements Linter {
 		return this.inner!.isolate_english(text);
 	}
 
+	async getReadability(text: string): Promise<Readability> {
+		await this.initialize();
+
+		return this.inner!.get_readability_as_object(text);
+	}
+
+	async getReadabilityAsJSON(text: string): Promise<string> {
+		await this.initialize();
+
+		return this.inner!.get_readability_as_json(text);
+	}
+
 	async getLintConfig(): Promise<LintConfig> {
 		await this.initialize();
 
@@ -3,7 +3,7 @@ import type { Lint, Suggestion, Span } from 'wasm';
 import Linter from '../Linter';
 import Worker from './worker.js?worker&inline';
 import { getWasmUri } from '../loadWasm';
-import { LintConfig } from '../main';
+import { LintConfig, Readability } from '../main';
 
 /** The data necessary to complete a request once the worker has responded. */
 type RequestItem = {
@@ -76,6 +76,14 @@ export default class WorkerLinter implements Linter {
 		return await this.rpc('isolateEnglish', [text]);
 	}
 
+	async getReadability(text: string): Promise<Readability> {
+		return JSON.parse(await this.getReadabilityAsJSON(text));
+	}
+
+	async getReadabilityAsJSON(text: string): Promise<string> {
+		return await this.rpc('getReadabilityAsJSON', [text]);
+	}
+
 	async getLintConfig(): Promise<LintConfig> {
 		return JSON.parse(await this.getLintConfigAsJSON());
 	}
@@ -11,4 +11,37 @@ export enum SuggestionKind {
 	Remove = 1
 }
 
-export type LintConfig = Record<string, boolean | undefined>;
+/** Options for the linters that accept them, keyed by the name of the linter. */
+export type LinterOptions = Record<string, Record<string, unknown> | undefined>;
+
+/** A regional variety of English. */
+export type Dialect = 'american' | 'british' | 'australian' | 'canadian';
+
+export type LintConfig = Record<string, boolean | Dialect | LinterOptions | undefined>;
+
+/** The counts and scores describing how easy a piece of text is to read.
+ * Scores are `null` when the text has no words or sentences. */
+export type ReadabilityScores = {
+	words: number;
+	sentences: number;
+	syllables: number;
+	complex_words: number;
+	passive_sentences: number;
+	average_sentence_length: number | null;
+	average_syllables_per_word: number | null;
+	passive_ratio: number | null;
+	flesch_reading_ease: number | null;
+	flesch_kincaid_grade: number | null;
+	gunning_fog: number | null;
+};
+
+/** The readability of a document, along with that of each paragraph and sentence within it.
+ * Spans are measured in characters from the start of the document. */
+export type Readability = {
+	scores: ReadabilityScores;
+	paragraphs: {
+		span: { start: number; end: number };
+		scores: ReadabilityScores;
+		sentences: { span: { start: number; end: number }; scores: ReadabilityScores }[];
+	}[];
+};
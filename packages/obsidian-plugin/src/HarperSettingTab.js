@@ -30,6 +30,11 @@ export class HarperSettingTab extends PluginSettingTab {
 		console.log(this.settings.lintSettings);
 
 		for (let setting of Object.keys(this.settings.lintSettings)) {
+			// The dialect and linter options aren't toggles, so they can't be edited here.
+			if (setting == 'options' || setting == 'dialect') {
+				continue;
+			}
+
 			let value = this.settings.lintSettings[setting];
 
 			new Setting(containerEl)